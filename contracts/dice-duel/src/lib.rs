@@ -3,38 +3,19 @@
 //! # Dice Duel
 //!
 //! A two-player dice game where each player rolls two dice.
-//! The player with the highest total wins (ties go to player 1).
+//! The player with the highest total wins; equal totals are a draw and both
+//! players get their points back.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use sgs_common::{extend_temporary, GameOutcome};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror, contractimpl,
     contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -67,6 +48,7 @@ pub struct Game {
     pub player1_die2: Option<u32>,
     pub player2_die1: Option<u32>,
     pub player2_die2: Option<u32>,
+    /// Set when the game ends; stays `None` on a draw
     pub winner: Option<Address>,
 }
 
@@ -74,20 +56,8 @@ pub struct Game {
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -114,10 +84,7 @@ impl DiceDuelContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_common::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -149,54 +116,53 @@ impl DiceDuelContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Create game (dice not rolled yet - will be generated in reveal_winner)
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        Self::open_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_rolled: false,
-            player2_rolled: false,
-            player1_die1: None,
-            player1_die2: None,
-            player2_die1: None,
-            player2_die2: None,
-            winner: None,
-        };
+        )
+    }
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+    /// Start a new game like `start_game`, with the session ID issued by the
+    /// Game Hub instead of chosen by the caller.
+    ///
+    /// Players authorize only their points, since the session ID isn't known
+    /// until the hub issues it.
+    ///
+    /// # Returns
+    /// * `u32` - The session ID issued by the Game Hub
+    pub fn start_game_reserved(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
+        }
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player2_points.into_val(&env)]);
 
-        // Event emitted by GameHub contract (GameStarted)
+        // Ask the Game Hub for the next free session ID
+        let session_id = sgs_common::hub_reserve_session(&env);
 
-        Ok(())
+        Self::open_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        )?;
+
+        Ok(session_id)
     }
 
     /// Commit a roll for the current game.
@@ -216,8 +182,8 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (dice not revealed yet)
+        if game.player1_die1.is_some() {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -250,8 +216,8 @@ impl DiceDuelContract {
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` on a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -260,9 +226,9 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended (dice revealed)
+        if game.player1_die1.is_some() {
+            return Ok(game.winner);
         }
 
         // Check both players have rolled
@@ -309,34 +275,23 @@ impl DiceDuelContract {
         game.player2_die1 = Some(player2_die1);
         game.player2_die2 = Some(player2_die2);
 
-        // Determine winner (if tie, player1 wins)
+        // Determine winner (equal totals are a draw)
         let player1_total = player1_die1 + player1_die2;
         let player2_total = player2_die1 + player2_die2;
-        let winner = if player1_total >= player2_total {
-            game.player1.clone()
-        } else {
-            game.player2.clone()
+        let (outcome, winner) = match player1_total.cmp(&player2_total) {
+            core::cmp::Ordering::Greater => (GameOutcome::Player1, Some(game.player1.clone())),
+            core::cmp::Ordering::Less => (GameOutcome::Player2, Some(game.player2.clone())),
+            core::cmp::Ordering::Equal => (GameOutcome::Draw, None),
         };
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
+        // Update game with winner (the revealed dice mark the game as ended)
+        game.winner = winner.clone();
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
         // Call GameHub to end the session
-        // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        // This unlocks points (refunding both on a draw) and updates standings
+        // Event emitted by the Game Hub contract (GameEnded / GameDrawn)
+        sgs_common::hub_end_game_v2(&env, session_id, outcome);
 
         Ok(winner)
     }
@@ -365,10 +320,7 @@ impl DiceDuelContract {
     /// # Returns
     /// * `Address` - The admin address
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        sgs_common::get_admin(&env)
     }
 
     /// Set a new admin address
@@ -376,14 +328,7 @@ impl DiceDuelContract {
     /// # Arguments
     /// * `new_admin` - The new admin address
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        sgs_common::set_admin(&env, &new_admin);
     }
 
     /// Get the current GameHub contract address
@@ -391,10 +336,7 @@ impl DiceDuelContract {
     /// # Returns
     /// * `Address` - The GameHub contract address
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        sgs_common::get_hub(&env)
     }
 
     /// Set a new GameHub contract address
@@ -402,16 +344,7 @@ impl DiceDuelContract {
     /// # Arguments
     /// * `new_hub` - The new GameHub contract address
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        sgs_common::set_hub(&env, &new_hub);
     }
 
     /// Update the contract WASM hash (upgrade contract)
@@ -419,14 +352,58 @@ impl DiceDuelContract {
     /// # Arguments
     /// * `new_wasm_hash` - The hash of the new WASM binary
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        sgs_common::upgrade(&env, new_wasm_hash);
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Helper to start the session with the Game Hub and store the new game
+    fn open_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        sgs_common::hub_start_game(
+            env,
+            session_id,
+            &player1,
+            &player2,
+            player1_points,
+            player2_points,
+        );
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        // Create game (dice not rolled yet - will be generated in reveal_winner)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_rolled: false,
+            player2_rolled: false,
+            player1_die1: None,
+            player1_die2: None,
+            player2_die1: None,
+            player2_die2: None,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
+
+        // Set TTL to ensure game is retained for at least 30 days
+        extend_temporary(env, &game_key);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
     }
 }
 
@@ -1,12 +1,35 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, BytesN, Env,
+    Map, Vec,
+};
 
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
-/// for game contracts to compile and integrate during development.
+/// (start_game, end_game) and tracks each session so that games misusing
+/// the hub (ending a session twice, ending unknown sessions) fail loudly in
+/// development.
+///
+/// Only game contracts approved by the admin may start sessions, and they
+/// must authorize `start_game` as themselves. Instead of picking a
+/// `session_id`, a game can take the next free one from `reserve_session`.
+///
+/// Players hold faucet points credited by the admin. `start_game` locks both
+/// stakes and `end_game` pays the whole pot to the winner. `end_game_v2` can
+/// also end a session as a draw or cancel it, refunding each player's stake.
+///
+/// Each session gets a deadline from its game's `GamePolicy`. Once it passes,
+/// anyone can `force_settle` a session the game never ended, applying the
+/// policy's default outcome. Games that never call `set_game_policy` get a
+/// one-day timeout that cancels the session and refunds both stakes.
+///
+/// Every decided or drawn session updates both players' standings:
+/// win/loss/draw counts and an Elo rating, per game and across all games.
+///
+/// The admin can inject faults (see `Faults`) so game contracts can test how
+/// they behave when a hub call traps.
 #[contract]
 pub struct MockGameHub;
 
@@ -26,17 +49,609 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct SessionIdReserved {
+    pub session_id: u32,
+    pub game_id: Address,
+}
+
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct GameCancelled {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct SessionForceSettled {
+    pub session_id: u32,
+    pub outcome: GameOutcome,
+}
+
+#[contractevent]
+pub struct PointsCredited {
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct GameAdded {
+    pub game_id: Address,
+}
+
+#[contractevent]
+pub struct GameRemoved {
+    pub game_id: Address,
+}
+
+#[contracterror]
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    SessionAlreadyExists = 1,
+    SessionNotFound = 2,
+    SessionAlreadyEnded = 3,
+    InsufficientBalance = 4,
+    InvalidAmount = 5,
+    GameNotRegistered = 6,
+    InjectedFault = 7,
+    SessionReserved = 8,
+    DeadlineNotReached = 9,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active,
+    Ended,     // A winner was declared (see `player1_won`)
+    Drawn,     // Ended as a draw
+    Cancelled, // Ended without a result
+}
+
+/// How a session ended, as reported to `end_game_v2`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Player1,   // Player 1 takes the pot
+    Player2,   // Player 2 takes the pot
+    Draw,      // Stakes refunded; counts as a draw in the standings
+    Cancelled, // Stakes refunded; the standings are untouched
+}
+
+/// A game session as seen by the hub.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSession {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub status: SessionStatus,
+    pub player1_won: Option<bool>, // Set once a winner was declared
+    pub deadline_ledger: u32,      // force_settle is allowed after this ledger
+    pub default_outcome: GameOutcome, // Applied by force_settle
+}
+
+/// How the hub treats a game's sessions that are never ended. Set by the game
+/// itself; games without one get the default policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GamePolicy {
+    pub timeout_ledgers: u32,         // Deadline = start ledger + timeout_ledgers
+    pub default_outcome: GameOutcome, // Applied by force_settle
+}
+
+/// Win/loss record and Elo rating, either for one game or across all games.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Standing {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub rating: u32,
+}
+
+/// A player's standing across all games and in each game they played.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStanding {
+    pub overall: Standing,
+    pub games: Map<Address, Standing>,
+}
+
+/// One row of `get_top_players`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RankedPlayer {
+    pub player: Address,
+    pub standing: Standing,
+}
+
+/// Fault modes set by the admin. A faulted call returns `InjectedFault`,
+/// which traps a game calling the hub directly.
+///
+/// A failing call rolls back its own writes, so faults can't disarm
+/// themselves: each stays armed until the admin clears it.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Faults {
+    pub fail_start: bool,          // Fail every start_game
+    pub fail_end: bool,            // Fail every end_game
+    pub fail_session: Option<u32>, // Fail start_game and end_game for this session_id
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Admin address (manages games, credits points, can upgrade)
+    Admin,
+    /// Approved game contracts: Vec<Address>
+    Games,
+    /// Injected fault modes (absent = no faults)
+    Faults,
+    /// Next id `reserve_session` will consider
+    NextSessionId,
+    /// Game a reserved, not yet started session_id belongs to
+    Reservation(u32),
+    /// GamePolicy keyed by game (absent = default policy)
+    GamePolicy(Address),
+    /// Session keyed by session_id
+    Session(u32),
+    /// Spendable points keyed by player
+    Balance(Address),
+    /// Points locked in active sessions keyed by player
+    Locked(Address),
+    /// One page of the sessions a player took part in, oldest first:
+    /// Vec<u32> keyed by (player, page)
+    PlayerSessions(Address, u32),
+    /// Number of sessions a player took part in: u32
+    PlayerSessionCount(Address),
+    /// One page of the sessions a game started, oldest first: Vec<u32>
+    /// keyed by (game, page)
+    GameSessions(Address, u32),
+    /// Number of sessions a game started: u32
+    GameSessionCount(Address),
+    /// A player's sessions that have not ended yet: Vec<u32>
+    ActiveSessions(Address),
+    /// PlayerStanding keyed by player
+    Standing(Address),
+    /// A game's top players with their rating, highest first:
+    /// Vec<(Address, u32)>
+    Ranking(Address),
+}
+
+/// Session and balance TTL — 30 days in ledgers (~5 s/ledger).
+const SESSION_TTL_LEDGERS: u32 = 518_400;
+
+/// Maximum entries returned by one page query.
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Session ids stored in one PlayerSessions/GameSessions page.
+const SESSION_PAGE_SIZE: u32 = 50;
+
+/// Default session timeout — 1 day in ledgers (~5 s/ledger).
+const DEFAULT_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Players kept in each game's ranking.
+const MAX_RANKED_PLAYERS: u32 = 100;
+
+/// Rating every player starts at, overall and in each game.
+const INITIAL_RATING: u32 = 1200;
+
+/// Maximum rating change from one session.
+const ELO_K: u32 = 32;
+
+/// Expected score (in thousandths) of the lower-rated player for rating gaps
+/// of 0, 50, ..., 800 points: 1000 / (1 + 10^(gap / 400)).
+const ELO_EXPECTED: [u32; 17] = [
+    500, 429, 360, 297, 240, 192, 151, 118, 91, 70, 53, 40, 31, 23, 17, 13, 10,
+];
+
+fn read_points(env: &Env, key: &DataKey) -> i128 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+fn write_points(env: &Env, key: &DataKey, amount: i128) {
+    env.storage().persistent().set(key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+}
+
+/// Move `amount` of the player's spendable points into their locked points.
+fn lock_points(env: &Env, player: &Address, amount: i128) {
+    let balance_key = DataKey::Balance(player.clone());
+    let locked_key = DataKey::Locked(player.clone());
+    write_points(env, &balance_key, read_points(env, &balance_key) - amount);
+    write_points(env, &locked_key, read_points(env, &locked_key) + amount);
+}
+
+/// Release `amount` of the player's locked points.
+fn unlock_points(env: &Env, player: &Address, amount: i128) {
+    let locked_key = DataKey::Locked(player.clone());
+    write_points(env, &locked_key, read_points(env, &locked_key) - amount);
+}
+
+fn add_balance(env: &Env, player: &Address, amount: i128) {
+    let balance_key = DataKey::Balance(player.clone());
+    write_points(env, &balance_key, read_points(env, &balance_key) + amount);
+}
+
+fn load_games(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Games)
+        .unwrap_or(Vec::new(env))
+}
+
+fn load_index(env: &Env, key: &DataKey) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_index(env: &Env, key: &DataKey, index: &Vec<u32>) {
+    env.storage().persistent().set(key, index);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+}
+
+fn index_push(env: &Env, key: DataKey, session_id: u32) {
+    let mut index = load_index(env, &key);
+    index.push_back(session_id);
+    save_index(env, &key, &index);
+}
+
+/// Append `session_id` to a paged history: `page_key(n)` holds entries
+/// `n * SESSION_PAGE_SIZE..`, `count_key` the total, so each write touches
+/// one bounded page.
+fn paged_push(
+    env: &Env,
+    count_key: DataKey,
+    page_key: impl Fn(u32) -> DataKey,
+    session_id: u32,
+) {
+    let count = paged_count(env, &count_key);
+    index_push(env, page_key(count / SESSION_PAGE_SIZE), session_id);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+}
+
+fn paged_count(env: &Env, count_key: &DataKey) -> u32 {
+    env.storage().persistent().get(count_key).unwrap_or(0)
+}
+
+/// Collect up to `limit` (capped at 50) session ids from a paged index,
+/// starting at `offset`, reading only the pages that range touches.
+fn paged_range(
+    env: &Env,
+    count_key: &DataKey,
+    page_key: impl Fn(u32) -> DataKey,
+    offset: u32,
+    limit: u32,
+) -> Vec<u32> {
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(paged_count(env, count_key));
+    let mut ids = Vec::new(env);
+    let mut i = offset;
+    while i < end {
+        let page = load_index(env, &page_key(i / SESSION_PAGE_SIZE));
+        let start = i % SESSION_PAGE_SIZE;
+        let stop = (start + end - i).min(page.len());
+        if start >= stop {
+            break;
+        }
+        for j in start..stop {
+            ids.push_back(page.get_unchecked(j));
+        }
+        i += stop - start;
+    }
+    ids
+}
+
+fn index_remove(env: &Env, key: DataKey, session_id: u32) {
+    let mut index = load_index(env, &key);
+    if let Some(position) = index.first_index_of(session_id) {
+        index.remove(position);
+        save_index(env, &key, &index);
+    }
+}
+
+/// Load the sessions listed in `index`.
+fn load_sessions(env: &Env, index: &Vec<u32>) -> Vec<GameSession> {
+    let mut page = Vec::new(env);
+    for session_id in index.iter() {
+        if let Some(session) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Session(session_id))
+        {
+            page.push_back(session);
+        }
+    }
+    page
+}
+
+fn load_faults(env: &Env) -> Faults {
+    env.storage()
+        .instance()
+        .get(&DataKey::Faults)
+        .unwrap_or_default()
+}
+
+fn load_game_policy(env: &Env, game_id: &Address) -> GamePolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::GamePolicy(game_id.clone()))
+        .unwrap_or(GamePolicy {
+            timeout_ledgers: DEFAULT_TIMEOUT_LEDGERS,
+            default_outcome: GameOutcome::Cancelled,
+        })
+}
+
+fn new_standing() -> Standing {
+    Standing {
+        wins: 0,
+        losses: 0,
+        draws: 0,
+        rating: INITIAL_RATING,
+    }
+}
+
+fn load_standing(env: &Env, player: &Address) -> PlayerStanding {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Standing(player.clone()))
+        .unwrap_or(PlayerStanding {
+            overall: new_standing(),
+            games: Map::new(env),
+        })
+}
+
+fn save_standing(env: &Env, player: &Address, standing: &PlayerStanding) {
+    let key = DataKey::Standing(player.clone());
+    env.storage().persistent().set(&key, standing);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+}
+
+/// Expected score (in thousandths) of a player rated `rating` against
+/// `opponent`, interpolated from `ELO_EXPECTED` and clamped at an 800 gap.
+fn elo_expected(rating: u32, opponent: u32) -> u32 {
+    let gap = rating.abs_diff(opponent).min(800);
+    let (step, within) = ((gap / 50) as usize, gap % 50);
+    let low = ELO_EXPECTED[step];
+    let high = ELO_EXPECTED[(step + 1).min(ELO_EXPECTED.len() - 1)];
+    let expected = low - (low - high) * within / 50;
+    if rating < opponent {
+        expected
+    } else {
+        1000 - expected
+    }
+}
+
+/// Record a win for `first` over `second`, or a draw between them, moving
+/// both ratings by the same Elo delta in opposite directions.
+fn settle_ratings(first: &mut Standing, second: &mut Standing, draw: bool) {
+    let score: i64 = if draw { 500 } else { 1000 };
+    let change = ELO_K as i64 * (score - elo_expected(first.rating, second.rating) as i64);
+    // Round half away from zero
+    let delta = if change >= 0 {
+        (change + 500) / 1000
+    } else {
+        -((500 - change) / 1000)
+    };
+
+    if draw {
+        first.draws += 1;
+        second.draws += 1;
+    } else {
+        first.wins += 1;
+        second.losses += 1;
+    }
+    first.rating = (first.rating as i64 + delta).max(0) as u32;
+    second.rating = (second.rating as i64 - delta).max(0) as u32;
+}
+
+/// The game's ranked `(player, rating)` pairs, highest rating first.
+fn load_ranking(env: &Env, game_id: &Address) -> Vec<(Address, u32)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Ranking(game_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Move `player` to their place in the game's ranking (ties keep the
+/// earlier-ranked player ahead). Ratings are stored alongside each address,
+/// so this reads only the ranking itself; players who fall below the top
+/// `MAX_RANKED_PLAYERS` drop out.
+fn update_ranking(env: &Env, game_id: &Address, player: &Address, rating: u32) {
+    let key = DataKey::Ranking(game_id.clone());
+    let mut ranking = load_ranking(env, game_id);
+    if let Some(position) = ranking.iter().position(|(other, _)| other == *player) {
+        ranking.remove(position as u32);
+    }
+
+    let position = ranking
+        .iter()
+        .position(|(_, other_rating)| rating > other_rating)
+        .map_or(ranking.len(), |i| i as u32);
+    if position < MAX_RANKED_PLAYERS {
+        ranking.insert(position, (player.clone(), rating));
+        if ranking.len() > MAX_RANKED_PLAYERS {
+            ranking.pop_back();
+        }
+    }
+
+    env.storage().persistent().set(&key, &ranking);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+}
+
+/// Update both players' standings for an ended session. Cancelled sessions
+/// don't count.
+fn record_result(env: &Env, session: &GameSession, outcome: GameOutcome) {
+    if session.player1 == session.player2 {
+        return;
+    }
+    let (first, second) = match outcome {
+        GameOutcome::Player1 | GameOutcome::Draw => (&session.player1, &session.player2),
+        GameOutcome::Player2 => (&session.player2, &session.player1),
+        GameOutcome::Cancelled => return,
+    };
+    let draw = outcome == GameOutcome::Draw;
+    let mut first_standing = load_standing(env, first);
+    let mut second_standing = load_standing(env, second);
+
+    settle_ratings(&mut first_standing.overall, &mut second_standing.overall, draw);
+
+    let game_id = &session.game_id;
+    let mut first_game = first_standing
+        .games
+        .get(game_id.clone())
+        .unwrap_or(new_standing());
+    let mut second_game = second_standing
+        .games
+        .get(game_id.clone())
+        .unwrap_or(new_standing());
+    settle_ratings(&mut first_game, &mut second_game, draw);
+    first_standing.games.set(game_id.clone(), first_game.clone());
+    second_standing.games.set(game_id.clone(), second_game.clone());
+
+    save_standing(env, first, &first_standing);
+    save_standing(env, second, &second_standing);
+    update_ranking(env, game_id, first, first_game.rating);
+    update_ranking(env, game_id, second, second_game.rating);
+}
+
+/// End a session on behalf of its game: the game must authorize, and
+/// injected faults apply.
+///
+/// A call from any other game is not a typed `Error`: `require_auth` on the
+/// session's `game_id` fails and the host traps the invocation.
+fn finish_session(env: &Env, session_id: u32, outcome: GameOutcome) -> Result<(), Error> {
+    let faults = load_faults(env);
+    if faults.fail_end || faults.fail_session == Some(session_id) {
+        return Err(Error::InjectedFault);
+    }
+
+    let session = load_active_session(env, session_id, true)?;
+    settle_session(env, session_id, session, outcome);
+    Ok(())
+}
+
+/// Load a session that has not ended yet, requiring its game's
+/// authorization first when `game_auth` is set.
+fn load_active_session(env: &Env, session_id: u32, game_auth: bool) -> Result<GameSession, Error> {
+    let session: GameSession = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)?;
+
+    if game_auth {
+        session.game_id.require_auth();
+    }
+
+    if session.status != SessionStatus::Active {
+        return Err(Error::SessionAlreadyEnded);
+    }
+    Ok(session)
+}
+
+/// End an active session with `outcome`: release both stakes, pay out,
+/// update the standings and publish the matching event.
+fn settle_session(env: &Env, session_id: u32, mut session: GameSession, outcome: GameOutcome) {
+    let (status, player1_won) = match outcome {
+        GameOutcome::Player1 => (SessionStatus::Ended, Some(true)),
+        GameOutcome::Player2 => (SessionStatus::Ended, Some(false)),
+        GameOutcome::Draw => (SessionStatus::Drawn, None),
+        GameOutcome::Cancelled => (SessionStatus::Cancelled, None),
+    };
+    session.status = status;
+    session.player1_won = player1_won;
+    save_session(env, session_id, &session);
+
+    index_remove(env, DataKey::ActiveSessions(session.player1.clone()), session_id);
+    index_remove(env, DataKey::ActiveSessions(session.player2.clone()), session_id);
+
+    unlock_points(env, &session.player1, session.player1_points);
+    unlock_points(env, &session.player2, session.player2_points);
+    match player1_won {
+        Some(true) => add_balance(
+            env,
+            &session.player1,
+            session.player1_points + session.player2_points,
+        ),
+        Some(false) => add_balance(
+            env,
+            &session.player2,
+            session.player1_points + session.player2_points,
+        ),
+        None => {
+            // Each player gets back exactly what they staked
+            add_balance(env, &session.player1, session.player1_points);
+            add_balance(env, &session.player2, session.player2_points);
+        }
+    }
+    record_result(env, &session, outcome);
+
+    match outcome {
+        GameOutcome::Player1 | GameOutcome::Player2 => GameEnded {
+            session_id,
+            player1_won: outcome == GameOutcome::Player1,
+        }
+        .publish(env),
+        GameOutcome::Draw => GameDrawn { session_id }.publish(env),
+        GameOutcome::Cancelled => GameCancelled { session_id }.publish(env),
+    }
+}
+
+fn save_session(env: &Env, session_id: u32, session: &GameSession) {
+    let key = DataKey::Session(session_id);
+    env.storage().persistent().set(&key, session);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+}
+
 #[contractimpl]
 impl MockGameHub {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
     /// Start a game session
     ///
     /// # Arguments
-    /// * `game_id` - Address of the game contract calling this method
+    /// * `game_id` - Address of the game contract calling this method (must
+    ///   be registered and authorize the call)
     /// * `session_id` - Unique identifier for this game session
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
-    /// * `player1_points` - Points amount for player 1 (ignored in mock)
-    /// * `player2_points` - Points amount for player 2 (ignored in mock)
+    /// * `player1_points` - Points player 1 stakes (locked until end_game)
+    /// * `player2_points` - Points player 2 stakes (locked until end_game)
+    ///
+    /// # Errors
+    /// * `GameNotRegistered` - `game_id` is not an approved game
+    /// * `InjectedFault` - a fault mode covers this call
+    /// * `SessionAlreadyExists` - `session_id` was already used
+    /// * `SessionReserved` - another game reserved `session_id`
+    /// * `InvalidAmount` - a stake is negative
+    /// * `InsufficientBalance` - a player can't cover their stake (both
+    ///   stakes when a player plays against themselves)
     pub fn start_game(
         env: Env,
         game_id: Address,
@@ -45,8 +660,95 @@ impl MockGameHub {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) {
-        // No auth required for mock
+    ) -> Result<(), Error> {
+        game_id.require_auth();
+
+        if !load_games(&env).contains(&game_id) {
+            return Err(Error::GameNotRegistered);
+        }
+        let faults = load_faults(&env);
+        if faults.fail_start || faults.fail_session == Some(session_id) {
+            return Err(Error::InjectedFault);
+        }
+        if env.storage().persistent().has(&DataKey::Session(session_id)) {
+            return Err(Error::SessionAlreadyExists);
+        }
+        let reservation_key = DataKey::Reservation(session_id);
+        if let Some(owner) = env
+            .storage()
+            .persistent()
+            .get::<_, Address>(&reservation_key)
+        {
+            if owner != game_id {
+                return Err(Error::SessionReserved);
+            }
+            env.storage().persistent().remove(&reservation_key);
+        }
+        if player1_points < 0 || player2_points < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if player1 == player2 {
+            // Self-play: both stakes come out of the same balance
+            let total = player1_points
+                .checked_add(player2_points)
+                .ok_or(Error::InvalidAmount)?;
+            if read_points(&env, &DataKey::Balance(player1.clone())) < total {
+                return Err(Error::InsufficientBalance);
+            }
+        } else if read_points(&env, &DataKey::Balance(player1.clone())) < player1_points
+            || read_points(&env, &DataKey::Balance(player2.clone())) < player2_points
+        {
+            return Err(Error::InsufficientBalance);
+        }
+
+        lock_points(&env, &player1, player1_points);
+        lock_points(&env, &player2, player2_points);
+
+        // Fixed at start, so a later policy change can't alter a live session
+        let policy = load_game_policy(&env, &game_id);
+        save_session(
+            &env,
+            session_id,
+            &GameSession {
+                session_id,
+                game_id: game_id.clone(),
+                player1: player1.clone(),
+                player2: player2.clone(),
+                player1_points,
+                player2_points,
+                status: SessionStatus::Active,
+                player1_won: None,
+                deadline_ledger: env
+                    .ledger()
+                    .sequence()
+                    .saturating_add(policy.timeout_ledgers),
+                default_outcome: policy.default_outcome,
+            },
+        );
+
+        paged_push(
+            &env,
+            DataKey::GameSessionCount(game_id.clone()),
+            |page| DataKey::GameSessions(game_id.clone(), page),
+            session_id,
+        );
+        paged_push(
+            &env,
+            DataKey::PlayerSessionCount(player1.clone()),
+            |page| DataKey::PlayerSessions(player1.clone(), page),
+            session_id,
+        );
+        index_push(&env, DataKey::ActiveSessions(player1.clone()), session_id);
+        if player2 != player1 {
+            paged_push(
+                &env,
+                DataKey::PlayerSessionCount(player2.clone()),
+                |page| DataKey::PlayerSessions(player2.clone(), page),
+                session_id,
+            );
+            index_push(&env, DataKey::ActiveSessions(player2.clone()), session_id);
+        }
+
         GameStarted {
             session_id,
             game_id,
@@ -58,40 +760,361 @@ impl MockGameHub {
         .publish(&env);
         // bump instance ttl if required
         env.storage().instance().extend_ttl(17_280, 518_400);
+
+        Ok(())
+    }
+
+    /// Reserve the next free session id for `game_id`.
+    ///
+    /// Ids count up from 1, skipping any already started with a caller-chosen
+    /// id. Until it starts, a reserved id can only be used by `game_id`.
+    ///
+    /// # Errors
+    /// * `GameNotRegistered` - `game_id` is not an approved game
+    pub fn reserve_session(env: Env, game_id: Address) -> Result<u32, Error> {
+        game_id.require_auth();
+
+        if !load_games(&env).contains(&game_id) {
+            return Err(Error::GameNotRegistered);
+        }
+
+        let mut session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSessionId)
+            .unwrap_or(1);
+        while env.storage().persistent().has(&DataKey::Session(session_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::Reservation(session_id))
+        {
+            session_id += 1;
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSessionId, &(session_id + 1));
+
+        let reservation_key = DataKey::Reservation(session_id);
+        env.storage().persistent().set(&reservation_key, &game_id);
+        env.storage().persistent().extend_ttl(
+            &reservation_key,
+            SESSION_TTL_LEDGERS,
+            SESSION_TTL_LEDGERS,
+        );
+
+        SessionIdReserved {
+            session_id,
+            game_id,
+        }
+        .publish(&env);
+
+        Ok(session_id)
     }
 
     /// End a game session and declare winner
     ///
+    /// Both stakes are released and the whole pot is paid to the winner.
+    ///
+    /// Only the game that started the session may end it: the hub requires
+    /// that game's authorization, which a game contract calling directly
+    /// provides implicitly. Any other caller traps in the host's auth check
+    /// instead of receiving one of the errors below.
+    ///
     /// # Arguments
     /// * `session_id` - The game session being ended
     /// * `player1_won` - True if player1 won, false if player2 won
+    ///
+    /// # Errors
+    /// * `InjectedFault` - a fault mode covers this call
+    /// * `SessionNotFound` - no session was started with `session_id`
+    /// * `SessionAlreadyEnded` - the session was already ended
     pub fn end_game(
         env: Env,
         session_id: u32,
         player1_won: bool,
-    ) {
-        // No auth required for mock
-        GameEnded {
+    ) -> Result<(), Error> {
+        let outcome = if player1_won {
+            GameOutcome::Player1
+        } else {
+            GameOutcome::Player2
+        };
+        finish_session(&env, session_id, outcome)
+    }
+
+    /// End a game session with any outcome, including a draw or a
+    /// cancellation.
+    ///
+    /// A winner takes the whole pot as with `end_game`. A draw or a
+    /// cancellation refunds each player's own stake; only a draw counts in
+    /// the standings. Authorization and errors are the same as `end_game`.
+    pub fn end_game_v2(env: Env, session_id: u32, outcome: GameOutcome) -> Result<(), Error> {
+        finish_session(&env, session_id, outcome)
+    }
+
+    /// Settle a session its game never ended, once its deadline has passed.
+    ///
+    /// Anyone may call this. The session's `default_outcome`, taken from the
+    /// game's policy when it started, is applied exactly as if the game had
+    /// passed it to `end_game_v2`.
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - no session was started with `session_id`
+    /// * `SessionAlreadyEnded` - the session was already ended
+    /// * `DeadlineNotReached` - the current ledger is not past the deadline
+    pub fn force_settle(env: Env, session_id: u32) -> Result<GameOutcome, Error> {
+        let session = load_active_session(&env, session_id, false)?;
+        if env.ledger().sequence() <= session.deadline_ledger {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let outcome = session.default_outcome;
+        settle_session(&env, session_id, session, outcome);
+
+        SessionForceSettled {
             session_id,
-            player1_won,
+            outcome,
         }
         .publish(&env);
+        Ok(outcome)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
-    #[test]
-    fn test_start_and_end_game() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
-        let client = MockGameHubClient::new(&env, &contract_id);
-        let game_id = Address::generate(&env);
-        let player1 = Address::generate(&env);
-        let player2 = Address::generate(&env);
-        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
-        client.end_game(&1, &true);
+    /// Get a session by id.
+    pub fn get_session(env: Env, session_id: u32) -> Option<GameSession> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Session(session_id))
+    }
+
+    /// Get a page of the sessions a player took part in, oldest first
+    /// (limit capped at 50).
+    pub fn list_sessions_by_player(
+        env: Env,
+        player: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<GameSession> {
+        let ids = paged_range(
+            &env,
+            &DataKey::PlayerSessionCount(player.clone()),
+            |page| DataKey::PlayerSessions(player.clone(), page),
+            offset,
+            limit,
+        );
+        load_sessions(&env, &ids)
+    }
+
+    /// Number of sessions a player took part in.
+    pub fn get_player_session_count(env: Env, player: Address) -> u32 {
+        paged_count(&env, &DataKey::PlayerSessionCount(player))
+    }
+
+    /// Get a page of the sessions a game started, oldest first (limit
+    /// capped at 50).
+    pub fn list_sessions_by_game(
+        env: Env,
+        game_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<GameSession> {
+        let ids = paged_range(
+            &env,
+            &DataKey::GameSessionCount(game_id.clone()),
+            |page| DataKey::GameSessions(game_id.clone(), page),
+            offset,
+            limit,
+        );
+        load_sessions(&env, &ids)
+    }
+
+    /// Number of sessions a game started.
+    pub fn get_game_session_count(env: Env, game_id: Address) -> u32 {
+        paged_count(&env, &DataKey::GameSessionCount(game_id))
+    }
+
+    /// Get every session the player is in that has not ended yet.
+    pub fn get_active_sessions(env: Env, player: Address) -> Vec<GameSession> {
+        let index = load_index(&env, &DataKey::ActiveSessions(player));
+        load_sessions(&env, &index)
+    }
+
+    /// Get a player's standing overall and per game. Players who never
+    /// finished a session have no wins or losses and the initial rating.
+    pub fn get_standing(env: Env, player: Address) -> PlayerStanding {
+        load_standing(&env, &player)
+    }
+
+    /// Get a page of a game's top 100 players by rating, highest first
+    /// (limit capped at 50).
+    pub fn get_top_players(
+        env: Env,
+        game_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RankedPlayer> {
+        let ranking = load_ranking(&env, &game_id);
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(ranking.len());
+        for i in offset..end {
+            let (player, _) = ranking.get_unchecked(i);
+            let standing = load_standing(&env, &player)
+                .games
+                .get(game_id.clone())
+                .unwrap_or(new_standing());
+            page.push_back(RankedPlayer { player, standing });
+        }
+        page
+    }
+
+    /// Whether `game_id` is an approved game.
+    pub fn is_game(env: Env, game_id: Address) -> bool {
+        load_games(&env).contains(&game_id)
+    }
+
+    /// List the approved games in the order they were added.
+    pub fn get_games(env: Env) -> Vec<Address> {
+        load_games(&env)
+    }
+
+    /// Get the timeout and default outcome applied to a game's sessions.
+    pub fn get_game_policy(env: Env, game_id: Address) -> GamePolicy {
+        load_game_policy(&env, &game_id)
+    }
+
+    /// Get the fault modes currently injected.
+    pub fn get_faults(env: Env) -> Faults {
+        load_faults(&env)
+    }
+
+    /// Get a player's spendable points.
+    pub fn get_balance(env: Env, player: Address) -> i128 {
+        read_points(&env, &DataKey::Balance(player))
+    }
+
+    /// Get a player's points locked in active sessions.
+    pub fn get_locked(env: Env, player: Address) -> i128 {
+        read_points(&env, &DataKey::Locked(player))
+    }
+
+    // ── Admin functions ───────────────────────────────────────────────────────
+
+    /// Approve a game contract to start sessions. Adding it twice is a no-op.
+    pub fn add_game(env: Env, game_address: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut games = load_games(&env);
+        if games.contains(&game_address) {
+            return;
+        }
+        games.push_back(game_address.clone());
+        env.storage().instance().set(&DataKey::Games, &games);
+
+        GameAdded {
+            game_id: game_address,
+        }
+        .publish(&env);
+    }
+
+    /// Stop a game contract from starting sessions. Its active sessions can
+    /// still be ended.
+    pub fn remove_game(env: Env, game_address: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut games = load_games(&env);
+        let index = games
+            .first_index_of(&game_address)
+            .ok_or(Error::GameNotRegistered)?;
+        games.remove(index);
+        env.storage().instance().set(&DataKey::Games, &games);
+
+        GameRemoved {
+            game_id: game_address,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Faucet: credit spendable points to a player.
+    pub fn credit(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        add_balance(&env, &player, amount);
+
+        PointsCredited { player, amount }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the timeout and default outcome for a game's sessions, replacing
+    /// the default policy. Only the game itself may call this. The policy
+    /// applies to sessions started afterwards.
+    pub fn set_game_policy(env: Env, game_id: Address, policy: GamePolicy) -> Result<(), Error> {
+        game_id.require_auth();
+
+        if !load_games(&env).contains(&game_id) {
+            return Err(Error::GameNotRegistered);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::GamePolicy(game_id), &policy);
+        Ok(())
+    }
+
+    /// Replace the injected fault modes. `Faults::default()` clears them.
+    pub fn set_faults(env: Env, faults: Faults) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Faults, &faults);
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
     }
 }
+
+#[cfg(test)]
+mod test;
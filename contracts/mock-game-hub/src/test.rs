@@ -0,0 +1,653 @@
+#![cfg(test)]
+
+use crate::{
+    Error, Faults, GameOutcome, GamePolicy, GameSession, MockGameHub, MockGameHubClient,
+    SessionStatus, Standing,
+};
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, Ledger as _, MockAuth, MockAuthInvoke,
+};
+use soroban_sdk::{vec, Address, Env, IntoVal, InvokeError, Symbol, Vec};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, MockGameHubClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+
+    let game_id = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.credit(&player1, &10_000);
+    client.credit(&player2, &10_000);
+    client.add_game(&game_id);
+
+    (env, client, game_id, player1, player2)
+}
+
+// ============================================================================
+// Session Lifecycle Tests
+// ============================================================================
+
+#[test]
+fn test_start_and_end_game() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+    let session = client.get_session(&1).unwrap();
+    assert_eq!(session.game_id, game_id);
+    assert_eq!(session.player1_points, 1000);
+    assert_eq!(session.status, SessionStatus::Active);
+    assert_eq!(session.player1_won, None);
+
+    client.end_game(&1, &true);
+    let session = client.get_session(&1).unwrap();
+    assert_eq!(session.status, SessionStatus::Ended);
+    assert_eq!(session.player1_won, Some(true));
+}
+
+#[test]
+fn test_rejects_duplicate_session_id() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+    let result = client.try_start_game(&game_id, &1, &player2, &player1, &5, &5);
+    assert_eq!(result, Err(Ok(Error::SessionAlreadyExists)));
+}
+
+#[test]
+fn test_rejects_unknown_and_ended_sessions() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    assert_eq!(client.try_end_game(&7, &true), Err(Ok(Error::SessionNotFound)));
+
+    client.start_game(&game_id, &7, &player1, &player2, &1000, &1000);
+    client.end_game(&7, &false);
+    assert_eq!(client.try_end_game(&7, &true), Err(Ok(Error::SessionAlreadyEnded)));
+
+    // The first result stands
+    assert_eq!(client.get_session(&7).unwrap().player1_won, Some(false));
+}
+
+#[test]
+fn test_only_starting_game_can_end_session() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+
+    client.start_game(&game_id, &3, &player1, &player2, &1000, &1000);
+
+    // Another game's authorization does not satisfy the hub
+    env.mock_auths(&[MockAuth {
+        address: &other_game,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "end_game",
+            args: (3u32, true).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    // ...and the call traps in the host rather than returning a typed error
+    assert_eq!(client.try_end_game(&3, &true), Err(Err(InvokeError::Abort)));
+    assert_eq!(client.get_session(&3).unwrap().status, SessionStatus::Active);
+
+    env.mock_auths(&[MockAuth {
+        address: &game_id,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "end_game",
+            args: (3u32, true).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.end_game(&3, &true);
+    assert_eq!(client.get_session(&3).unwrap().status, SessionStatus::Ended);
+}
+
+// ============================================================================
+// Game Registry Tests
+// ============================================================================
+
+#[test]
+fn test_unregistered_game_cannot_start() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let rogue = Address::generate(&env);
+
+    let result = client.try_start_game(&rogue, &1, &player1, &player2, &100, &100);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+    assert_eq!(client.get_balance(&player1), 10_000);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+
+    // Removal blocks new sessions but the active one can still end
+    client.remove_game(&game_id);
+    assert!(!client.is_game(&game_id));
+    let result = client.try_start_game(&game_id, &2, &player1, &player2, &100, &100);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+    client.end_game(&1, &true);
+
+    assert_eq!(client.try_remove_game(&game_id), Err(Ok(Error::GameNotRegistered)));
+}
+
+#[test]
+fn test_start_game_requires_game_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, game_id);
+    match &auths[0].1.function {
+        AuthorizedFunction::Contract((contract, name, _)) => {
+            assert_eq!(contract, &client.address);
+            assert_eq!(name, &Symbol::new(&env, "start_game"));
+        }
+        _ => panic!("expected a contract call authorization"),
+    }
+}
+
+#[test]
+fn test_list_games() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let other_game = Address::generate(&env);
+
+    client.add_game(&other_game);
+    client.add_game(&game_id);
+    assert_eq!(client.get_games(), vec![&env, game_id.clone(), other_game.clone()]);
+    assert!(client.is_game(&other_game));
+
+    client.remove_game(&game_id);
+    assert_eq!(client.get_games(), vec![&env, other_game]);
+}
+
+// ============================================================================
+// Points Ledger Tests
+// ============================================================================
+
+#[test]
+fn test_stakes_locked_and_pot_paid_to_winner() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &1000, &500);
+    assert_eq!(client.get_balance(&player1), 9_000);
+    assert_eq!(client.get_locked(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 9_500);
+    assert_eq!(client.get_locked(&player2), 500);
+
+    client.end_game(&1, &false);
+    assert_eq!(client.get_balance(&player1), 9_000);
+    assert_eq!(client.get_locked(&player1), 0);
+    assert_eq!(client.get_balance(&player2), 11_000);
+    assert_eq!(client.get_locked(&player2), 0);
+}
+
+#[test]
+fn test_rejects_stake_above_balance() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let broke = Address::generate(&env);
+
+    let result = client.try_start_game(&game_id, &1, &player1, &broke, &1000, &1);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert!(client.get_session(&1).is_none());
+
+    // Locked points can't be staked twice
+    client.start_game(&game_id, &2, &player1, &player2, &6_000, &0);
+    let result = client.try_start_game(&game_id, &3, &player1, &player2, &6_000, &0);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(client.get_balance(&player1), 4_000);
+}
+
+#[test]
+fn test_self_play_stakes_come_from_one_balance() {
+    let (_env, client, game_id, player1, _player2) = setup_test();
+
+    // 6_000 + 6_000 exceeds the single 10_000 balance
+    let result = client.try_start_game(&game_id, &1, &player1, &player1, &6_000, &6_000);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(client.get_locked(&player1), 0);
+
+    client.start_game(&game_id, &2, &player1, &player1, &5_000, &5_000);
+    assert_eq!(client.get_balance(&player1), 0);
+    assert_eq!(client.get_locked(&player1), 10_000);
+}
+
+#[test]
+fn test_credit_validation() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    assert_eq!(client.try_credit(&player1, &0), Err(Ok(Error::InvalidAmount)));
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &-1, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    client.credit(&player1, &250);
+    assert_eq!(client.get_balance(&player1), 10_250);
+}
+
+// ============================================================================
+// Session Query Tests
+// ============================================================================
+
+#[test]
+fn test_sessions_indexed_by_player_and_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    let player3 = Address::generate(&env);
+    client.add_game(&other_game);
+    client.credit(&player3, &10_000);
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.start_game(&other_game, &2, &player1, &player3, &10, &10);
+    client.start_game(&game_id, &3, &player2, &player3, &10, &10);
+
+    let ids = |sessions: Vec<GameSession>| {
+        let mut ids = vec![&env];
+        for session in sessions.iter() {
+            ids.push_back(session.session_id);
+        }
+        ids
+    };
+    assert_eq!(ids(client.list_sessions_by_player(&player1, &0, &10)), vec![&env, 1u32, 2]);
+    assert_eq!(ids(client.list_sessions_by_player(&player3, &0, &10)), vec![&env, 2u32, 3]);
+    assert_eq!(ids(client.list_sessions_by_game(&game_id, &0, &10)), vec![&env, 1u32, 3]);
+    assert_eq!(ids(client.list_sessions_by_game(&other_game, &0, &10)), vec![&env, 2u32]);
+    assert_eq!(client.get_player_session_count(&player1), 2);
+    assert_eq!(client.get_game_session_count(&game_id), 2);
+    assert_eq!(ids(client.list_sessions_by_game(&game_id, &1, &1)), vec![&env, 3u32]);
+    assert_eq!(client.list_sessions_by_game(&game_id, &5, &10).len(), 0);
+}
+
+#[test]
+fn test_session_history_split_into_pages() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    // 120 sessions fill pages 0 and 1 and part of page 2
+    for session_id in 1..=120u32 {
+        client.start_game(&game_id, &session_id, &player1, &player2, &1, &1);
+    }
+    assert_eq!(client.get_player_session_count(&player1), 120);
+    assert_eq!(client.get_game_session_count(&game_id), 120);
+
+    let range = |sessions: Vec<GameSession>| {
+        (
+            sessions.len(),
+            sessions.first().map(|session| session.session_id),
+            sessions.last().map(|session| session.session_id),
+        )
+    };
+    assert_eq!(range(client.list_sessions_by_player(&player1, &0, &10)), (10, Some(1), Some(10)));
+    // Offsets spanning a page boundary read both pages
+    assert_eq!(range(client.list_sessions_by_player(&player1, &45, &10)), (10, Some(46), Some(55)));
+    assert_eq!(range(client.list_sessions_by_game(&game_id, &95, &50)), (25, Some(96), Some(120)));
+    // Limit is capped at 50
+    assert_eq!(range(client.list_sessions_by_game(&game_id, &30, &100)), (50, Some(31), Some(80)));
+    assert_eq!(client.list_sessions_by_player(&player2, &120, &10).len(), 0);
+    assert_eq!(client.list_sessions_by_player(&player2, &u32::MAX, &u32::MAX).len(), 0);
+}
+
+#[test]
+fn test_active_sessions_drop_when_ended() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.start_game(&game_id, &2, &player1, &player2, &10, &10);
+    assert_eq!(client.get_active_sessions(&player2).len(), 2);
+
+    client.end_game(&1, &true);
+    let active = client.get_active_sessions(&player1);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get_unchecked(0).session_id, 2);
+    assert_eq!(client.get_active_sessions(&player2).len(), 1);
+
+    // History keeps ended sessions
+    assert_eq!(client.list_sessions_by_player(&player1, &0, &10).len(), 2);
+    assert_eq!(client.get_active_sessions(&Address::generate(&env)).len(), 0);
+}
+
+// ============================================================================
+// Fault Injection Tests
+// ============================================================================
+
+#[test]
+fn test_fault_modes() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.set_faults(&Faults {
+        fail_start: true,
+        ..Faults::default()
+    });
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &10, &10);
+    assert_eq!(result, Err(Ok(Error::InjectedFault)));
+    // The fault stays armed until cleared
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &10, &10);
+    assert_eq!(result, Err(Ok(Error::InjectedFault)));
+
+    client.set_faults(&Faults {
+        fail_end: true,
+        ..Faults::default()
+    });
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    assert_eq!(client.try_end_game(&1, &true), Err(Ok(Error::InjectedFault)));
+    assert_eq!(client.get_locked(&player1), 10);
+
+    client.set_faults(&Faults::default());
+    client.end_game(&1, &true);
+}
+
+#[test]
+fn test_session_fault_only_hits_that_session() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.set_faults(&Faults {
+        fail_session: Some(1),
+        ..Faults::default()
+    });
+    assert_eq!(client.try_end_game(&1, &true), Err(Ok(Error::InjectedFault)));
+    assert_eq!(client.get_session(&1).unwrap().status, SessionStatus::Active);
+
+    client.start_game(&game_id, &2, &player1, &player2, &10, &10);
+    client.end_game(&2, &true);
+    assert_eq!(client.get_faults().fail_session, Some(1));
+}
+
+// ============================================================================
+// Standings Tests
+// ============================================================================
+
+#[test]
+fn test_standings_per_game_and_overall() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    // Even ratings move by half of K
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game(&1, &true);
+    let standing = client.get_standing(&player1);
+    assert_eq!(standing.overall, Standing { wins: 1, losses: 0, draws: 0, rating: 1216 });
+    assert_eq!(standing.games.get(game_id.clone()).unwrap().rating, 1216);
+    assert_eq!(client.get_standing(&player2).overall.rating, 1184);
+
+    // A new game starts from the initial rating; overall carries over
+    client.start_game(&other_game, &2, &player1, &player2, &10, &10);
+    client.end_game(&2, &false);
+    let standing = client.get_standing(&player2);
+    let other = standing.games.get(other_game.clone()).unwrap();
+    assert_eq!(other, Standing { wins: 1, losses: 0, draws: 0, rating: 1216 });
+    let first = standing.games.get(game_id).unwrap();
+    assert_eq!(first, Standing { wins: 0, losses: 1, draws: 0, rating: 1184 });
+    assert_eq!(standing.overall.wins, 1);
+    assert_eq!(standing.overall.losses, 1);
+
+    // The underdog's win moves ratings by more than half of K
+    assert_eq!(standing.overall.rating, 1184 + 17);
+    assert_eq!(client.get_standing(&player1).overall.rating, 1216 - 17);
+
+    let newcomer = client.get_standing(&Address::generate(&env));
+    assert_eq!(newcomer.overall.rating, 1200);
+    assert_eq!(newcomer.games.len(), 0);
+}
+
+#[test]
+fn test_top_players_by_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.credit(&player3, &10_000);
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game(&1, &false);
+    client.start_game(&game_id, &2, &player2, &player3, &10, &10);
+    client.end_game(&2, &true);
+    client.start_game(&game_id, &3, &player3, &player1, &10, &10);
+    client.end_game(&3, &true);
+
+    let top = client.get_top_players(&game_id, &0, &10);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.get_unchecked(0).player, player2);
+    assert_eq!(top.get_unchecked(0).standing.wins, 2);
+    for i in 1..top.len() {
+        assert!(top.get_unchecked(i - 1).standing.rating >= top.get_unchecked(i).standing.rating);
+    }
+
+    let page = client.get_top_players(&game_id, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0), top.get_unchecked(1));
+    assert_eq!(client.get_top_players(&Address::generate(&env), &0, &10).len(), 0);
+}
+
+#[test]
+fn test_ranking_keeps_top_players_only() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+
+    let mut winners = vec![&env];
+    for session_id in 1..=51u32 {
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        client.start_game(&game_id, &session_id, &winner, &loser, &0, &0);
+        client.end_game(&session_id, &true);
+        winners.push_back(winner);
+    }
+
+    // 102 players finished a session; the ranking keeps the best 100
+    let mut ranked = client.get_top_players(&game_id, &0, &50);
+    ranked.append(&client.get_top_players(&game_id, &50, &50));
+    assert_eq!(ranked.len(), 100);
+    assert_eq!(client.get_top_players(&game_id, &100, &50).len(), 0);
+    for i in 0..winners.len() {
+        assert!(winners.contains(ranked.get_unchecked(i).player));
+    }
+    for i in 1..ranked.len() {
+        let (higher, lower) = (ranked.get_unchecked(i - 1), ranked.get_unchecked(i));
+        assert!(higher.standing.rating >= lower.standing.rating);
+    }
+}
+
+// ============================================================================
+// Outcome Tests
+// ============================================================================
+
+#[test]
+fn test_draw_refunds_stakes_and_counts_in_standings() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &1000, &250);
+    client.end_game_v2(&1, &GameOutcome::Draw);
+
+    let session = client.get_session(&1).unwrap();
+    assert_eq!(session.status, SessionStatus::Drawn);
+    assert_eq!(session.player1_won, None);
+    assert_eq!(client.get_balance(&player1), 10_000);
+    assert_eq!(client.get_balance(&player2), 10_000);
+    assert_eq!(client.get_locked(&player1), 0);
+
+    // Even ratings don't move on a draw
+    let standing = client.get_standing(&player1).overall;
+    assert_eq!(standing, Standing { wins: 0, losses: 0, draws: 1, rating: 1200 });
+}
+
+#[test]
+fn test_draw_moves_ratings_toward_each_other() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game_v2(&1, &GameOutcome::Player1);
+    client.start_game(&game_id, &2, &player1, &player2, &10, &10);
+    client.end_game_v2(&2, &GameOutcome::Draw);
+
+    let favourite = client.get_standing(&player1).overall;
+    let underdog = client.get_standing(&player2).overall;
+    assert_eq!((favourite.wins, favourite.draws), (1, 1));
+    assert!(favourite.rating < 1216);
+    assert!(underdog.rating > 1184);
+    assert_eq!(favourite.rating + underdog.rating, 2400);
+    assert_eq!(client.get_session(&1).unwrap().player1_won, Some(true));
+}
+
+#[test]
+fn test_cancelled_session_is_refunded_and_not_ranked() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &400, &600);
+    client.end_game_v2(&1, &GameOutcome::Cancelled);
+
+    assert_eq!(client.get_session(&1).unwrap().status, SessionStatus::Cancelled);
+    assert_eq!(client.get_balance(&player2), 10_000);
+    assert_eq!(client.get_active_sessions(&player1).len(), 0);
+    assert_eq!(client.get_standing(&player1).overall.draws, 0);
+    assert_eq!(client.get_top_players(&game_id, &0, &10).len(), 0);
+
+    let result = client.try_end_game_v2(&1, &GameOutcome::Player1);
+    assert_eq!(result, Err(Ok(Error::SessionAlreadyEnded)));
+}
+
+// ============================================================================
+// Session Reservation Tests
+// ============================================================================
+
+#[test]
+fn test_reserved_ids_count_up_and_skip_used_ids() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    // A caller-chosen id is never handed out
+    client.start_game(&game_id, &2, &player1, &player2, &10, &10);
+
+    assert_eq!(client.reserve_session(&game_id), 1);
+    assert_eq!(client.reserve_session(&other_game), 3);
+    assert_eq!(client.reserve_session(&game_id), 4);
+
+    client.start_game(&other_game, &3, &player1, &player2, &10, &10);
+    assert_eq!(client.get_session(&3).unwrap().game_id, other_game);
+}
+
+#[test]
+fn test_reserved_id_belongs_to_reserving_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    let session_id = client.reserve_session(&game_id);
+    let result = client.try_start_game(&other_game, &session_id, &player1, &player2, &10, &10);
+    assert_eq!(result, Err(Ok(Error::SessionReserved)));
+
+    client.start_game(&game_id, &session_id, &player1, &player2, &10, &10);
+    let result = client.try_start_game(&game_id, &session_id, &player1, &player2, &10, &10);
+    assert_eq!(result, Err(Ok(Error::SessionAlreadyExists)));
+
+    let rogue = Address::generate(&env);
+    assert_eq!(client.try_reserve_session(&rogue), Err(Ok(Error::GameNotRegistered)));
+}
+
+// ============================================================================
+// Forced Settlement Tests
+// ============================================================================
+
+#[test]
+fn test_force_settle_refunds_after_timeout() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    // Games that never set a policy get the default one
+    assert_eq!(
+        client.get_game_policy(&game_id),
+        GamePolicy {
+            timeout_ledgers: 17_280,
+            default_outcome: GameOutcome::Cancelled,
+        }
+    );
+
+    client.start_game(&game_id, &1, &player1, &player2, &300, &700);
+    let deadline = client.get_session(&1).unwrap().deadline_ledger;
+    assert_eq!(deadline, env.ledger().sequence() + 17_280);
+
+    env.ledger().set_sequence_number(deadline);
+    assert_eq!(client.try_force_settle(&1), Err(Ok(Error::DeadlineNotReached)));
+
+    env.ledger().set_sequence_number(deadline + 1);
+    assert_eq!(client.force_settle(&1), GameOutcome::Cancelled);
+    assert_eq!(client.get_session(&1).unwrap().status, SessionStatus::Cancelled);
+    assert_eq!(client.get_balance(&player1), 10_000);
+    assert_eq!(client.get_locked(&player2), 0);
+
+    assert_eq!(client.try_force_settle(&1), Err(Ok(Error::SessionAlreadyEnded)));
+    assert_eq!(client.try_force_settle(&2), Err(Ok(Error::SessionNotFound)));
+}
+
+#[test]
+fn test_force_settle_applies_game_default_outcome() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let policy = GamePolicy {
+        timeout_ledgers: 10,
+        default_outcome: GameOutcome::Player2,
+    };
+    client.set_game_policy(&game_id, &policy);
+    assert_eq!(client.get_game_policy(&game_id), policy);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 11);
+
+    // No game authorization is needed once the deadline has passed
+    env.set_auths(&[]);
+    assert_eq!(client.force_settle(&1), GameOutcome::Player2);
+    assert_eq!(client.get_session(&1).unwrap().player1_won, Some(false));
+    assert_eq!(client.get_balance(&player2), 10_100);
+    assert_eq!(client.get_standing(&player2).overall.wins, 1);
+
+    env.mock_all_auths();
+    let result = client.try_set_game_policy(&Address::generate(&env), &policy);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+}
+
+#[test]
+fn test_session_keeps_policy_from_its_start() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.set_game_policy(
+        &game_id,
+        &GamePolicy {
+            timeout_ledgers: 10,
+            default_outcome: GameOutcome::Player1,
+        },
+    );
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+
+    // The running session keeps the default timeout and cancellation
+    env.ledger().set_sequence_number(env.ledger().sequence() + 11);
+    assert_eq!(client.try_force_settle(&1), Err(Ok(Error::DeadlineNotReached)));
+    assert_eq!(client.force_settle(&2), GameOutcome::Player1);
+
+    env.ledger().set_sequence_number(env.ledger().sequence() + 17_280);
+    assert_eq!(client.force_settle(&1), GameOutcome::Cancelled);
+    assert_eq!(client.get_balance(&player1), 10_100);
+}
+
+#[test]
+fn test_only_the_game_sets_its_policy() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let policy = GamePolicy {
+        timeout_ledgers: 10,
+        default_outcome: GameOutcome::Player1,
+    };
+
+    client.set_game_policy(&game_id, &policy);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, game_id);
+    match &auths[0].1.function {
+        AuthorizedFunction::Contract((_, name, _)) => {
+            assert_eq!(name, &Symbol::new(&env, "set_game_policy"));
+        }
+        _ => panic!("expected a contract call authorization"),
+    }
+
+    // Without the game's authorization the call traps
+    env.set_auths(&[]);
+    let result = client.try_set_game_policy(&game_id, &policy);
+    assert_eq!(result, Err(Err(InvokeError::Abort)));
+}
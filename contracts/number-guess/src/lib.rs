@@ -3,37 +3,18 @@
 //! # Number Guess Game
 //!
 //! A simple two-player guessing game where players guess a number between 1 and 10.
-//! The player whose guess is closest to the randomly generated number wins.
+//! The player whose guess is closest to the randomly generated number wins;
+//! equally close guesses are a draw and both players get their points back.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use sgs_common::{extend_temporary, GameOutcome};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror, contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -63,6 +44,7 @@ pub struct Game {
     pub player1_guess: Option<u32>,
     pub player2_guess: Option<u32>,
     pub winning_number: Option<u32>,
+    /// Set when the game ends; stays `None` on a draw
     pub winner: Option<Address>,
 }
 
@@ -70,20 +52,8 @@ pub struct Game {
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -100,10 +70,7 @@ impl NumberGuessContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_common::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -135,51 +102,53 @@ impl NumberGuessContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Create game (winning_number not set yet - will be generated in reveal_winner)
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        Self::open_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_guess: None,
-            player2_guess: None,
-            winning_number: None,
-            winner: None,
-        };
+        )
+    }
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+    /// Start a new game like `start_game`, with the session ID issued by the
+    /// Game Hub instead of chosen by the caller.
+    ///
+    /// Players authorize only their points, since the session ID isn't known
+    /// until the hub issues it.
+    ///
+    /// # Returns
+    /// * `u32` - The session ID issued by the Game Hub
+    pub fn start_game_reserved(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
+        }
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player2_points.into_val(&env)]);
 
-        // Event emitted by the Game Hub contract (GameStarted)
+        // Ask the Game Hub for the next free session ID
+        let session_id = sgs_common::hub_reserve_session(&env);
 
-        Ok(())
+        Self::open_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        )?;
+
+        Ok(session_id)
     }
 
     /// Make a guess for the current game.
@@ -193,7 +162,7 @@ impl NumberGuessContract {
         player.require_auth();
 
         // Validate guess is in range
-        if guess < 1 || guess > 10 {
+        if !(1..=10).contains(&guess) {
             panic!("Guess must be between 1 and 10");
         }
 
@@ -205,8 +174,8 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (winning number not drawn yet)
+        if game.winning_number.is_some() {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -241,8 +210,8 @@ impl NumberGuessContract {
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` on a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -251,9 +220,9 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended (winning number drawn)
+        if game.winning_number.is_some() {
+            return Ok(game.winner);
         }
 
         // Check both players have guessed
@@ -293,44 +262,25 @@ impl NumberGuessContract {
         game.winning_number = Some(winning_number);
 
         // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
-        } else {
-            winning_number - guess1
-        };
+        let distance1 = guess1.abs_diff(winning_number);
 
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
-        };
+        let distance2 = guess2.abs_diff(winning_number);
 
-        // Determine winner (if equal distance, player1 wins)
-        let winner = if distance1 <= distance2 {
-            game.player1.clone()
-        } else {
-            game.player2.clone()
+        // Determine winner (equal distance is a draw)
+        let (outcome, winner) = match distance1.cmp(&distance2) {
+            core::cmp::Ordering::Less => (GameOutcome::Player1, Some(game.player1.clone())),
+            core::cmp::Ordering::Greater => (GameOutcome::Player2, Some(game.player2.clone())),
+            core::cmp::Ordering::Equal => (GameOutcome::Draw, None),
         };
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
+        // Update game with winner (the winning number marks the game as ended)
+        game.winner = winner.clone();
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
         // Call GameHub to end the session
-        // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        // This unlocks points (refunding both on a draw) and updates standings
+        // Event emitted by the Game Hub contract (GameEnded / GameDrawn)
+        sgs_common::hub_end_game_v2(&env, session_id, outcome);
 
         Ok(winner)
     }
@@ -359,10 +309,7 @@ impl NumberGuessContract {
     /// # Returns
     /// * `Address` - The admin address
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        sgs_common::get_admin(&env)
     }
 
     /// Set a new admin address
@@ -370,14 +317,7 @@ impl NumberGuessContract {
     /// # Arguments
     /// * `new_admin` - The new admin address
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        sgs_common::set_admin(&env, &new_admin);
     }
 
     /// Get the current GameHub contract address
@@ -385,10 +325,7 @@ impl NumberGuessContract {
     /// # Returns
     /// * `Address` - The GameHub contract address
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        sgs_common::get_hub(&env)
     }
 
     /// Set a new GameHub contract address
@@ -396,16 +333,7 @@ impl NumberGuessContract {
     /// # Arguments
     /// * `new_hub` - The new GameHub contract address
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        sgs_common::set_hub(&env, &new_hub);
     }
 
     /// Update the contract WASM hash (upgrade contract)
@@ -413,14 +341,55 @@ impl NumberGuessContract {
     /// # Arguments
     /// * `new_wasm_hash` - The hash of the new WASM binary
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        sgs_common::upgrade(&env, new_wasm_hash);
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    /// Helper to start the session with the Game Hub and store the new game
+    fn open_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Call Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        sgs_common::hub_start_game(
+            env,
+            session_id,
+            &player1,
+            &player2,
+            player1_points,
+            player2_points,
+        );
+
+        // Create game (winning_number not set yet - will be generated in reveal_winner)
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_guess: None,
+            player2_guess: None,
+            winning_number: None,
+            winner: None,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
+
+        // Set TTL to ensure game is retained for at least 30 days
+        extend_temporary(env, &game_key);
+
+        // Event emitted by the Game Hub contract (GameStarted)
+
+        Ok(())
     }
 }
 
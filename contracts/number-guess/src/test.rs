@@ -7,8 +7,9 @@
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
 use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use sgs_common::GameOutcome;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -35,9 +36,25 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_v2(env: Env, session_id: u32, outcome: GameOutcome) {
+        // Mock implementation - records the outcome for assertions
+        env.storage().instance().set(&session_id, &outcome);
+    }
+
+    pub fn get_outcome(env: Env, session_id: u32) -> Option<GameOutcome> {
+        env.storage().instance().get(&session_id)
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
+
+    pub fn reserve_session(env: Env, _game_id: Address) -> u32 {
+        // Mock implementation - hands out 1, 2, 3, ...
+        let next: u32 = env.storage().instance().get(&symbol_short!("next")).unwrap_or(0) + 1;
+        env.storage().instance().set(&symbol_short!("next"), &next);
+        next
+    }
 }
 
 // ============================================================================
@@ -162,12 +179,12 @@ fn test_complete_game() {
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
 
-    // Make guesses
+    // Make guesses (no number is equally close to 5 and 8, so no draw)
     client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    client.make_guess(&session_id, &player2, &8);
 
     // Reveal winner
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     assert!(winner == player1 || winner == player2);
 
     // Verify game is ended and winning number is now set
@@ -176,7 +193,7 @@ fn test_complete_game() {
     assert_eq!(final_game.winner.unwrap(), winner);
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
 }
 
 #[test]
@@ -196,7 +213,7 @@ fn test_winning_number_in_range() {
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -221,6 +238,20 @@ fn test_multiple_sessions() {
     assert_eq!(game2.player1, player3);
 }
 
+#[test]
+fn test_start_game_reserved_uses_hub_session_ids() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let first = client.start_game_reserved(&player1, &player2, &100_0000000, &100_0000000);
+    let second = client.start_game_reserved(&player2, &player1, &50_0000000, &50_0000000);
+    assert_eq!((first, second), (1, 2));
+
+    let game = client.get_game(&first);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.player1_points, 100_0000000);
+    assert_eq!(client.get_game(&second).player1, player2);
+}
+
 // ============================================================================
 // Guess Logic Tests
 // ============================================================================
@@ -244,31 +275,24 @@ fn test_closest_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(10);
 
-    let expected_winner = if distance1 <= distance2 {
+    let expected_winner = if distance1 < distance2 {
         player1.clone()
     } else {
         player2.clone()
     };
     assert_eq!(
-        winner, expected_winner,
+        winner,
+        Some(expected_winner),
         "Player with closer guess should win"
     );
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_tie_game_is_draw() {
+    let (_env, client, hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
@@ -277,13 +301,20 @@ fn test_tie_game_player1_wins() {
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &5);
 
-    let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+    assert_eq!(client.reveal_winner(&session_id), None, "A tie has no winner");
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Draw));
+
+    // The game has ended without a winner
+    let game = client.get_game(&session_id);
+    assert!(game.winning_number.is_some());
+    assert!(game.winner.is_none());
+    let result = client.try_make_guess(&session_id, &player1, &3);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
 }
 
 #[test]
 fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (_env, client, hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
@@ -298,22 +329,15 @@ fn test_exact_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(10);
+    let (expected_winner, expected_outcome) = if distance1 < distance2 {
+        (player1.clone(), GameOutcome::Player1)
     } else {
-        winning_number - 5
+        (player2.clone(), GameOutcome::Player2)
     };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(winner, expected_winner);
+    assert_eq!(winner, Some(expected_winner));
+    assert_eq!(hub.get_outcome(&session_id), Some(expected_outcome));
 }
 
 // ============================================================================
@@ -434,15 +458,15 @@ fn test_cannot_reveal_twice() {
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    client.make_guess(&session_id, &player2, &8);
 
     // First reveal succeeds
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     assert!(winner == player1 || winner == player2);
 
     // Second reveal should return same winner (idempotent)
     let winner2 = client.reveal_winner(&session_id);
-    assert_eq!(winner, winner2);
+    assert_eq!(Some(winner), winner2);
 }
 
 // ============================================================================
@@ -465,12 +489,12 @@ fn test_multiple_games_independent() {
     // Play both games independently
     client.make_guess(&session1, &player1, &3);
     client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
+    client.make_guess(&session1, &player2, &8);
+    client.make_guess(&session2, &player4, &1);
 
     // Reveal both winners
-    let winner1 = client.reveal_winner(&session1);
-    let winner2 = client.reveal_winner(&session2);
+    let winner1 = client.reveal_winner(&session1).unwrap();
+    let winner2 = client.reveal_winner(&session2).unwrap();
 
     assert!(winner1 == player1 || winner1 == player2);
     assert!(winner2 == player3 || winner2 == player4);
@@ -505,9 +529,9 @@ fn test_asymmetric_points() {
     client.make_guess(&session_id, &player2, &5);
     client.reveal_winner(&session_id);
 
-    // Game completes successfully with asymmetric points
+    // Game completes successfully with asymmetric points (a draw here)
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    assert!(final_game.winning_number.is_some()); // Game has ended
 }
 
 // ============================================================================
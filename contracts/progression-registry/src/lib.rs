@@ -0,0 +1,244 @@
+#![no_std]
+
+//! # Source Agents Progression Registry
+//!
+//! Shared record of what each player has achieved with each character across
+//! the Source Agent games (BIRTH first, then ALICE, BYRON and CAROL).
+//!
+//! **Writers:**
+//!   Only game contracts allowlisted by the registry admin may record
+//!   milestones. A game records as itself, so the game contract's own
+//!   authorization is required.
+//!
+//! **Milestones:**
+//!   A milestone is a `Symbol` such as `embodied`, `overloaded` or
+//!   `mastered_hearing`, recorded per `(player, character)`. The registry
+//!   keeps when it was first and last recorded, by which game, and how often.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, BytesN, Env, Map,
+    Symbol, Vec,
+};
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent(topics = ["milestone"])]
+pub struct MilestoneRecordedEvent {
+    pub game: Address,
+    pub player: Address,
+    pub character: u32,
+    pub milestone: Symbol,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    WriterNotAllowed = 1,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// History of one milestone for a `(player, character)` pair.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneRecord {
+    pub first_game: Address,       // Game that first recorded the milestone
+    pub last_game: Address,        // Game that most recently recorded it
+    pub first_recorded_at: u64,
+    pub last_recorded_at: u64,
+    pub count: u32,                // Times the milestone has been recorded
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Admin address (manages the writer allowlist, can upgrade)
+    Admin,
+    /// Allowlisted game contract
+    Writer(Address),
+    /// Milestones keyed by (player, character): Map<Symbol, MilestoneRecord>
+    Progress(Address, u32),
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Storage TTL — 30 days in ledgers (~5 s/ledger).
+const PROGRESS_TTL_LEDGERS: u32 = 518_400;
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct ProgressionRegistry;
+
+#[contractimpl]
+impl ProgressionRegistry {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ── record_milestones ─────────────────────────────────────────────────────
+    /// Record milestones for a player's character on behalf of `game`.
+    ///
+    /// `game` must be allowlisted and must authorize the call — a game
+    /// contract calling directly authorizes as itself.
+    pub fn record_milestones(
+        env: Env,
+        game: Address,
+        player: Address,
+        character: u32,
+        milestones: Vec<Symbol>,
+    ) -> Result<(), Error> {
+        game.require_auth();
+
+        if !env
+            .storage()
+            .instance()
+            .has(&DataKey::Writer(game.clone()))
+        {
+            return Err(Error::WriterNotAllowed);
+        }
+
+        let progress_key = DataKey::Progress(player.clone(), character);
+        let mut progress: Map<Symbol, MilestoneRecord> = env
+            .storage()
+            .persistent()
+            .get(&progress_key)
+            .unwrap_or(Map::new(&env));
+        let now = env.ledger().timestamp();
+
+        for milestone in milestones.iter() {
+            let record = match progress.get(milestone.clone()) {
+                Some(mut record) => {
+                    record.last_game = game.clone();
+                    record.last_recorded_at = now;
+                    record.count = record.count.saturating_add(1);
+                    record
+                }
+                None => MilestoneRecord {
+                    first_game: game.clone(),
+                    last_game: game.clone(),
+                    first_recorded_at: now,
+                    last_recorded_at: now,
+                    count: 1,
+                },
+            };
+            progress.set(milestone.clone(), record);
+
+            MilestoneRecordedEvent {
+                game: game.clone(),
+                player: player.clone(),
+                character,
+                milestone,
+            }
+            .publish(&env);
+        }
+
+        env.storage().persistent().set(&progress_key, &progress);
+        env.storage()
+            .persistent()
+            .extend_ttl(&progress_key, PROGRESS_TTL_LEDGERS, PROGRESS_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    // ── Read-only helpers ─────────────────────────────────────────────────────
+
+    /// Get every milestone recorded for a player's character.
+    pub fn get_milestones(env: Env, player: Address, character: u32) -> Map<Symbol, MilestoneRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Progress(player, character))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Get one milestone for a player's character, if it was ever recorded.
+    pub fn get_milestone(
+        env: Env,
+        player: Address,
+        character: u32,
+        milestone: Symbol,
+    ) -> Option<MilestoneRecord> {
+        Self::get_milestones(env, player, character).get(milestone)
+    }
+
+    /// Whether a milestone was ever recorded for a player's character.
+    pub fn has_milestone(env: Env, player: Address, character: u32, milestone: Symbol) -> bool {
+        Self::get_milestones(env, player, character).contains_key(milestone)
+    }
+
+    /// Whether `game` may record milestones.
+    pub fn is_writer(env: Env, game: Address) -> bool {
+        env.storage().instance().has(&DataKey::Writer(game))
+    }
+
+    // ── Admin functions ───────────────────────────────────────────────────────
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Allow a game contract to record milestones.
+    pub fn allow_writer(env: Env, game: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Writer(game), &true);
+    }
+
+    /// Stop a game contract from recording milestones. Its past records stay.
+    pub fn revoke_writer(env: Env, game: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().remove(&DataKey::Writer(game));
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
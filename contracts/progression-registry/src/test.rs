@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+// Unit tests for the progression registry. Game contracts are stood in for by
+// generated addresses with all auths mocked.
+
+use crate::{Error, ProgressionRegistry, ProgressionRegistryClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, Symbol};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, ProgressionRegistryClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1441065600);
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ProgressionRegistry, (&admin,));
+    let client = ProgressionRegistryClient::new(&env, &contract_id);
+
+    let game = Address::generate(&env);
+    let player = Address::generate(&env);
+
+    (env, client, game, player)
+}
+
+// ============================================================================
+// Milestone Tests
+// ============================================================================
+
+#[test]
+fn test_allowlisted_game_records_milestones() {
+    let (env, client, game, player) = setup_test();
+    let embodied = Symbol::new(&env, "embodied");
+    let mastered = Symbol::new(&env, "mastered_hearing");
+
+    client.allow_writer(&game);
+    assert!(client.is_writer(&game));
+
+    client.record_milestones(&game, &player, &1, &vec![&env, embodied.clone(), mastered.clone()]);
+
+    let milestones = client.get_milestones(&player, &1);
+    assert_eq!(milestones.len(), 2);
+    assert!(client.has_milestone(&player, &1, &mastered));
+    assert!(!client.has_milestone(&player, &0, &embodied));
+
+    let record = client.get_milestone(&player, &1, &embodied).unwrap();
+    assert_eq!(record.first_game, game);
+    assert_eq!(record.count, 1);
+    assert_eq!(record.first_recorded_at, 1441065600);
+}
+
+#[test]
+fn test_repeat_milestones_update_history() {
+    let (env, client, game, player) = setup_test();
+    let other_game = Address::generate(&env);
+    let embodied = Symbol::new(&env, "embodied");
+
+    client.allow_writer(&game);
+    client.allow_writer(&other_game);
+
+    client.record_milestones(&game, &player, &2, &vec![&env, embodied.clone()]);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.record_milestones(&other_game, &player, &2, &vec![&env, embodied.clone()]);
+
+    let record = client.get_milestone(&player, &2, &embodied).unwrap();
+    assert_eq!(record.count, 2);
+    assert_eq!(record.first_game, game);
+    assert_eq!(record.last_game, other_game);
+    assert_eq!(record.last_recorded_at, record.first_recorded_at + 100);
+}
+
+#[test]
+fn test_unlisted_game_cannot_record() {
+    let (env, client, game, player) = setup_test();
+    let milestones = vec![&env, Symbol::new(&env, "embodied")];
+
+    let result = client.try_record_milestones(&game, &player, &0, &milestones);
+    assert_eq!(result, Err(Ok(Error::WriterNotAllowed)));
+
+    client.allow_writer(&game);
+    client.record_milestones(&game, &player, &0, &milestones);
+
+    client.revoke_writer(&game);
+    assert!(!client.is_writer(&game));
+    let result = client.try_record_milestones(&game, &player, &0, &milestones);
+    assert_eq!(result, Err(Ok(Error::WriterNotAllowed)));
+
+    // Earlier records survive revocation
+    assert_eq!(client.get_milestones(&player, &0).len(), 1);
+}
@@ -1,4 +1,5 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 //! # SA:BIRTH Calibration Contract
 //!
@@ -12,37 +13,81 @@
 //! **Game Hub Integration:**
 //!   - `start_game`  → locks both players' points in the Hub
 //!   - `end_game`    → releases points to the winner
-//!     Player 1 wins when all 6 senses are completed with total_score ≤ SCORE_CAP.
-//!     Otherwise the house (Player 2) wins.
+//!     Player 1 wins when all 6 senses are completed with total_score ≤ the
+//!     run's tier cap. Otherwise the house (Player 2) wins.
+//!
+//! **Race Mode:**
+//!   Two human players stake against each other via `start_race`, play the
+//!   same six mazes (seeded by the shared session id) and `settle_race` pays
+//!   the better run through the hub. A deadline stops a race from stalling.
+//!
+//! **Ghost Challenges:**
+//!   Every successful exit leaves a ghost: a snapshot of its per-sense scores.
+//!   `start_challenge` stakes against the ghost's owner instead of the house;
+//!   the challenger wins only by beating every recorded sense score.
+//!
+//! **Embodiment Credentials:**
+//!   A successful exit issues (or improves) a non-transferable credential per
+//!   player and character. Other Source Agent contracts read it through the
+//!   `EmbodimentCredentials` interface.
+//!
+//! **Progression Registry:**
+//!   When the admin configures a registry (`set_registry`), every exit records
+//!   the run's milestones there (`embodied`, `overloaded`, mastered senses) so
+//!   later Source Agent games can build on BIRTH.
+//!
+//! **Achievements:**
+//!   Ranked runs are checked against an admin-editable table of parameterised
+//!   rules at each submission and exit. Unlocks are kept as a per-player
+//!   bitset (`get_achievements`) and announced with `AchievementUnlockedEvent`.
+//!
+//! **Difficulty Tiers:**
+//!   Standard (0), Novice (1) and Overclock (2). Each tier has its own score
+//!   cap, minimum stake and payout multiplier (`TierConfig`), is chosen at
+//!   `start_game_with_tier` and forms its own leaderboard bracket.
 //!
 //! **ZK Leaderboard:**
 //!   Every submitted sense includes a Noir UltraHonk proof that score == points × time_ms
 //!   and score ≤ score_cap.  The proof is stored on-chain for auditability.
-//!   attempt_exit adds successful runs to the leaderboard, sorted ascending (lower = better).
+//!   Alternatively a run may skip the per-sense proofs and present one aggregated
+//!   proof over all six scores at exit time via `attempt_exit_with_proof`.
+//!   attempt_exit adds successful runs to the leaderboard, kept sorted ascending
+//!   (lower = better) on insert so rank and page queries never re-sort.
 
+use sgs_common::{extend_persistent, GameHubClient, GameOutcome};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, Symbol,
+    Address, Bytes, BytesN, Env, IntoVal, InvokeError, Map, Symbol, TryFromVal, Val,
     contract, contractclient, contracterror, contractevent, contractimpl, contracttype,
-    vec, Vec,
+    vec, xdr::ToXdr, Vec,
 };
 
 // ============================================================================
-// Game Hub cross-contract interface  (matches mock-game-hub exported ABI)
+// Progression registry interface  (matches progression-registry exported ABI)
 // ============================================================================
 
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
+#[contractclient(name = "ProgressionRegistryClient")]
+pub trait ProgressionRegistry {
+    fn record_milestones(
         env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
+        game: Address,
+        player: Address,
+        character: u32,
+        milestones: Vec<Symbol>,
     );
+}
+
+// ============================================================================
+// Embodiment credential interface  (stable, read-only — for other SA games)
+// ============================================================================
+
+/// Read-only view of sa-birth credentials for downstream Source Agent
+/// contracts. Keep these signatures stable; add new methods instead of
+/// changing existing ones.
+#[contractclient(name = "EmbodimentCredentialsClient")]
+pub trait EmbodimentCredentials {
+    fn get_credential(env: Env, player: Address, character: u32) -> Option<EmbodimentCredential>;
 
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
+    fn has_credential(env: Env, player: Address, character: u32) -> bool;
 }
 
 // ============================================================================
@@ -65,6 +110,14 @@ pub struct SenseCompletedEvent {
     // ZK verification is done client-side; leaderboard shows verified completions
 }
 
+#[contractevent(topics = ["sense_failed"])]
+pub struct SenseFailedEvent {
+    pub sense_id: u32,
+    pub failures: u32,
+    pub penalty: u64,
+    pub can_retry: bool,
+}
+
 #[contractevent(topics = ["complete"])]
 pub struct CompleteEvent {
     pub character: u32,
@@ -77,6 +130,48 @@ pub struct OverloadEvent {
     pub total_score: u64,
 }
 
+#[contractevent(topics = ["race_settled"])]
+pub struct RaceSettledEvent {
+    pub session_id: u32,
+    pub outcome: GameOutcome,
+    pub player1_score: u64,
+    pub player2_score: u64,
+}
+
+#[contractevent(topics = ["challenge"])]
+pub struct ChallengeEvent {
+    pub ghost_session_id: u32,
+    pub owner: Address,
+    pub challenger_won: bool,
+}
+
+#[contractevent(topics = ["credential"])]
+pub struct CredentialEvent {
+    pub player: Address,
+    pub character: u32,
+    pub best_score: u64,
+}
+
+#[contractevent(topics = ["achievement_unlocked"])]
+pub struct AchievementUnlockedEvent {
+    pub player: Address,
+    pub achievement_id: u32,
+}
+
+#[contractevent(topics = ["milestones_failed"])]
+pub struct MilestonesFailedEvent {
+    pub player: Address,
+    pub registry: Address,
+    pub milestones: Vec<Symbol>,
+}
+
+#[contractevent(topics = ["run_proof"])]
+pub struct RunProofEvent {
+    pub character: u32,
+    pub total_score: u64,
+    pub proof_digest: BytesN<32>,
+}
+
 // ============================================================================
 // Errors
 // ============================================================================
@@ -100,6 +195,23 @@ pub enum Error {
     SessionNotActive = 10,
     HubCallFailed = 11,
     OverloadExceeded = 12,
+    ProofRequired = 13,
+    MazeCommitmentMismatch = 14,
+    SessionKeyNotSet = 15,
+    InvalidNonce = 16,
+    CooldownActive = 17,
+    SessionQuotaExceeded = 18,
+    InvalidTier = 19,
+    InvalidStake = 20,
+    DeadlineNotReached = 21,
+    DeadlinePassed = 22,
+    InvalidDeadline = 23,
+    AchievementTableFull = 24,
+    InvalidAchievement = 25,
+    SenseLocked = 26,
+    PrerequisiteCycle = 27,
+    RetriesExhausted = 28,
+    GhostNotPublished = 29,
 }
 
 // ============================================================================
@@ -120,6 +232,15 @@ pub struct CalibrationSession {
     pub player1_points: i128,      // Points committed by Player 1
     pub player2_points: i128,      // Points committed by Player 2 (house stake)
     pub active: bool,
+    pub proven_senses: u32,        // Bitfield: senses submitted with their own proof
+    pub session_key: Option<BytesN<32>>, // ed25519 key allowed to sign relayed submissions
+    pub next_nonce: u64,           // Nonce the next relayed submission must carry
+    pub tier: u32,                 // 0=Standard, 1=Novice, 2=Overclock
+    pub ghost: Option<u32>,        // Session id of the ghost run being challenged
+    pub sense_failures: Map<u32, u32>, // sense_id → failures reported via fail_sense
+    pub retry_penalty: u64,        // Total retry penalty included in total_score
+    pub score_cap: u64,            // Tier score cap when the run started
+    pub payout_bps: u32,           // Tier payout when the run started
 }
 
 /// Per-sense result stored on-chain.
@@ -128,9 +249,109 @@ pub struct CalibrationSession {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SenseResult {
     pub sense_id: u32,
+    pub character: u32,            // Character whose maze was proven
+    pub points: u64,
+    pub time_ms: u64,
+    pub hazards: u32,              // Hazards hit; each adds HAZARD_PENALTY_MS to time_ms
+    pub score: u64,
+    pub maze_root: BytesN<32>,     // Maze commitment the proof was generated against
+    pub proof_digest: BytesN<32>,  // keccak256 of the submitted proof bytes (may be empty)
+}
+
+/// Off-chain payload a player signs with their session key so that any
+/// relayer can submit the sense on their behalf (see `submit_sense_signed`).
+/// The signed message is the XDR encoding of this struct. It names the
+/// contract and player so a signature can't be replayed on another
+/// deployment or on another player's session with the same id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedSensePayload {
+    pub contract: Address,
+    pub player: Address,
+    pub session_id: u32,
+    pub sense_id: u32,
+    pub maze_id: u32,
+    pub maze_root: BytesN<32>,
     pub points: u64,
     pub time_ms: u64,
+    pub hazards: u32,
     pub score: u64,
+    pub proof_digest: BytesN<32>, // keccak256 of the proof bytes sent alongside
+    pub nonce: u64,
+}
+
+/// Public inputs of the aggregated whole-run circuit.
+/// `sense_scores` is indexed by sense_id and must hold exactly 6 entries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RunPublicInputs {
+    pub sense_scores: Vec<u64>,
+    pub total_score: u64,
+    pub score_cap: u64,
+    pub character: u32,
+}
+
+/// Aggregated proof accepted at exit, kept for off-chain auditability.
+/// Only the keccak256 digest of the proof bytes is stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RunProof {
+    pub session_id: u32,
+    pub inputs: RunPublicInputs,
+    pub proof_digest: BytesN<32>,
+}
+
+/// Rules for one difficulty tier, set by the admin.
+///
+/// The house stake must equal `player1_points × payout_bps / 10_000`, so a
+/// winning player takes home their stake plus that multiple of it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierConfig {
+    pub score_cap: u64,   // Max total_score for a win in this tier
+    pub min_stake: i128,  // Minimum player1_points
+    pub payout_bps: u32,  // House stake as basis points of the player's stake
+}
+
+/// How failed senses may be retried, set by the admin.
+///
+/// Each sense may be failed and retried `max_retries` times, each retry
+/// adding `penalty` to the run's total_score. One more failure locks the
+/// sense for the rest of the run. The default (0, 0) keeps senses one-shot.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub penalty: u64,
+}
+
+/// Per-player rate limits on `start_game`, set by the admin.
+/// A zero field disables that limit; all zeros (the default) means unlimited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimits {
+    pub min_ledger_gap: u32,  // Minimum ledgers between two session starts
+    pub max_sessions: u32,    // Max session starts per rolling window
+    pub window_ledgers: u32,  // Length of the rolling window in ledgers
+}
+
+/// Recent session starts for one player, used to enforce `RateLimits`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StartHistory {
+    pub last_start_ledger: u32,
+    pub recent_starts: Vec<u32>, // Start ledgers inside the window, oldest first
+}
+
+/// Snapshot returned by `get_limits_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LimitsStatus {
+    pub current_ledger: u32,
+    pub next_allowed_ledger: u32,
+    pub sessions_in_window: u32,
+    pub max_sessions: u32,
+    pub can_start: bool,
 }
 
 /// Leaderboard entry — only successful (score ≤ cap) exits are recorded.
@@ -141,309 +362,2265 @@ pub struct LeaderboardEntry {
     pub character: u32,
     pub total_score: u64,
     pub timestamp: u64,
+    pub tier: u32,
+    pub session_id: u32,           // Ghost id to pass to `start_challenge`
+    pub retries: u32,              // Sense retries taken during the run
+    pub retry_penalty: u64,        // Retry penalty included in total_score
+}
+
+/// Storage layouts written before `SCHEMA_VERSION` 1. Sessions and sense
+/// results are converted when read; the leaderboard is rewritten by `migrate`.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyCalibrationSession {
+    pub player: Address,
+    pub player2: Address,
+    pub character: u32,
+    pub completed_senses: u32,
+    pub total_score: u64,
+    pub session_id: u32,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub active: bool,
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacySenseResult {
+    pub sense_id: u32,
+    pub points: u64,
+    pub time_ms: u64,
+    pub score: u64,
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyLeaderboardEntry {
+    pub player: Address,
+    pub character: u32,
+    pub total_score: u64,
+    pub timestamp: u64,
+}
+
+/// Soulbound record of a player's embodiment as one character.
+///
+/// Issued on the first successful exit and updated when a later run beats
+/// `best_score`. There is no transfer or burn entry point.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmbodimentCredential {
+    pub player: Address,
+    pub character: u32,
+    pub best_score: u64,
+    pub tier: u32,                 // Tier of the best run
+    pub session_id: u32,           // Session of the best run
+    pub proof_digest: BytesN<32>,  // Proof digest of the best run
+    pub earned_at: u64,            // Timestamp of the best run
+    pub first_earned_at: u64,
+    pub embodiments: u32,          // Successful exits with this character
+}
+
+/// Condition for one achievement. The achievement's id is its index in the
+/// table, which is also its bit in the player's bitset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AchievementRule {
+    /// Never unlocks; used to retire an id without renumbering the table
+    Retired,
+    /// (sense_id, max_score): a single sense submitted at or under max_score
+    SenseUnder(u32, u64),
+    /// Successful exit with every sense score at or under the threshold
+    AllSensesUnder(u64),
+    /// (character, limit_ms): successful exit as `character` with every sense
+    /// faster than limit_ms
+    SwiftRun(u32, u64),
+    /// Successful exit as the given character
+    Embodied(u32),
+    /// Every character embodied at least once
+    AllCharactersEmbodied,
+    /// This many consecutive successful exits
+    Streak(u32),
+    /// Successful exit as the given character with every sense proven and
+    /// no hazard hit
+    NoHazards(u32),
+}
+
+/// A player's achievement state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct PlayerAchievements {
+    pub unlocked: u128,            // Bit i set = achievement i unlocked
+    pub streak: u32,               // Current run of consecutive successful exits
+}
+
+/// Snapshot of a successful run that others can challenge.
+/// `sense_scores` is indexed by sense_id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GhostRun {
+    pub owner: Address,
+    pub session_id: u32,
+    pub character: u32,
+    pub tier: u32,
+    pub sense_scores: Vec<u64>,
+    pub total_score: u64,
+}
+
+/// Running counters for one `(character, sense_id)` maze.
+///
+/// `attempts` counts every time the maze was played to an outcome: an accepted
+/// submission, or a run that closed without completing it. Means are derived
+/// from the sums in `get_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct SenseStats {
+    pub attempts: u32,
+    pub completions: u32,
+    pub min_score: u64,
+    pub max_score: u64,
+    pub score_sum: u64,
+    pub time_sum_ms: u64,
+}
+
+/// Run-level counters. `exits` counts successful embodiments only.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct RunStats {
+    pub sessions_started: u32,
+    pub exits: u32,
+    pub overloads: u32,
+    pub incompletes: u32,
+    pub challenges_lost: u32,      // Complete, under cap, but didn't beat the ghost
+}
+
+/// One maze's statistics as reported by `get_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenseStatsView {
+    pub character: u32,
+    pub sense_id: u32,
+    pub attempts: u32,
+    pub completions: u32,
+    pub min_score: u64,
+    pub max_score: u64,
+    pub mean_score: u64,
+    pub mean_time_ms: u64,
+}
+
+/// Aggregate metrics returned by `get_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CalibrationStats {
+    pub run: RunStats,
+    pub senses: Vec<SenseStatsView>,
+}
+
+/// A head-to-head race between two staking players.
+///
+/// Each player's progress is tracked in their own lane, a `CalibrationSession`
+/// whose `player2` is the opponent. Both lanes share the race's session_id
+/// and character, and so the same maze roots.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Race {
+    pub session_id: u32,
+    pub character: u32,
+    pub deadline_ledger: u32,      // Last ledger on which senses are accepted
+    pub player1: CalibrationSession,
+    pub player2: CalibrationSession,
+    pub settled: bool,
+}
+
+/// Result of `exit_practice`: what the run would have earned if staked.
+/// `projected_rank` is the 1-based leaderboard position a ranked run with
+/// this score would take today, `None` when it would not have qualified.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PracticeOutcome {
+    pub success: bool,
+    pub total_score: u64,
+    pub projected_rank: Option<u32>,
+}
+
+/// Optional filters for `get_leaderboard_filtered`. Timestamps are inclusive.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardFilter {
+    pub character: Option<u32>,
+    pub tier: Option<u32>,
+    pub from_timestamp: Option<u64>,
+    pub to_timestamp: Option<u64>,
 }
 
-/// DataKey enum mirrors the SGS template pattern so the setup script's
-/// `__constructor` call works without modification.
+/// Contract storage keys. The admin and Game Hub addresses live under
+/// `sgs_common::CommonKey`.
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     /// Active/completed calibration session keyed by player address
     Session(Address),
-    /// Address of the deployed MockGameHub contract
-    GameHubAddress,
-    /// Admin address (can upgrade the contract)
-    Admin,
-    /// Auto-incrementing session counter
-    SessionCounter,
-    /// Leaderboard (Vec<LeaderboardEntry>)
+    /// Leaderboard (Vec<LeaderboardEntry>, sorted by total_score ascending)
     Leaderboard,
     /// Per-player, per-sense result keyed by (player, sense_id)
     SenseResult(Address, u32),
+    /// Last aggregated run proof accepted for a player
+    RunProof(Address),
+    /// Admin-published maze commitment keyed by (character, sense_id)
+    MazeRoot(u32, u32),
+    /// Rate limits applied to `start_game`
+    RateLimits,
+    /// Recent session starts keyed by player address
+    StartHistory(Address),
+    /// Run-level counters (RunStats)
+    RunStats,
+    /// Per-maze counters keyed by (character, sense_id)
+    SenseStats(u32, u32),
+    /// Unstaked practice session keyed by player address
+    PracticeSession(Address),
+    /// Practice sense result keyed by (player, sense_id)
+    PracticeResult(Address, u32),
+    /// Admin override of a tier's rules (TierConfig)
+    TierConfig(u32),
+    /// Head-to-head race keyed by session_id
+    Race(u32),
+    /// Ghost run snapshot keyed by the session_id that produced it
+    Ghost(u32),
+    /// Largest stake the ghost's owner agreed to match, keyed like `Ghost`
+    GhostStakeCap(u32),
+    /// Embodiment credential keyed by (player, character)
+    Credential(Address, u32),
+    /// Address of the cross-game progression registry, if configured
+    Registry,
+    /// Achievement table (Vec<AchievementRule>); defaults apply while unset
+    Achievements,
+    /// Achievement state keyed by player address
+    PlayerAchievements(Address),
+    /// Mask of senses that must be completed before sense_id unlocks
+    SensePrerequisites(u32),
+    /// Retry policy for failed senses
+    RetryPolicy,
+    /// Storage layout version (SCHEMA_VERSION); unset before version 1
+    SchemaVersion,
 }
 
 // ============================================================================
 // Constants
 // ============================================================================
 
-/// Maximum total score to qualify for the leaderboard / trigger a player win.
+/// Maximum total score to qualify for the leaderboard / trigger a player win
+/// in the Standard tier.
 /// score = points × time_ms, so lower is better (fast + exploring fewer cells).
 const SCORE_CAP: u64 = 20_000_000;
 
-/// Default points committed by Player 1 (user) and Player 2 (house).
-/// The house always matches the player's stake.  These can be overridden by
-/// the frontend via `start_game`; we store them in the session for `end_game`.
+/// Registry milestone per sense, indexed by sense_id. A sense is mastered
+/// when completed within its share (1/6) of the run's tier cap.
+const SENSE_MASTERY_MILESTONES: [&str; 6] = [
+    "mastered_hearing",
+    "mastered_smell",
+    "mastered_taste",
+    "mastered_touch",
+    "mastered_sight",
+    "mastered_proprioception",
+];
+
+/// Achievement ids are bits of a u128.
+const MAX_ACHIEVEMENTS: u32 = 128;
+
+/// Hazard time penalty added to a sense, in milliseconds.
+const HAZARD_PENALTY_MS: u64 = 20_000;
+
+/// Character ids.
+const ROBERT: u32 = 1;
+
+/// Difficulty tiers. Standard is 0 so its maze ids match pre-tier runs.
+const TIER_STANDARD: u32 = 0;
+const TIER_NOVICE: u32 = 1;
+const TIER_OVERCLOCK: u32 = 2;
+
+/// Number of senses per run, and the `completed_senses` mask once all are done.
+const SENSE_COUNT: u32 = 6;
+const ALL_SENSES: u32 = 0b11_1111;
+
+// Default points committed by Player 1 (user) and Player 2 (house).
+// The house always matches the player's stake.  These can be overridden by
+// the frontend via `start_game`; we store them in the session for `end_game`.
 // const DEFAULT_POINTS: i128 = 1_000_000_000; // 1 billion stroops = 100 XLM equiv
 
-/// Storage TTL — 30 days in ledgers (~5 s/ledger).
-const SESSION_TTL_LEDGERS: u32 = 518_400;
+/// Maximum entries returned by one leaderboard page query.
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Maximum entries kept on the leaderboard; slower runs fall off the end.
+const MAX_LEADERBOARD_LEN: u32 = 100;
+
+/// Current storage layout. Contracts upgraded from an older layout run
+/// `migrate` once to rewrite the leaderboard.
+const SCHEMA_VERSION: u32 = 1;
+
+/// Field counts of the pre-version-1 storage layouts.
+const LEGACY_SESSION_FIELDS: u32 = 9;
+const LEGACY_SENSE_RESULT_FIELDS: u32 = 4;
+const LEGACY_LEADERBOARD_ENTRY_FIELDS: u32 = 4;
+
+/// Character recorded on legacy sense results, which never stored one.
+const UNKNOWN_CHARACTER: u32 = u32::MAX;
+
+/// The hub's `SessionAlreadyEnded` error code.
+const HUB_SESSION_ALREADY_ENDED: u32 = 3;
 
 // ============================================================================
-// Contract
+// Helper Functions
 // ============================================================================
 
-#[contract]
-pub struct SaBirthContract;
+/// Which storage a run lives in. Practice runs are never staked, never reach
+/// the hub or the leaderboard, and don't feed the statistics.
+#[derive(Clone, Copy, PartialEq)]
+enum RunKind {
+    Ranked,
+    Practice,
+}
 
-#[contractimpl]
-impl SaBirthContract {
-    // ── Constructor (SGS template signature — do NOT change) ─────────────────
-    /// Called once by the SGS deploy script:
-    ///   `stellar contract invoke ... -- __constructor --admin <ADDR> --game_hub <ADDR>`
-    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
-        env.storage()
-            .instance()
-            .set(&DataKey::SessionCounter, &0u32);
+impl RunKind {
+    fn session_key(self, player: &Address) -> DataKey {
+        match self {
+            RunKind::Ranked => DataKey::Session(player.clone()),
+            RunKind::Practice => DataKey::PracticeSession(player.clone()),
+        }
     }
 
-    // ── start_game ────────────────────────────────────────────────────────────
-    /// Start a calibration session.
-    ///
-    /// Called by the frontend when Player 1 wants to begin a new game.
-    /// Player 2 is the system/house wallet — only Player 1 needs to authorize
-    /// since they're committing their own stake. The Hub locks both players' points.
-    ///
-    /// After calling this, the frontend should call `set_character()` to record
-    /// the player's chosen character (ALICE/ROBERT/CAROL).
-    ///
-    /// Returns Ok(()) on success.
-    pub fn start_game(
-        env: Env,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    ) -> Result<(), Error> {
-        // Players must be different
-        if player1 == player2 {
-            panic!("player1 and player2 must differ");
+    fn result_key(self, player: &Address, sense_id: u32) -> DataKey {
+        match self {
+            RunKind::Ranked => DataKey::SenseResult(player.clone(), sense_id),
+            RunKind::Practice => DataKey::PracticeResult(player.clone(), sense_id),
         }
+    }
+}
 
-        // Only player1 needs to authorize (player2 is the house/system wallet)
-        player1.require_auth_for_args(
-            vec![&env, session_id.into_val(&env), player1_points.into_val(&env)],
-        );
+/// Number of fields in a stored `#[contracttype]` struct. Decoding a struct
+/// from a map with a different field count traps, so legacy layouts are
+/// recognised by their size before decoding.
+fn field_count(env: &Env, raw: &Val) -> u32 {
+    Map::<Symbol, Val>::try_from_val(env, raw)
+        .map(|fields| fields.len())
+        .unwrap_or(0)
+}
 
-        // Reject duplicate active session for player1
-        let session_key = DataKey::Session(player1.clone());
-        if let Some(existing) = env
-            .storage()
-            .persistent()
-            .get::<_, CalibrationSession>(&session_key)
-        {
-            if existing.active {
-                // Orphaned session detected - end it with house (Player 2) winning
-                if let Some(hub_addr) = env
-                    .storage()
-                    .instance()
-                    .get::<_, Address>(&DataKey::GameHubAddress)
-                {
-                    // Best-effort cleanup - don't fail if hub call errors
-                    // (e.g., if hub was redeployed and old session_id is invalid)
-                    let _ = env.invoke_contract::<()>(
-                        &hub_addr,
-                        &Symbol::new(&env, "end_game"),
-                        vec![
-                            &env,
-                            existing.session_id.into_val(&env),
-                            false.into_val(&env), // player1_won = false (house wins)
-                        ],
-                    );
-                }
-            }
-        }
+/// Read a stored session, converting one written in the pre-version-1
+/// layout. Legacy runs are Standard tier, keyless and have no proven senses.
+fn load_session(env: &Env, key: &DataKey) -> Option<CalibrationSession> {
+    let raw: Val = env.storage().persistent().get(key)?;
+    if field_count(env, &raw) != LEGACY_SESSION_FIELDS {
+        return Some(CalibrationSession::try_from_val(env, &raw).unwrap());
+    }
 
-        // Notify hub — lock points
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .ok_or(Error::HubCallFailed)?;
+    let legacy = LegacyCalibrationSession::try_from_val(env, &raw).unwrap();
+    Some(CalibrationSession {
+        player: legacy.player,
+        player2: legacy.player2,
+        character: legacy.character,
+        completed_senses: legacy.completed_senses,
+        total_score: legacy.total_score,
+        session_id: legacy.session_id,
+        player1_points: legacy.player1_points,
+        player2_points: legacy.player2_points,
+        active: legacy.active,
+        proven_senses: 0,
+        session_key: None,
+        next_nonce: 0,
+        tier: TIER_STANDARD,
+        ghost: None,
+        sense_failures: Map::new(env),
+        retry_penalty: 0,
+        score_cap: SCORE_CAP,
+        payout_bps: 10_000,
+    })
+}
 
-        env.invoke_contract::<()>(
-            &hub_addr,
-            &Symbol::new(&env, "start_game"),
-            vec![
-                &env,
-                env.current_contract_address().into_val(&env),
-                session_id.into_val(&env),
-                player1.clone().into_val(&env),
-                player2.clone().into_val(&env),
-                player1_points.into_val(&env),
-                player2_points.into_val(&env),
-            ],
-        );
+/// Read a stored sense result, converting one written in the pre-version-1
+/// layout. Legacy results carry no hazards, no proven character and zeroed
+/// maze roots and proof digests.
+fn load_sense_result(env: &Env, key: &DataKey) -> Option<SenseResult> {
+    let raw: Val = env.storage().persistent().get(key)?;
+    if field_count(env, &raw) != LEGACY_SENSE_RESULT_FIELDS {
+        return Some(SenseResult::try_from_val(env, &raw).unwrap());
+    }
 
-        // Persist session
-        let session = CalibrationSession {
-            player: player1.clone(),
-            player2: player2.clone(),
-            character: 0, // default — overridden by set_character() below
-            completed_senses: 0,
-            total_score: 0,
-            session_id,
-            player1_points,
-            player2_points,
-            active: true,
-        };
-        env.storage()
-            .persistent()
-            .set(&session_key, &session);
-        env.storage()
-            .persistent()
-            .extend_ttl(&session_key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+    let legacy = LegacySenseResult::try_from_val(env, &raw).unwrap();
+    let zero = BytesN::from_array(env, &[0u8; 32]);
+    Some(SenseResult {
+        sense_id: legacy.sense_id,
+        character: UNKNOWN_CHARACTER,
+        points: legacy.points,
+        time_ms: legacy.time_ms,
+        hazards: 0,
+        score: legacy.score,
+        maze_root: zero.clone(),
+        proof_digest: zero,
+    })
+}
 
-        Ok(())
+/// Load the player's session, failing unless it is still active.
+fn load_active_session(
+    env: &Env,
+    player: &Address,
+    kind: RunKind,
+) -> Result<CalibrationSession, Error> {
+    let session = load_session(env, &kind.session_key(player)).ok_or(Error::GameNotFound)?;
+
+    if !session.active {
+        return Err(Error::SessionNotActive);
     }
 
-    // ── set_character ─────────────────────────────────────────────────────────
-    /// Record the player's chosen character (ALICE/ROBERT/CAROL).
-    ///
-    /// Must be called after `start_game()` and before any maze attempts.
-    /// Returns the session_id for the active session.
-    pub fn set_character(
-        env: Env,
-        player: Address,
-        character: u32,
-    ) -> Result<u32, Error> {
-        player.require_auth();
+    Ok(session)
+}
 
-        if character > 2 {
-            return Err(Error::InvalidCharacter);
-        }
+/// Maze commitment a sense submission must be proven against.
+///
+/// The admin-published root for `(character, sense_id)` wins when present.
+/// Otherwise the maze is seeded from the session and its commitment is
+/// `keccak256(session_id ‖ character ‖ sense_id)` (big-endian u32s), which the
+/// frontend derives the same way.
+///
+/// The fallback only binds a submission to its session and sense; it does not
+/// commit to the maze layout, so anyone can compute it without playing. Only an
+/// admin-published root is a real maze commitment.
+fn expected_maze_root(env: &Env, session: &CalibrationSession, sense_id: u32) -> BytesN<32> {
+    if let Some(root) = env
+        .storage()
+        .instance()
+        .get(&DataKey::MazeRoot(session.character, sense_id))
+    {
+        return root;
+    }
 
-        let session_key = DataKey::Session(player.clone());
-        let mut session: CalibrationSession = env
-            .storage()
-            .persistent()
-            .get(&session_key)
-            .ok_or(Error::GameNotFound)?;
+    let mut seed = [0u8; 12];
+    seed[0..4].copy_from_slice(&session.session_id.to_be_bytes());
+    seed[4..8].copy_from_slice(&session.character.to_be_bytes());
+    seed[8..12].copy_from_slice(&sense_id.to_be_bytes());
+    env.crypto()
+        .keccak256(&Bytes::from_array(env, &seed))
+        .into()
+}
 
-        if !session.active {
-            return Err(Error::SessionNotActive);
-        }
+/// Rules for `tier`: the admin override when set, otherwise the built-in
+/// defaults. `None` for an unknown tier.
+fn tier_config(env: &Env, tier: u32) -> Option<TierConfig> {
+    if let Some(config) = env.storage().instance().get(&DataKey::TierConfig(tier)) {
+        return Some(config);
+    }
 
-        session.character = character;
-        env.storage().persistent().set(&session_key, &session);
+    let (score_cap, payout_bps) = match tier {
+        TIER_STANDARD => (SCORE_CAP, 10_000),
+        TIER_NOVICE => (30_000_000, 5_000),
+        TIER_OVERCLOCK => (12_000_000, 20_000),
+        _ => return None,
+    };
+    Some(TierConfig {
+        score_cap,
+        min_stake: 0,
+        payout_bps,
+    })
+}
+
+/// Check one sense submission against the circuit's public inputs.
+///
+/// Mirrors the ZK circuit constraints:
+///   • maze_id == (tier << 16) | (character << 8) | sense_id
+///   • maze_root == the stored (or session-derived) maze commitment
+///   • score == points × time_ms
+fn verify_sense_inputs(
+    env: &Env,
+    session: &CalibrationSession,
+    sense_id: u32,
+    maze_id: u32,
+    maze_root: &BytesN<32>,
+    points: u64,
+    time_ms: u64,
+    score: u64,
+) -> Result<(), Error> {
+    // maze_id must match (tier << 16) | (character << 8) | sense_id
+    let expected_maze_id = (session.tier << 16) | (session.character << 8) | sense_id;
+    if maze_id != expected_maze_id {
+        return Err(Error::VerificationFailed);
+    }
+
+    if *maze_root != expected_maze_root(env, session, sense_id) {
+        return Err(Error::MazeCommitmentMismatch);
+    }
+
+    // Score must equal points × time_ms (replicates ZK circuit assertion)
+    let expected_score = points
+        .checked_mul(time_ms)
+        .ok_or(Error::VerificationFailed)?;
+    if score != expected_score {
+        return Err(Error::VerificationFailed);
+    }
+
+    // Basic sanity: disallow trivial / overflow inputs
+    if points == 0 || time_ms == 0 || score > 10_000_000_000u64 {
+        return Err(Error::VerificationFailed);
+    }
+
+    Ok(())
+}
+
+/// Senses that must be completed before `sense_id` (none unless configured).
+fn sense_prerequisites(env: &Env, sense_id: u32) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SensePrerequisites(sense_id))
+        .unwrap_or(0)
+}
+
+/// Mask of senses not yet completed whose prerequisites are all completed.
+fn available_senses(env: &Env, completed_senses: u32) -> u32 {
+    let mut available = 0u32;
+    for sense_id in 0..SENSE_COUNT {
+        let prerequisites = sense_prerequisites(env, sense_id);
+        if completed_senses & (1 << sense_id) == 0
+            && completed_senses & prerequisites == prerequisites
+        {
+            available |= 1 << sense_id;
+        }
+    }
+    available
+}
+
+/// Reject a submission for a sense whose prerequisites are still open.
+fn check_sense_unlocked(env: &Env, completed_senses: u32, sense_id: u32) -> Result<(), Error> {
+    let prerequisites = sense_prerequisites(env, sense_id);
+    if completed_senses & prerequisites != prerequisites {
+        return Err(Error::SenseLocked);
+    }
+    Ok(())
+}
+
+fn get_retry_policy(env: &Env) -> RetryPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::RetryPolicy)
+        .unwrap_or(RetryPolicy {
+            max_retries: 0,
+            penalty: 0,
+        })
+}
+
+/// True once a sense has been failed more often than the policy allows.
+fn retries_exhausted(env: &Env, session: &CalibrationSession, sense_id: u32) -> bool {
+    session.sense_failures.get(sense_id).unwrap_or(0) > get_retry_policy(env).max_retries
+}
+
+/// Persist a session and refresh its TTL.
+fn save_session(env: &Env, player: &Address, kind: RunKind, session: &CalibrationSession) {
+    let session_key = kind.session_key(player);
+    env.storage().persistent().set(&session_key, session);
+    extend_persistent(env, &session_key);
+}
+
+/// Validate and record one completed sense for an active session.
+///
+/// Shared by direct (`submit_sense_completion`), relayed
+/// (`submit_sense_signed`) and practice (`submit_practice_sense`) submissions.
+/// Hazard penalties are part of `time_ms`, so they must fit inside it.
+fn check_hazards(hazards: u32, time_ms: u64) -> Result<(), Error> {
+    if (hazards as u64).saturating_mul(HAZARD_PENALTY_MS) > time_ms {
+        return Err(Error::VerificationFailed);
+    }
+    Ok(())
+}
+
+fn record_sense(
+    env: &Env,
+    player: &Address,
+    kind: RunKind,
+    mut session: CalibrationSession,
+    sense_id: u32,
+    maze_id: u32,
+    maze_root: BytesN<32>,
+    points: u64,
+    time_ms: u64,
+    hazards: u32,
+    score: u64,
+    proof_hex: &Bytes,
+) -> Result<(), Error> {
+    // Prevent re-submission for the same sense
+    let sense_bit = 1u32 << sense_id;
+    if (session.completed_senses & sense_bit) != 0 {
+        return Err(Error::AlreadyGuessed);
+    }
+    check_sense_unlocked(env, session.completed_senses, sense_id)?;
+    if retries_exhausted(env, &session, sense_id) {
+        return Err(Error::RetriesExhausted);
+    }
+
+    verify_sense_inputs(
+        env, &session, sense_id, maze_id, &maze_root, points, time_ms, score,
+    )?;
+
+    check_hazards(hazards, time_ms)?;
+
+    // Persist sense result (proof not emitted nor stored)
+    let result = SenseResult {
+        sense_id,
+        character: session.character,
+        points,
+        time_ms,
+        hazards,
+        score,
+        maze_root,
+        proof_digest: env.crypto().keccak256(proof_hex).into(),
+    };
+    let result_key = kind.result_key(player, sense_id);
+    env.storage().persistent().set(&result_key, &result);
+    extend_persistent(env, &result_key);
+
+    // Update session
+    session.completed_senses |= sense_bit;
+    if !proof_hex.is_empty() {
+        session.proven_senses |= sense_bit;
+    }
+    session.total_score = session.total_score.saturating_add(score);
+    save_session(env, player, kind, &session);
+
+    if kind == RunKind::Practice {
+        return Ok(());
+    }
+
+    record_completion_stats(env, session.character, sense_id, score, time_ms);
+    if !proof_hex.is_empty() {
+        record_sense_achievements(env, player, sense_id, score);
+    }
+
+    SenseCompletedEvent {
+        sense_id,
+        score,
+        points,
+        time_ms,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+fn get_rate_limits(env: &Env) -> RateLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::RateLimits)
+        .unwrap_or(RateLimits {
+            min_ledger_gap: 0,
+            max_sessions: 0,
+            window_ledgers: 0,
+        })
+}
+
+/// Work out when `player` may next start a session.
+/// Also returns the in-window start ledgers so the caller can append to them.
+fn limits_status(env: &Env, player: &Address) -> (LimitsStatus, Vec<u32>) {
+    let limits = get_rate_limits(env);
+    let now = env.ledger().sequence();
+    let history: Option<StartHistory> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StartHistory(player.clone()));
+
+    let mut next_allowed = now;
+    let mut recent = Vec::new(env);
+
+    if let Some(history) = history {
+        if limits.min_ledger_gap > 0 {
+            next_allowed = next_allowed
+                .max(history.last_start_ledger.saturating_add(limits.min_ledger_gap));
+        }
+
+        if limits.max_sessions > 0 && limits.window_ledgers > 0 {
+            for ledger in history.recent_starts.iter() {
+                if ledger.saturating_add(limits.window_ledgers) > now {
+                    recent.push_back(ledger);
+                }
+            }
+            if recent.len() >= limits.max_sessions {
+                // The window frees up once the start `max_sessions` back expires
+                let oldest = recent.get_unchecked(recent.len() - limits.max_sessions);
+                next_allowed = next_allowed.max(oldest.saturating_add(limits.window_ledgers));
+            }
+        }
+    }
+
+    let status = LimitsStatus {
+        current_ledger: now,
+        next_allowed_ledger: next_allowed,
+        sessions_in_window: recent.len(),
+        max_sessions: limits.max_sessions,
+        can_start: next_allowed <= now,
+    };
+    (status, recent)
+}
+
+/// Reject the start if `player` is cooling down or out of quota, otherwise
+/// record it against their history.
+fn check_and_record_start(env: &Env, player: &Address) -> Result<(), Error> {
+    let limits = get_rate_limits(env);
+    let (status, mut recent) = limits_status(env, player);
+
+    if !status.can_start {
+        if limits.max_sessions > 0 && status.sessions_in_window >= limits.max_sessions {
+            return Err(Error::SessionQuotaExceeded);
+        }
+        return Err(Error::CooldownActive);
+    }
+
+    if limits.max_sessions > 0 && limits.window_ledgers > 0 {
+        recent.push_back(status.current_ledger);
+        // Only the newest `max_sessions` starts can ever matter
+        while recent.len() > limits.max_sessions {
+            recent.pop_front();
+        }
+    }
+
+    let history_key = DataKey::StartHistory(player.clone());
+    env.storage().persistent().set(
+        &history_key,
+        &StartHistory {
+            last_start_ledger: status.current_ledger,
+            recent_starts: recent,
+        },
+    );
+    extend_persistent(env, &history_key);
+
+    Ok(())
+}
+
+fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+fn load_leaderboard(env: &Env) -> Vec<LeaderboardEntry> {
+    if schema_version(env) < SCHEMA_VERSION {
+        return legacy_leaderboard(env);
+    }
+    env.storage()
+        .instance()
+        .get(&DataKey::Leaderboard)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Decode a leaderboard written before version 1 (unsorted, four fields per
+/// entry), sorted and capped like a current one.
+fn legacy_leaderboard(env: &Env) -> Vec<LeaderboardEntry> {
+    let mut leaderboard = Vec::new(env);
+    let Some(raw) = env
+        .storage()
+        .instance()
+        .get::<_, Vec<Val>>(&DataKey::Leaderboard)
+    else {
+        return leaderboard;
+    };
+
+    for value in raw.iter() {
+        let entry = if field_count(env, &value) == LEGACY_LEADERBOARD_ENTRY_FIELDS {
+            let legacy = LegacyLeaderboardEntry::try_from_val(env, &value).unwrap();
+            LeaderboardEntry {
+                player: legacy.player,
+                character: legacy.character,
+                total_score: legacy.total_score,
+                timestamp: legacy.timestamp,
+                tier: TIER_STANDARD,
+                session_id: 0,
+                retries: 0,
+                retry_penalty: 0,
+            }
+        } else {
+            LeaderboardEntry::try_from_val(env, &value).unwrap()
+        };
+        insert_entry(&mut leaderboard, entry);
+    }
+    leaderboard
+}
+
+/// Insert `entry` in score order, keeping at most `MAX_LEADERBOARD_LEN`
+/// entries. Returns false when the run is too slow to make the cut.
+fn insert_entry(leaderboard: &mut Vec<LeaderboardEntry>, entry: LeaderboardEntry) -> bool {
+    let position = insert_position(leaderboard, entry.total_score);
+    if position >= MAX_LEADERBOARD_LEN {
+        return false;
+    }
+    leaderboard.insert(position, entry);
+    if leaderboard.len() > MAX_LEADERBOARD_LEN {
+        leaderboard.pop_back();
+    }
+    true
+}
+
+/// Binary search for where a new score goes in the sorted leaderboard.
+/// Equal scores are placed after existing ones, so earlier runs rank higher.
+fn insert_position(leaderboard: &Vec<LeaderboardEntry>, total_score: u64) -> u32 {
+    let mut low = 0u32;
+    let mut high = leaderboard.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if leaderboard.get_unchecked(mid).total_score <= total_score {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Index of the player's best (first) entry in the sorted leaderboard.
+fn best_index(leaderboard: &Vec<LeaderboardEntry>, player: &Address) -> Option<u32> {
+    leaderboard
+        .iter()
+        .position(|entry| entry.player == *player)
+        .map(|index| index as u32)
+}
+
+/// Copy `leaderboard[start..start + count]`, clipped to its length.
+fn leaderboard_slice(
+    env: &Env,
+    leaderboard: &Vec<LeaderboardEntry>,
+    start: u32,
+    count: u32,
+) -> Vec<LeaderboardEntry> {
+    let start = start.min(leaderboard.len());
+    let end = start.saturating_add(count).min(leaderboard.len());
+    let mut page = Vec::new(env);
+    for index in start..end {
+        page.push_back(leaderboard.get_unchecked(index));
+    }
+    page
+}
+
+fn save_race(env: &Env, race: &Race) {
+    let race_key = DataKey::Race(race.session_id);
+    env.storage().persistent().set(&race_key, race);
+    extend_persistent(env, &race_key);
+}
+
+/// Decide a race: more proven senses wins, then the lower total score.
+/// Senses submitted without a proof don't count towards the win. A race
+/// nobody proved a sense in is cancelled, and a full tie is a draw.
+fn race_outcome(race: &Race) -> GameOutcome {
+    let senses1 = race.player1.proven_senses.count_ones();
+    let senses2 = race.player2.proven_senses.count_ones();
+    if senses1 == 0 && senses2 == 0 {
+        return GameOutcome::Cancelled;
+    }
+    if senses1 != senses2 {
+        return if senses1 > senses2 {
+            GameOutcome::Player1
+        } else {
+            GameOutcome::Player2
+        };
+    }
+    match race.player1.total_score.cmp(&race.player2.total_score) {
+        core::cmp::Ordering::Less => GameOutcome::Player1,
+        core::cmp::Ordering::Greater => GameOutcome::Player2,
+        core::cmp::Ordering::Equal => GameOutcome::Draw,
+    }
+}
+
+/// How a run ended, for the run-level counters.
+enum RunOutcome {
+    Embodied,
+    Overloaded,
+    Incomplete,
+    Outpaced,
+}
+
+fn load_run_stats(env: &Env) -> RunStats {
+    env.storage()
+        .instance()
+        .get(&DataKey::RunStats)
+        .unwrap_or_default()
+}
+
+fn load_sense_stats(env: &Env, character: u32, sense_id: u32) -> SenseStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenseStats(character, sense_id))
+        .unwrap_or_default()
+}
+
+fn save_sense_stats(env: &Env, character: u32, sense_id: u32, stats: &SenseStats) {
+    let key = DataKey::SenseStats(character, sense_id);
+    env.storage().persistent().set(&key, stats);
+    extend_persistent(env, &key);
+}
+
+fn record_session_started(env: &Env) {
+    let mut run = load_run_stats(env);
+    run.sessions_started = run.sessions_started.saturating_add(1);
+    env.storage().instance().set(&DataKey::RunStats, &run);
+}
+
+/// Fold one accepted submission into its maze's counters.
+fn record_completion_stats(env: &Env, character: u32, sense_id: u32, score: u64, time_ms: u64) {
+    let mut stats = load_sense_stats(env, character, sense_id);
+    stats.min_score = if stats.completions == 0 {
+        score
+    } else {
+        stats.min_score.min(score)
+    };
+    stats.max_score = stats.max_score.max(score);
+    stats.attempts = stats.attempts.saturating_add(1);
+    stats.completions = stats.completions.saturating_add(1);
+    stats.score_sum = stats.score_sum.saturating_add(score);
+    stats.time_sum_ms = stats.time_sum_ms.saturating_add(time_ms);
+    save_sense_stats(env, character, sense_id, &stats);
+}
+
+/// Count a closed run and the mazes it left unfinished. Senses the player
+/// failed were already counted as attempts by `fail_sense`.
+fn record_run_closed(env: &Env, session: &CalibrationSession, outcome: RunOutcome) {
+    let mut run = load_run_stats(env);
+    match outcome {
+        RunOutcome::Embodied => run.exits = run.exits.saturating_add(1),
+        RunOutcome::Overloaded => run.overloads = run.overloads.saturating_add(1),
+        RunOutcome::Incomplete => run.incompletes = run.incompletes.saturating_add(1),
+        RunOutcome::Outpaced => run.challenges_lost = run.challenges_lost.saturating_add(1),
+    }
+    env.storage().instance().set(&DataKey::RunStats, &run);
+
+    for sense_id in 0..SENSE_COUNT {
+        if session.completed_senses & (1 << sense_id) == 0
+            && !session.sense_failures.contains_key(sense_id)
+        {
+            let mut stats = load_sense_stats(env, session.character, sense_id);
+            stats.attempts = stats.attempts.saturating_add(1);
+            save_sense_stats(env, session.character, sense_id, &stats);
+        }
+    }
+}
+
+/// True when the run would pay out to the player: all senses done, under the
+/// tier's cap, and for a challenge, every sense faster than the ghost's.
+fn is_winning_run(env: &Env, session: &CalibrationSession) -> bool {
+    session.completed_senses == ALL_SENSES
+        && session.total_score <= session.score_cap
+        && session
+            .ghost
+            .is_none_or(|ghost_id| beats_ghost(env, &session.player, ghost_id))
+}
+
+/// True when each of the player's recorded sense scores is strictly lower
+/// than the ghost's score for that sense.
+fn beats_ghost(env: &Env, player: &Address, ghost_id: u32) -> bool {
+    let Some(ghost) = env
+        .storage()
+        .persistent()
+        .get::<_, GhostRun>(&DataKey::Ghost(ghost_id))
+    else {
+        return false;
+    };
+
+    (0..SENSE_COUNT).all(|sense_id| {
+        load_sense_result(env, &DataKey::SenseResult(player.clone(), sense_id))
+            .is_some_and(|result| result.score < ghost.sense_scores.get_unchecked(sense_id))
+    })
+}
+
+/// Snapshot a successful run's per-sense scores as a challengeable ghost.
+fn record_ghost(env: &Env, player: &Address, session: &CalibrationSession) {
+    let mut sense_scores = Vec::new(env);
+    for sense_id in 0..SENSE_COUNT {
+        let result = load_sense_result(env, &DataKey::SenseResult(player.clone(), sense_id));
+        sense_scores.push_back(result.map(|result| result.score).unwrap_or(0));
+    }
+
+    let ghost_key = DataKey::Ghost(session.session_id);
+    env.storage().persistent().set(
+        &ghost_key,
+        &GhostRun {
+            owner: player.clone(),
+            session_id: session.session_id,
+            character: session.character,
+            tier: session.tier,
+            sense_scores,
+            total_score: session.total_score,
+        },
+    );
+    extend_persistent(env, &ghost_key);
+}
+
+/// Digest committing to a run's per-sense proofs:
+/// keccak256 of the six sense proof digests in sense order.
+fn sense_proofs_digest(env: &Env, player: &Address) -> BytesN<32> {
+    let mut digests = Bytes::new(env);
+    for sense_id in 0..SENSE_COUNT {
+        if let Some(result) = load_sense_result(env, &DataKey::SenseResult(player.clone(), sense_id)) {
+            digests.append(&result.proof_digest.into());
+        }
+    }
+    env.crypto().keccak256(&digests).into()
+}
+
+/// Issue the player's credential for the session's character, or update it
+/// when this run beats the recorded best.
+fn record_credential(
+    env: &Env,
+    player: &Address,
+    session: &CalibrationSession,
+    proof_digest: BytesN<32>,
+) {
+    let credential_key = DataKey::Credential(player.clone(), session.character);
+    let now = env.ledger().timestamp();
+    let existing: Option<EmbodimentCredential> = env.storage().persistent().get(&credential_key);
+
+    let credential = match existing {
+        Some(mut credential) => {
+            credential.embodiments = credential.embodiments.saturating_add(1);
+            if session.total_score < credential.best_score {
+                credential.best_score = session.total_score;
+                credential.tier = session.tier;
+                credential.session_id = session.session_id;
+                credential.proof_digest = proof_digest;
+                credential.earned_at = now;
+            }
+            credential
+        }
+        None => EmbodimentCredential {
+            player: player.clone(),
+            character: session.character,
+            best_score: session.total_score,
+            tier: session.tier,
+            session_id: session.session_id,
+            proof_digest,
+            earned_at: now,
+            first_earned_at: now,
+            embodiments: 1,
+        },
+    };
+
+    env.storage().persistent().set(&credential_key, &credential);
+    extend_persistent(env, &credential_key);
+
+    CredentialEvent {
+        player: player.clone(),
+        character: credential.character,
+        best_score: credential.best_score,
+    }
+    .publish(env);
+}
+
+/// Record the run's milestones in the progression registry, if configured.
+///
+/// Best-effort: a missing allowlist entry or a failing registry must not
+/// block settlement, so a failed call only publishes `MilestonesFailedEvent`.
+fn record_progress(
+    env: &Env,
+    player: &Address,
+    session: &CalibrationSession,
+    outcome: Option<&str>,
+) {
+    let Some(registry) = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::Registry)
+    else {
+        return;
+    };
+
+    let mut milestones = Vec::new(env);
+    if let Some(outcome) = outcome {
+        milestones.push_back(Symbol::new(env, outcome));
+    }
+
+    // Only senses submitted with their own proof count towards mastery
+    let mastery_score = session.score_cap / SENSE_COUNT as u64;
+    for sense_id in 0..SENSE_COUNT {
+        if session.proven_senses & (1 << sense_id) == 0 {
+            continue;
+        }
+        let result = load_sense_result(env, &DataKey::SenseResult(player.clone(), sense_id));
+        if result.is_some_and(|result| result.score <= mastery_score) {
+            milestones.push_back(Symbol::new(env, SENSE_MASTERY_MILESTONES[sense_id as usize]));
+        }
+    }
+
+    if milestones.is_empty() {
+        return;
+    }
+
+    let recorded = ProgressionRegistryClient::new(env, &registry).try_record_milestones(
+        &env.current_contract_address(),
+        player,
+        &session.character,
+        &milestones,
+    );
+    if !matches!(recorded, Ok(Ok(()))) {
+        MilestonesFailedEvent {
+            player: player.clone(),
+            registry,
+            milestones,
+        }
+        .publish(env);
+    }
+}
+
+/// The achievement table, or the built-in one if the admin never set it.
+fn load_achievement_table(env: &Env) -> Vec<AchievementRule> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Achievements)
+        .unwrap_or_else(|| {
+            vec![
+                env,
+                AchievementRule::AllSensesUnder(500_000),
+                AchievementRule::NoHazards(ROBERT),
+                AchievementRule::Embodied(0),
+                AchievementRule::Embodied(1),
+                AchievementRule::Embodied(2),
+                AchievementRule::AllCharactersEmbodied,
+                AchievementRule::Streak(3),
+            ]
+        })
+}
+
+fn load_player_achievements(env: &Env, player: &Address) -> PlayerAchievements {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerAchievements(player.clone()))
+        .unwrap_or_default()
+}
+
+fn save_player_achievements(env: &Env, player: &Address, state: &PlayerAchievements) {
+    let key = DataKey::PlayerAchievements(player.clone());
+    env.storage().persistent().set(&key, state);
+    extend_persistent(env, &key);
+}
+
+/// Unlock every achievement whose rule passes `check`, emitting one event
+/// per new unlock. Returns whether anything changed.
+fn unlock_matching(
+    env: &Env,
+    player: &Address,
+    state: &mut PlayerAchievements,
+    check: impl Fn(&AchievementRule) -> bool,
+) -> bool {
+    let mut changed = false;
+    for (id, rule) in load_achievement_table(env).iter().enumerate() {
+        let bit = 1u128 << id;
+        if state.unlocked & bit != 0 || !check(&rule) {
+            continue;
+        }
+        state.unlocked |= bit;
+        changed = true;
+        AchievementUnlockedEvent {
+            player: player.clone(),
+            achievement_id: id as u32,
+        }
+        .publish(env);
+    }
+    changed
+}
+
+/// Submission-time achievements.
+fn record_sense_achievements(env: &Env, player: &Address, sense_id: u32, score: u64) {
+    let mut state = load_player_achievements(env, player);
+    let changed = unlock_matching(env, player, &mut state, |rule| {
+        matches!(rule, AchievementRule::SenseUnder(id, max_score) if *id == sense_id && score <= *max_score)
+    });
+    if changed {
+        save_player_achievements(env, player, &state);
+    }
+}
+
+/// Exit-time achievements. A failed run only resets the streak.
+fn record_exit_achievements(
+    env: &Env,
+    player: &Address,
+    session: &CalibrationSession,
+    embodied: bool,
+) {
+    let mut state = load_player_achievements(env, player);
+    if !embodied {
+        if state.streak != 0 {
+            state.streak = 0;
+            save_player_achievements(env, player, &state);
+        }
+        return;
+    }
+    state.streak = state.streak.saturating_add(1);
+
+    let mut max_score = 0u64;
+    let mut max_time_ms = 0u64;
+    let mut hazards = 0u32;
+    // Character rules go by the character the senses were proven with
+    let mut proven_character = Some(session.character);
+    for sense_id in 0..SENSE_COUNT {
+        if let Some(result) = load_sense_result(env, &DataKey::SenseResult(player.clone(), sense_id)) {
+            if proven_character != Some(result.character) {
+                proven_character = None;
+            }
+            max_score = max_score.max(result.score);
+            max_time_ms = max_time_ms.max(result.time_ms);
+            hazards = hazards.saturating_add(result.hazards);
+        }
+    }
+    let has_embodied = |character: u32| {
         env.storage()
             .persistent()
-            .extend_ttl(&session_key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+            .has(&DataKey::Credential(player.clone(), character))
+    };
+    let streak = state.streak;
+
+    unlock_matching(env, player, &mut state, |rule| match rule {
+        AchievementRule::AllSensesUnder(threshold) => max_score <= *threshold,
+        AchievementRule::SwiftRun(character, limit_ms) => {
+            proven_character == Some(*character) && max_time_ms < *limit_ms
+        }
+        AchievementRule::Embodied(character) => proven_character == Some(*character),
+        AchievementRule::AllCharactersEmbodied => (0..3).all(has_embodied),
+        AchievementRule::Streak(length) => streak >= *length,
+        AchievementRule::NoHazards(character) => {
+            proven_character == Some(*character)
+                && session.proven_senses == ALL_SENSES
+                && hazards == 0
+        }
+        AchievementRule::Retired | AchievementRule::SenseUnder(..) => false,
+    });
+    save_player_achievements(env, player, &state);
+}
 
-        // Increment + store session counter (used as a stable unique ID)
-        let mut counter: u32 = env
-            .storage()
+/// Open a staked session: enforce rate limits, end any orphaned session,
+/// lock both stakes in the hub and persist `session`.
+fn open_session(env: &Env, session: CalibrationSession) -> Result<(), Error> {
+    let player1 = session.player.clone();
+
+    // Enforce per-player cooldown and session quota
+    check_and_record_start(env, &player1)?;
+
+    // Reject duplicate active session for player1
+    let session_key = DataKey::Session(player1.clone());
+    if let Some(existing) = load_session(env, &session_key) {
+        if existing.active {
+            // Orphaned session detected - end it with house (Player 2) winning
+            record_run_closed(env, &existing, RunOutcome::Incomplete);
+            record_exit_achievements(env, &player1, &existing, false);
+            if let Some(hub_addr) = sgs_common::hub_address(env) {
+                // Best-effort cleanup - don't fail if hub call errors
+                // (e.g., if hub was redeployed and old session_id is invalid)
+                let _ = GameHubClient::new(env, &hub_addr)
+                    .try_end_game(&existing.session_id, &false);
+            }
+        }
+    }
+
+    // Notify hub — lock points
+    let hub_addr = sgs_common::hub_address(env).ok_or(Error::HubCallFailed)?;
+
+    GameHubClient::new(env, &hub_addr).start_game(
+        &env.current_contract_address(),
+        &session.session_id,
+        &player1,
+        &session.player2,
+        &session.player1_points,
+        &session.player2_points,
+    );
+
+    // Persist session
+    env.storage()
+        .persistent()
+        .set(&session_key, &session);
+    extend_persistent(env, &session_key);
+
+    record_session_started(env);
+
+    Ok(())
+}
+
+/// Check the tier's stakes and open a fresh ranked session in that tier.
+fn open_tiered_session(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    tier: u32,
+) -> Result<(), Error> {
+    let config = tier_config(env, tier).ok_or(Error::InvalidTier)?;
+    let house_stake = player1_points
+        .checked_mul(config.payout_bps as i128)
+        .ok_or(Error::InvalidStake)?
+        / 10_000;
+    if player1_points < config.min_stake || player2_points != house_stake {
+        return Err(Error::InvalidStake);
+    }
+
+    open_session(
+        env,
+        CalibrationSession {
+            player: player1,
+            player2,
+            character: 0, // default — overridden by set_character() below
+            completed_senses: 0,
+            total_score: 0,
+            session_id,
+            player1_points,
+            player2_points,
+            active: true,
+            proven_senses: 0,
+            session_key: None,
+            next_nonce: 0,
+            tier,
+            ghost: None,
+            sense_failures: Map::new(env),
+            retry_penalty: 0,
+            score_cap: config.score_cap,
+            payout_bps: config.payout_bps,
+        },
+    )
+}
+
+/// Check the result of ending a hub session. A session the hub already ended
+/// (force-settled once its timeout passed, cancelled under the hub's default
+/// policy) counts as settled; any other failure is `HubCallFailed`, rolling
+/// the caller back.
+fn hub_settled<T, C>(
+    result: Result<Result<T, C>, Result<soroban_sdk::Error, InvokeError>>,
+) -> Result<(), Error> {
+    match result {
+        Ok(_) => Ok(()),
+        Err(Ok(error))
+            if error == soroban_sdk::Error::from_contract_error(HUB_SESSION_ALREADY_ENDED) =>
+        {
+            Ok(())
+        }
+        Err(_) => Err(Error::HubCallFailed),
+    }
+}
+
+/// Close the session, settle with the hub and record the outcome.
+///
+/// `run_proof_digest` is the aggregated proof's digest when the run exits
+/// with one; otherwise the credential commits to the per-sense proofs.
+fn close_session(
+    env: &Env,
+    player: &Address,
+    mut session: CalibrationSession,
+    run_proof_digest: Option<BytesN<32>>,
+) -> Result<(bool, u64), Error> {
+    // Close session regardless of outcome
+    session.active = false;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Session(player.clone()), &session);
+
+    let hub_addr = sgs_common::hub_address(env).ok_or(Error::HubCallFailed)?;
+    let hub = GameHubClient::new(env, &hub_addr);
+
+    // Check if incomplete (missing senses)
+    if session.completed_senses != ALL_SENSES {
+        record_run_closed(env, &session, RunOutcome::Incomplete);
+        record_progress(env, player, &session, None);
+        record_exit_achievements(env, player, &session, false);
+
+        // Incomplete — house (Player 2) wins
+        hub_settled(hub.try_end_game(&session.session_id, &false))?;
+
+        // Don't add to leaderboard, but don't fail transaction
+        return Ok((false, session.total_score));
+    }
+
+    // All senses complete - check score
+    if session.total_score > session.score_cap {
+        record_run_closed(env, &session, RunOutcome::Overloaded);
+        record_progress(env, player, &session, Some("overloaded"));
+        record_exit_achievements(env, player, &session, false);
+
+        // Score too high — house (Player 2) wins
+        hub_settled(hub.try_end_game(&session.session_id, &false))?;
+
+        OverloadEvent {
+            character: session.character,
+            total_score: session.total_score,
+        }
+        .publish(env);
+
+        // Don't add to leaderboard, but don't fail transaction
+        return Ok((false, session.total_score));
+    }
+
+    // Challenge not beaten — the ghost's owner (Player 2) wins
+    if !is_winning_run(env, &session) {
+        record_run_closed(env, &session, RunOutcome::Outpaced);
+        record_progress(env, player, &session, None);
+        record_exit_achievements(env, player, &session, false);
+
+        hub_settled(hub.try_end_game(&session.session_id, &false))?;
+
+        ChallengeEvent {
+            ghost_session_id: session.ghost.unwrap_or_default(),
+            owner: session.player2.clone(),
+            challenger_won: false,
+        }
+        .publish(env);
+
+        return Ok((false, session.total_score));
+    }
+
+    record_run_closed(env, &session, RunOutcome::Embodied);
+    record_progress(env, player, &session, Some("embodied"));
+
+    // Player 1 wins — unlock points in favour of player
+    hub_settled(hub.try_end_game(&session.session_id, &true))?;
+
+    // Record on leaderboard
+    let entry = LeaderboardEntry {
+        player: player.clone(),
+        character: session.character,
+        total_score: session.total_score,
+        timestamp: env.ledger().timestamp(),
+        tier: session.tier,
+        session_id: session.session_id,
+        retries: session.sense_failures.values().iter().sum(),
+        retry_penalty: session.retry_penalty,
+    };
+
+    let mut leaderboard = load_leaderboard(env);
+    if insert_entry(&mut leaderboard, entry) {
+        env.storage()
             .instance()
-            .get(&DataKey::SessionCounter)
-            .unwrap_or(0);
-        counter = counter.saturating_add(1);
+            .set(&DataKey::Leaderboard, &leaderboard);
+    }
+
+    record_ghost(env, player, &session);
+
+    let proof_digest = run_proof_digest.unwrap_or_else(|| sense_proofs_digest(env, player));
+    record_credential(env, player, &session, proof_digest);
+    record_exit_achievements(env, player, &session, true);
+
+    if let Some(ghost_session_id) = session.ghost {
+        ChallengeEvent {
+            ghost_session_id,
+            owner: session.player2.clone(),
+            challenger_won: true,
+        }
+        .publish(env);
+    }
+
+    CompleteEvent {
+        character: session.character,
+        total_score: session.total_score,
+    }
+    .publish(env);
+
+    Ok((true, session.total_score))
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct SaBirthContract;
+
+#[contractimpl]
+impl SaBirthContract {
+    // ── Constructor (SGS template signature — do NOT change) ─────────────────
+    /// Called once by the SGS deploy script:
+    ///   `stellar contract invoke ... -- __constructor --admin <ADDR> --game_hub <ADDR>`
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        sgs_common::init(&env, &admin, &game_hub);
         env.storage()
             .instance()
-            .set(&DataKey::SessionCounter, &counter);
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+    }
+
+    // ── start_game ────────────────────────────────────────────────────────────
+    /// Start a calibration session.
+    ///
+    /// Called by the frontend when Player 1 wants to begin a new game.
+    /// Player 2 is the system/house wallet — only Player 1 needs to authorize
+    /// since they're committing their own stake. The Hub locks both players' points.
+    ///
+    /// After calling this, the frontend should call `set_character()` to record
+    /// the player's chosen character (ALICE/ROBERT/CAROL).
+    ///
+    /// Subject to the admin's `RateLimits`: fails with `CooldownActive` or
+    /// `SessionQuotaExceeded` (see `get_limits_status`).
+    ///
+    /// Plays the Standard tier; see `start_game_with_tier` for the others.
+    ///
+    /// Returns Ok(()) on success.
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        Self::start_game_with_tier(
+            env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            TIER_STANDARD,
+        )
+    }
+
+    // ── start_game_with_tier ──────────────────────────────────────────────────
+    /// Start a calibration session in a difficulty tier
+    /// (0=Standard, 1=Novice, 2=Overclock).
+    ///
+    /// The tier's `TierConfig` fixes the stakes: player1_points must be at
+    /// least `min_stake` and player2_points must equal
+    /// `player1_points × payout_bps / 10_000`, otherwise `InvalidStake`.
+    pub fn start_game_with_tier(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        tier: u32,
+    ) -> Result<(), Error> {
+        // Players must be different
+        if player1 == player2 {
+            panic!("player1 and player2 must differ");
+        }
+
+        // Only player1 needs to authorize (player2 is the house/system wallet)
+        player1.require_auth_for_args(
+            vec![&env, session_id.into_val(&env), player1_points.into_val(&env)],
+        );
+
+        open_tiered_session(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            tier,
+        )
+    }
+
+    // ── start_game_reserved ───────────────────────────────────────────────────
+    /// Start a calibration session like `start_game_with_tier`, with the
+    /// session_id issued by the hub's `reserve_session` instead of chosen by
+    /// the frontend. Player 1 authorizes the stake and tier only.
+    ///
+    /// Returns the issued session_id.
+    pub fn start_game_reserved(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        tier: u32,
+    ) -> Result<u32, Error> {
+        // Players must be different
+        if player1 == player2 {
+            panic!("player1 and player2 must differ");
+        }
+
+        player1.require_auth_for_args(
+            vec![&env, player1_points.into_val(&env), tier.into_val(&env)],
+        );
+
+        let hub_addr = sgs_common::hub_address(&env).ok_or(Error::HubCallFailed)?;
+        let session_id =
+            GameHubClient::new(&env, &hub_addr).reserve_session(&env.current_contract_address());
+
+        open_tiered_session(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            tier,
+        )?;
+        Ok(session_id)
+    }
+
+    // ── publish_ghost ─────────────────────────────────────────────────────────
+    /// Offer a ghost run up for challenges. Must be called by the ghost's owner.
+    ///
+    /// Challengers may stake at most `max_stake`, which the owner matches as
+    /// Player 2. Publish again to change the cap; a cap of 0 only admits
+    /// unstaked challenges. Fails with `GameNotFound` for an unknown ghost.
+    pub fn publish_ghost(env: Env, session_id: u32, max_stake: i128) -> Result<(), Error> {
+        let ghost: GhostRun = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Ghost(session_id))
+            .ok_or(Error::GameNotFound)?;
+        ghost.owner.require_auth();
+
+        if max_stake < 0 {
+            return Err(Error::InvalidStake);
+        }
+
+        let cap_key = DataKey::GhostStakeCap(session_id);
+        env.storage().persistent().set(&cap_key, &max_stake);
+        extend_persistent(&env, &cap_key);
+        Ok(())
+    }
+
+    // ── start_challenge ───────────────────────────────────────────────────────
+    /// Start a session that challenges a published ghost run.
+    ///
+    /// `ghost_session_id` is the `session_id` of a leaderboard entry. The
+    /// ghost's owner takes the house's place as Player 2 and matches the
+    /// challenger's stake; the run uses the ghost's character and tier. The
+    /// challenger wins only by completing all six senses, each with a lower
+    /// score than the ghost recorded, otherwise the owner is paid.
+    ///
+    /// The owner must have published the ghost (see `publish_ghost`), else
+    /// `GhostNotPublished`; a stake above their cap fails with `InvalidStake`.
+    pub fn start_challenge(
+        env: Env,
+        session_id: u32,
+        challenger: Address,
+        ghost_session_id: u32,
+        challenger_points: i128,
+    ) -> Result<(), Error> {
+        challenger.require_auth_for_args(
+            vec![&env, session_id.into_val(&env), challenger_points.into_val(&env)],
+        );
+
+        let ghost_key = DataKey::Ghost(ghost_session_id);
+        let ghost: GhostRun = env
+            .storage()
+            .persistent()
+            .get(&ghost_key)
+            .ok_or(Error::GameNotFound)?;
+        if ghost.owner == challenger {
+            panic!("player1 and player2 must differ");
+        }
+
+        let cap_key = DataKey::GhostStakeCap(ghost_session_id);
+        let max_stake: i128 = env
+            .storage()
+            .persistent()
+            .get(&cap_key)
+            .ok_or(Error::GhostNotPublished)?;
+
+        let config = tier_config(&env, ghost.tier).ok_or(Error::InvalidTier)?;
+        if challenger_points < config.min_stake || challenger_points > max_stake {
+            return Err(Error::InvalidStake);
+        }
+
+        // Keep the ghost around for as long as the challenge can run
+        extend_persistent(&env, &ghost_key);
+        extend_persistent(&env, &cap_key);
+
+        open_session(
+            &env,
+            CalibrationSession {
+                player: challenger,
+                player2: ghost.owner,
+                character: ghost.character,
+                completed_senses: 0,
+                total_score: 0,
+                session_id,
+                player1_points: challenger_points,
+                player2_points: challenger_points,
+                active: true,
+                proven_senses: 0,
+                session_key: None,
+                next_nonce: 0,
+                tier: ghost.tier,
+                ghost: Some(ghost_session_id),
+                sense_failures: Map::new(&env),
+                retry_penalty: 0,
+                score_cap: config.score_cap,
+                payout_bps: config.payout_bps,
+            },
+        )
+    }
+
+    // ── set_character ─────────────────────────────────────────────────────────
+    /// Record the player's chosen character (ALICE/ROBERT/CAROL).
+    ///
+    /// Must be called after `start_game()` and before any maze attempts; once a
+    /// sense is submitted or failed the character is locked.
+    /// Returns the session_id for the active session.
+    pub fn set_character(
+        env: Env,
+        player: Address,
+        character: u32,
+    ) -> Result<u32, Error> {
+        player.require_auth();
+
+        if character > 2 {
+            return Err(Error::InvalidCharacter);
+        }
+
+        let session_key = DataKey::Session(player.clone());
+        let mut session = load_session(&env, &session_key).ok_or(Error::GameNotFound)?;
+
+        if !session.active {
+            return Err(Error::SessionNotActive);
+        }
+
+        // A challenge must be played with the ghost's character
+        if session.ghost.is_some() && session.character != character {
+            return Err(Error::InvalidCharacter);
+        }
+
+        // Senses are proven against the character's mazes
+        if session.completed_senses != 0 || !session.sense_failures.is_empty() {
+            return Err(Error::SessionAlreadyActive);
+        }
+
+        session.character = character;
+        env.storage().persistent().set(&session_key, &session);
+        extend_persistent(&env, &session_key);
+
+        StartEvent {
+            character,
+            session_id: session.session_id,
+        }
+        .publish(&env);
+
+        Ok(session.session_id)
+    }
+
+    // ── submit_sense_completion ───────────────────────────────────────────────
+    /// Submit the result of one maze sense.
+    ///
+    /// On-chain validation mirrors the ZK circuit constraints:
+    ///   • score == points × time_ms
+    ///   • maze_id == (tier << 16) | (character << 8) | sense_id
+    ///   • maze_root == committed root of the maze (see `get_maze_root`)
+    ///   • time_ms includes HAZARD_PENALTY_MS for each of the `hazards` hit
+    ///
+    /// Senses with unmet prerequisites fail with `SenseLocked`; see
+    /// `get_available_senses`.
+    ///
+    /// `proof_hex` may be empty when the player intends to cover the run with a
+    /// single aggregated proof at `attempt_exit_with_proof`. Senses submitted
+    /// with a proof are marked in `proven_senses`; only those unlock
+    /// `SenseUnder` achievements.
+    ///
+    /// Full Barretenberg UltraHonk verification can be wired in here once a
+    /// Soroban-native verifier is available; for now proofs are stored for
+    /// off-chain auditability.
+    ///
+    /// `sense_id`: 0=hearing, 1=smell, 2=taste, 3=touch, 4=sight, 5=proprioception
+    pub fn submit_sense_completion(
+        env: Env,
+        player: Address,
+        sense_id: u32,
+        maze_id: u32,
+        maze_root: BytesN<32>,
+        points: u64,
+        time_ms: u64,
+        hazards: u32,
+        score: u64,
+        proof_hex: Bytes,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        if sense_id >= SENSE_COUNT {
+            return Err(Error::InvalidSense);
+        }
+
+        let session = load_active_session(&env, &player, RunKind::Ranked)?;
+
+        record_sense(
+            &env, &player, RunKind::Ranked, session, sense_id, maze_id, maze_root, points, time_ms,
+            hazards, score, &proof_hex,
+        )
+    }
+
+    // ── fail_sense ────────────────────────────────────────────────────────────
+    /// Record that the player failed a sense (the frontend's SenseFailure).
+    ///
+    /// Within the admin's `RetryPolicy` the sense may be attempted again and
+    /// `penalty` is added to total_score. Once the retries are used up, the
+    /// next failure locks the sense, so the run can no longer complete.
+    ///
+    /// Returns whether the sense can still be retried.
+    pub fn fail_sense(env: Env, player: Address, sense_id: u32) -> Result<bool, Error> {
+        player.require_auth();
+
+        if sense_id >= SENSE_COUNT {
+            return Err(Error::InvalidSense);
+        }
+
+        let mut session = load_active_session(&env, &player, RunKind::Ranked)?;
+        if session.completed_senses & (1 << sense_id) != 0 {
+            return Err(Error::AlreadyGuessed);
+        }
+        check_sense_unlocked(&env, session.completed_senses, sense_id)?;
+        if retries_exhausted(&env, &session, sense_id) {
+            return Err(Error::RetriesExhausted);
+        }
+
+        let policy = get_retry_policy(&env);
+        let failures = session.sense_failures.get(sense_id).unwrap_or(0) + 1;
+        session.sense_failures.set(sense_id, failures);
+
+        let can_retry = failures <= policy.max_retries;
+        let penalty = if can_retry { policy.penalty } else { 0 };
+        session.total_score = session.total_score.saturating_add(penalty);
+        session.retry_penalty = session.retry_penalty.saturating_add(penalty);
+        save_session(&env, &player, RunKind::Ranked, &session);
+
+        // A failure is an attempt that didn't complete
+        let mut stats = load_sense_stats(&env, session.character, sense_id);
+        stats.attempts = stats.attempts.saturating_add(1);
+        save_sense_stats(&env, session.character, sense_id, &stats);
+
+        SenseFailedEvent {
+            sense_id,
+            failures,
+            penalty,
+            can_retry,
+        }
+        .publish(&env);
+
+        Ok(can_retry)
+    }
+
+    // ── register_session_key ──────────────────────────────────────────────────
+    /// Register the ed25519 public key that signs relayed submissions for the
+    /// player's active session. Re-registering replaces the key; the nonce
+    /// sequence carries on so old signatures stay unusable.
+    pub fn register_session_key(
+        env: Env,
+        player: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut session = load_active_session(&env, &player, RunKind::Ranked)?;
+        session.session_key = Some(public_key);
+        save_session(&env, &player, RunKind::Ranked, &session);
+
+        Ok(())
+    }
+
+    // ── submit_sense_signed ───────────────────────────────────────────────────
+    /// Relayer entry point: submit a sense on behalf of `player` without their
+    /// transaction signature.
+    ///
+    /// The payload must be signed with the session key registered through
+    /// `register_session_key`, name this contract and `player`, target the
+    /// player's active session, carry the
+    /// session's next nonce and commit to `proof_hex` via `proof_digest`. An
+    /// invalid signature traps in the host. The sense is then validated exactly
+    /// like `submit_sense_completion`.
+    pub fn submit_sense_signed(
+        env: Env,
+        player: Address,
+        payload: SignedSensePayload,
+        signature: BytesN<64>,
+        proof_hex: Bytes,
+    ) -> Result<(), Error> {
+        if payload.sense_id >= SENSE_COUNT {
+            return Err(Error::InvalidSense);
+        }
+
+        let mut session = load_active_session(&env, &player, RunKind::Ranked)?;
+        let public_key = session.session_key.clone().ok_or(Error::SessionKeyNotSet)?;
+
+        if payload.contract != env.current_contract_address()
+            || payload.player != player
+            || payload.session_id != session.session_id
+        {
+            return Err(Error::VerificationFailed);
+        }
+        if payload.nonce != session.next_nonce {
+            return Err(Error::InvalidNonce);
+        }
+        let proof_digest: BytesN<32> = env.crypto().keccak256(&proof_hex).into();
+        if payload.proof_digest != proof_digest {
+            return Err(Error::VerificationFailed);
+        }
+
+        env.crypto()
+            .ed25519_verify(&public_key, &payload.clone().to_xdr(&env), &signature);
+
+        session.next_nonce = session.next_nonce.saturating_add(1);
+
+        record_sense(
+            &env,
+            &player,
+            RunKind::Ranked,
+            session,
+            payload.sense_id,
+            payload.maze_id,
+            payload.maze_root,
+            payload.points,
+            payload.time_ms,
+            payload.hazards,
+            payload.score,
+            &proof_hex,
+        )
+    }
+
+    // ── attempt_exit ──────────────────────────────────────────────────────────
+    /// Attempt to exit calibration (complete embodiment).
+    ///
+    /// Success requires all 6 senses completed AND total_score ≤ the tier cap.
+    /// Regardless of outcome, closes the session and calls hub.end_game().
+    ///
+    /// A winning run must have a proof for every sense; runs whose senses were
+    /// submitted without proofs must exit via `attempt_exit_with_proof`.
+    ///
+    /// Returns (success: bool, total_score: u64) tuple.
+    /// success=true means player won; success=false means overload or incomplete.
+    pub fn attempt_exit(
+        env: Env,
+        player: Address,
+    ) -> Result<(bool, u64), Error> {
+        player.require_auth();
+
+        let session = load_active_session(&env, &player, RunKind::Ranked)?;
+
+        if is_winning_run(&env, &session) && session.proven_senses != ALL_SENSES {
+            return Err(Error::ProofRequired);
+        }
+
+        close_session(&env, &player, session, None)
+    }
+
+    // ── attempt_exit_with_proof ───────────────────────────────────────────────
+    /// Exit calibration with one aggregated proof covering all six senses.
+    ///
+    /// The public inputs must match what the contract recorded for the run:
+    ///   • sense_scores[i] == stored score of sense i (all 6 completed)
+    ///   • total_score == Σ sense_scores == session.total_score − retry penalties
+    ///   • score_cap == the session's cap (its tier's cap when it started)
+    ///   • character == session.character
+    ///
+    /// As with per-sense proofs, the proof itself is checked client-side; the
+    /// contract stores its digest so the run can be audited later.
+    pub fn attempt_exit_with_proof(
+        env: Env,
+        player: Address,
+        inputs: RunPublicInputs,
+        proof_hex: Bytes,
+    ) -> Result<(bool, u64), Error> {
+        player.require_auth();
+
+        let session = load_active_session(&env, &player, RunKind::Ranked)?;
+
+        if session.completed_senses != ALL_SENSES {
+            return Err(Error::BothPlayersNotGuessed);
+        }
+
+        if proof_hex.is_empty()
+            || inputs.sense_scores.len() != SENSE_COUNT
+            || inputs.score_cap != session.score_cap
+            || inputs.character != session.character
+            || inputs.total_score.checked_add(session.retry_penalty) != Some(session.total_score)
+        {
+            return Err(Error::VerificationFailed);
+        }
+
+        let mut sum: u64 = 0;
+        for sense_id in 0..SENSE_COUNT {
+            let claimed = inputs.sense_scores.get_unchecked(sense_id);
+            let recorded = load_sense_result(&env, &DataKey::SenseResult(player.clone(), sense_id))
+                .ok_or(Error::VerificationFailed)?;
+            if claimed != recorded.score {
+                return Err(Error::VerificationFailed);
+            }
+            sum = sum.checked_add(claimed).ok_or(Error::VerificationFailed)?;
+        }
+        if sum != inputs.total_score {
+            return Err(Error::VerificationFailed);
+        }
+
+        let proof_digest: BytesN<32> = env.crypto().keccak256(&proof_hex).into();
+        let record = RunProof {
+            session_id: session.session_id,
+            inputs,
+            proof_digest: proof_digest.clone(),
+        };
+        let proof_key = DataKey::RunProof(player.clone());
+        env.storage().persistent().set(&proof_key, &record);
+        extend_persistent(&env, &proof_key);
+
+        RunProofEvent {
+            character: session.character,
+            total_score: session.total_score,
+            proof_digest: proof_digest.clone(),
+        }
+        .publish(&env);
+
+        close_session(&env, &player, session, Some(proof_digest))
+    }
+
+    // ── start_practice ────────────────────────────────────────────────────────
+    /// Start an unstaked practice run with the given character.
+    ///
+    /// Practice runs never call the hub, are not rate limited and never touch
+    /// the leaderboard or statistics. They use their own session and result
+    /// keys, so a practice run can sit alongside a ranked one. Starting again
+    /// discards the previous practice run. Its session_id is always 0, which
+    /// seeds the derived maze roots.
+    pub fn start_practice(env: Env, player: Address, character: u32) -> Result<(), Error> {
+        player.require_auth();
+
+        if character > 2 {
+            return Err(Error::InvalidCharacter);
+        }
+
+        for sense_id in 0..SENSE_COUNT {
+            env.storage()
+                .persistent()
+                .remove(&RunKind::Practice.result_key(&player, sense_id));
+        }
+
+        let config = tier_config(&env, TIER_STANDARD).ok_or(Error::InvalidTier)?;
+        let session = CalibrationSession {
+            player: player.clone(),
+            player2: player.clone(), // no house in practice
+            character,
+            completed_senses: 0,
+            total_score: 0,
+            session_id: 0,
+            player1_points: 0,
+            player2_points: 0,
+            active: true,
+            proven_senses: 0,
+            session_key: None,
+            next_nonce: 0,
+            tier: TIER_STANDARD,
+            ghost: None,
+            sense_failures: Map::new(&env),
+            retry_penalty: 0,
+            score_cap: config.score_cap,
+            payout_bps: config.payout_bps,
+        };
+        save_session(&env, &player, RunKind::Practice, &session);
+
+        Ok(())
+    }
+
+    // ── submit_practice_sense ─────────────────────────────────────────────────
+    /// Submit one maze sense for the player's practice run.
+    ///
+    /// Validated exactly like `submit_sense_completion`, against the practice
+    /// session's maze roots (see `get_practice_maze_root`).
+    pub fn submit_practice_sense(
+        env: Env,
+        player: Address,
+        sense_id: u32,
+        maze_id: u32,
+        maze_root: BytesN<32>,
+        points: u64,
+        time_ms: u64,
+        hazards: u32,
+        score: u64,
+        proof_hex: Bytes,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        if sense_id >= SENSE_COUNT {
+            return Err(Error::InvalidSense);
+        }
+
+        let session = load_active_session(&env, &player, RunKind::Practice)?;
+
+        record_sense(
+            &env, &player, RunKind::Practice, session, sense_id, maze_id, maze_root, points,
+            time_ms, hazards, score, &proof_hex,
+        )
+    }
+
+    // ── exit_practice ─────────────────────────────────────────────────────────
+    /// Close the practice run and report what it would have earned.
+    ///
+    /// `success` follows the Standard-tier rule (all 6 senses, total_score ≤ SCORE_CAP)
+    /// but no proofs are required. A successful run reports the rank it would
+    /// take on the current leaderboard.
+    pub fn exit_practice(env: Env, player: Address) -> Result<PracticeOutcome, Error> {
+        player.require_auth();
+
+        let mut session = load_active_session(&env, &player, RunKind::Practice)?;
+        session.active = false;
+        save_session(&env, &player, RunKind::Practice, &session);
+
+        let success = is_winning_run(&env, &session);
+        // A run that lands past the end of a full board would not be kept
+        let projected_rank = if success {
+            let position = insert_position(&load_leaderboard(&env), session.total_score);
+            (position < MAX_LEADERBOARD_LEN).then_some(position + 1)
+        } else {
+            None
+        };
+
+        Ok(PracticeOutcome {
+            success,
+            total_score: session.total_score,
+            projected_rank,
+        })
+    }
+
+    // ── start_race ────────────────────────────────────────────────────────────
+    /// Open a head-to-head race between two human players.
+    ///
+    /// Both players authorize their own stake, which the hub locks as usual.
+    /// Senses are accepted for `duration_ledgers` ledgers. Races are played
+    /// in the Standard tier and don't feed the leaderboard or statistics.
+    ///
+    /// Subject to the admin's `RateLimits` for both players, like `start_game`.
+    pub fn start_race(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        character: u32,
+        duration_ledgers: u32,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            panic!("player1 and player2 must differ");
+        }
+
+        player1.require_auth_for_args(
+            vec![&env, session_id.into_val(&env), player1_points.into_val(&env)],
+        );
+        player2.require_auth_for_args(
+            vec![&env, session_id.into_val(&env), player2_points.into_val(&env)],
+        );
+
+        if character > 2 {
+            return Err(Error::InvalidCharacter);
+        }
+        if duration_ledgers == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        if env.storage().persistent().has(&DataKey::Race(session_id)) {
+            return Err(Error::SessionAlreadyActive);
+        }
+
+        // A race counts as a session start for both players
+        check_and_record_start(&env, &player1)?;
+        check_and_record_start(&env, &player2)?;
+
+        let hub_addr = sgs_common::hub_address(&env).ok_or(Error::HubCallFailed)?;
+
+        GameHubClient::new(&env, &hub_addr).start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        let config = tier_config(&env, TIER_STANDARD).ok_or(Error::InvalidTier)?;
+        let lane = |player: &Address, opponent: &Address, points: i128, opponent_points: i128| {
+            CalibrationSession {
+                player: player.clone(),
+                player2: opponent.clone(),
+                character,
+                completed_senses: 0,
+                total_score: 0,
+                session_id,
+                player1_points: points,
+                player2_points: opponent_points,
+                active: true,
+                proven_senses: 0,
+                session_key: None,
+                next_nonce: 0,
+                tier: TIER_STANDARD,
+                ghost: None,
+                sense_failures: Map::new(&env),
+                retry_penalty: 0,
+                score_cap: config.score_cap,
+                payout_bps: config.payout_bps,
+            }
+        };
+
+        let race = Race {
+            session_id,
+            character,
+            deadline_ledger: env.ledger().sequence().saturating_add(duration_ledgers),
+            player1: lane(&player1, &player2, player1_points, player2_points),
+            player2: lane(&player2, &player1, player2_points, player1_points),
+            settled: false,
+        };
+        save_race(&env, &race);
 
         StartEvent {
             character,
-            session_id: session.session_id,
+            session_id,
         }
         .publish(&env);
 
-        Ok(session.session_id)
+        Ok(())
     }
 
-    // ── submit_sense_completion ───────────────────────────────────────────────
-    /// Submit the result of one maze sense.
-    ///
-    /// On-chain validation mirrors the ZK circuit constraints:
-    ///   • score == points × time_ms
-    ///   • maze_id == (character << 8) | sense_id
-    ///   • proof_hex must be non-empty
-    ///
-    /// Full Barretenberg UltraHonk verification can be wired in here once a
-    /// Soroban-native verifier is available; for now proofs are stored for
-    /// off-chain auditability.
+    // ── submit_race_sense ─────────────────────────────────────────────────────
+    /// Submit one maze sense for the player's lane of a race.
     ///
-    /// `sense_id`: 0=hearing, 1=smell, 2=taste, 3=touch, 4=sight, 5=proprioception
-    pub fn submit_sense_completion(
+    /// Validated like `submit_sense_completion`, hazard penalties included,
+    /// against the race's maze roots (see `get_race_maze_root`). Fails with
+    /// `DeadlinePassed` once the race deadline has gone by.
+    pub fn submit_race_sense(
         env: Env,
         player: Address,
+        session_id: u32,
         sense_id: u32,
         maze_id: u32,
+        maze_root: BytesN<32>,
         points: u64,
         time_ms: u64,
+        hazards: u32,
         score: u64,
         proof_hex: Bytes,
     ) -> Result<(), Error> {
         player.require_auth();
 
-        if sense_id > 5 {
+        if sense_id >= SENSE_COUNT {
             return Err(Error::InvalidSense);
         }
 
-        let session_key = DataKey::Session(player.clone());
-        let mut session: CalibrationSession = env
+        let mut race: Race = env
             .storage()
             .persistent()
-            .get(&session_key)
+            .get(&DataKey::Race(session_id))
             .ok_or(Error::GameNotFound)?;
-
-        if !session.active {
-            return Err(Error::SessionNotActive);
+        if race.settled {
+            return Err(Error::GameAlreadyEnded);
         }
-
-        // Prevent re-submission for the same sense
-        let sense_bit = 1u32 << sense_id;
-        if (session.completed_senses & sense_bit) != 0 {
-            return Err(Error::AlreadyGuessed);
+        if env.ledger().sequence() > race.deadline_ledger {
+            return Err(Error::DeadlinePassed);
         }
 
-        // maze_id must match (character << 8) | sense_id
-        let expected_maze_id = (session.character << 8) | sense_id;
-        if maze_id != expected_maze_id {
-            return Err(Error::VerificationFailed);
-        }
+        let lane = if player == race.player1.player {
+            &mut race.player1
+        } else if player == race.player2.player {
+            &mut race.player2
+        } else {
+            return Err(Error::NotPlayer);
+        };
 
-        // Score must equal points × time_ms (replicates ZK circuit assertion)
-        let expected_score = points
-            .checked_mul(time_ms)
-            .ok_or(Error::VerificationFailed)?;
-        if score != expected_score {
-            return Err(Error::VerificationFailed);
+        let sense_bit = 1u32 << sense_id;
+        if (lane.completed_senses & sense_bit) != 0 {
+            return Err(Error::AlreadyGuessed);
         }
+        check_sense_unlocked(&env, lane.completed_senses, sense_id)?;
 
-        // Basic sanity: disallow trivial / overflow inputs
-        if points == 0 || time_ms == 0 || score > 10_000_000_000u64 {
-            return Err(Error::VerificationFailed);
-        }
+        verify_sense_inputs(
+            &env, lane, sense_id, maze_id, &maze_root, points, time_ms, score,
+        )?;
+        check_hazards(hazards, time_ms)?;
 
-        // Proof must be present
-        if proof_hex.len() == 0 {
-            return Err(Error::VerificationFailed);
+        lane.completed_senses |= sense_bit;
+        if !proof_hex.is_empty() {
+            lane.proven_senses |= sense_bit;
         }
-
-        // Persist sense result (proof not emitted nor stored)
-        let result = SenseResult {
-            sense_id,
-            points,
-            time_ms,
-            score,
-        };
-        let result_key = DataKey::SenseResult(player.clone(), sense_id);
-        env.storage().persistent().set(&result_key, &result);
-        env.storage()
-            .persistent()
-            .extend_ttl(&result_key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
-
-        // Update session
-        session.completed_senses |= sense_bit;
-        session.total_score = session.total_score.saturating_add(score);
-        env.storage().persistent().set(&session_key, &session);
-        env.storage()
-            .persistent()
-            .extend_ttl(&session_key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+        lane.total_score = lane.total_score.saturating_add(score);
+        save_race(&env, &race);
 
         SenseCompletedEvent {
             sense_id,
@@ -456,117 +2633,51 @@ impl SaBirthContract {
         Ok(())
     }
 
-    // ── attempt_exit ──────────────────────────────────────────────────────────
-    /// Attempt to exit calibration (complete embodiment).
+    // ── settle_race ───────────────────────────────────────────────────────────
+    /// Settle a race through the hub. Callable by anyone.
     ///
-    /// Success requires all 6 senses completed AND total_score ≤ SCORE_CAP.
-    /// Regardless of outcome, closes the session and calls hub.end_game().
+    /// Before the deadline both players must have completed all six senses.
+    /// After the deadline the race settles as it stands. Either way more
+    /// proven senses wins (so a player who never finishes forfeits), then the
+    /// lower total score. A full tie settles as a `Draw` and a race without a
+    /// single proven sense as `Cancelled`; both refund the stakes.
     ///
-    /// Returns (success: bool, total_score: u64) tuple.
-    /// success=true means player won; success=false means overload or incomplete.
-    pub fn attempt_exit(
-        env: Env,
-        player: Address,
-    ) -> Result<(bool, u64), Error> {
-        player.require_auth();
-
-        let session_key = DataKey::Session(player.clone());
-        let mut session: CalibrationSession = env
+    /// Returns the outcome reported to the hub.
+    pub fn settle_race(env: Env, session_id: u32) -> Result<GameOutcome, Error> {
+        let mut race: Race = env
             .storage()
             .persistent()
-            .get(&session_key)
+            .get(&DataKey::Race(session_id))
             .ok_or(Error::GameNotFound)?;
-
-        if !session.active {
-            return Err(Error::SessionNotActive);
+        if race.settled {
+            return Err(Error::GameAlreadyEnded);
         }
 
-        // Close session regardless of outcome
-        session.active = false;
-        env.storage().persistent().set(&session_key, &session);
-
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .ok_or(Error::HubCallFailed)?;
-
-        // Check if incomplete (missing senses)
-        if session.completed_senses != 0b11_1111 {
-            // Incomplete — house (Player 2) wins
-            env.invoke_contract::<()>(
-                &hub_addr,
-                &Symbol::new(&env, "end_game"),
-                vec![
-                    &env,
-                    session.session_id.into_val(&env),
-                    false.into_val(&env), // player1_won = false
-                ],
-            );
-
-            // Don't add to leaderboard, but don't fail transaction
-            return Ok((false, session.total_score));
-        }
-
-        // All senses complete - check score
-        if session.total_score > SCORE_CAP {
-            // Score too high — house (Player 2) wins
-            env.invoke_contract::<()>(
-                &hub_addr,
-                &Symbol::new(&env, "end_game"),
-                vec![
-                    &env,
-                    session.session_id.into_val(&env),
-                    false.into_val(&env), // player1_won = false
-                ],
-            );
-
-            OverloadEvent {
-                character: session.character,
-                total_score: session.total_score,
-            }
-            .publish(&env);
-
-            // Don't add to leaderboard, but don't fail transaction
-            return Ok((false, session.total_score));
+        let both_finished = race.player1.completed_senses == ALL_SENSES
+            && race.player2.completed_senses == ALL_SENSES;
+        if !both_finished && env.ledger().sequence() <= race.deadline_ledger {
+            return Err(Error::DeadlineNotReached);
         }
 
-        // Player 1 wins — unlock points in favour of player
-        env.invoke_contract::<()>(
-            &hub_addr,
-            &Symbol::new(&env, "end_game"),
-            vec![
-                &env,
-                session.session_id.into_val(&env),
-                true.into_val(&env), // player1_won = true
-            ],
-        );
-
-        // Record on leaderboard
-        let entry = LeaderboardEntry {
-            player: player.clone(),
-            character: session.character,
-            total_score: session.total_score,
-            timestamp: env.ledger().timestamp(),
-        };
+        let outcome = race_outcome(&race);
+        race.settled = true;
+        race.player1.active = false;
+        race.player2.active = false;
+        save_race(&env, &race);
 
-        let lb_key = DataKey::Leaderboard;
-        let mut leaderboard: Vec<LeaderboardEntry> = env
-            .storage()
-            .instance()
-            .get(&lb_key)
-            .unwrap_or(Vec::new(&env));
+        let hub_addr = sgs_common::hub_address(&env).ok_or(Error::HubCallFailed)?;
 
-        leaderboard.push_back(entry);
-        env.storage().instance().set(&lb_key, &leaderboard);
+        hub_settled(GameHubClient::new(&env, &hub_addr).try_end_game_v2(&session_id, &outcome))?;
 
-        CompleteEvent {
-            character: session.character,
-            total_score: session.total_score,
+        RaceSettledEvent {
+            session_id,
+            outcome,
+            player1_score: race.player1.total_score,
+            player2_score: race.player2.total_score,
         }
         .publish(&env);
 
-        Ok((true, session.total_score))
+        Ok(outcome)
     }
 
     // ── Read-only helpers ─────────────────────────────────────────────────────
@@ -583,96 +2694,452 @@ impl SaBirthContract {
 
     /// Get calibration session by player address (preferred over get_game).
     pub fn get_session(env: Env, player: Address) -> Option<CalibrationSession> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Session(player))
+        load_session(&env, &DataKey::Session(player))
     }
 
     /// Get stored sense result for a player (includes proof bytes).
     pub fn get_sense_result(env: Env, player: Address, sense_id: u32) -> Option<SenseResult> {
-        if sense_id > 5 {
+        if sense_id >= SENSE_COUNT {
+            return None;
+        }
+        load_sense_result(&env, &DataKey::SenseResult(player, sense_id))
+    }
+
+    /// Get the senses the player can attempt next in their active session, as
+    /// a bitfield like `completed_senses`. 0 without an active session.
+    pub fn get_available_senses(env: Env, player: Address) -> u32 {
+        match load_active_session(&env, &player, RunKind::Ranked) {
+            Ok(session) => available_senses(&env, session.completed_senses),
+            Err(_) => 0,
+        }
+    }
+
+    /// Get the mask of senses that must be completed before `sense_id`.
+    pub fn get_sense_prerequisites(env: Env, sense_id: u32) -> u32 {
+        sense_prerequisites(&env, sense_id)
+    }
+
+    /// Get the last aggregated run proof accepted for a player.
+    pub fn get_run_proof(env: Env, player: Address) -> Option<RunProof> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RunProof(player))
+    }
+
+    /// Get the maze commitment a sense submission must match.
+    ///
+    /// Returns the admin-published root when set, otherwise the root derived
+    /// from the player's current session seed (which does not commit to the
+    /// maze layout; see `set_maze_root`). `None` without a session.
+    pub fn get_maze_root(env: Env, player: Address, sense_id: u32) -> Option<BytesN<32>> {
+        if sense_id >= SENSE_COUNT {
+            return None;
+        }
+        let session = load_session(&env, &DataKey::Session(player))?;
+        Some(expected_maze_root(&env, &session, sense_id))
+    }
+
+    /// Get a player's embodiment credential for one character.
+    /// Part of the stable `EmbodimentCredentials` interface.
+    pub fn get_credential(env: Env, player: Address, character: u32) -> Option<EmbodimentCredential> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Credential(player, character))
+    }
+
+    /// Whether the player has ever embodied `character`.
+    /// Part of the stable `EmbodimentCredentials` interface.
+    pub fn has_credential(env: Env, player: Address, character: u32) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Credential(player, character))
+    }
+
+    /// Get the player's unlocked achievements as a bitset (bit i = id i).
+    pub fn get_achievements(env: Env, player: Address) -> u128 {
+        load_player_achievements(&env, &player).unlocked
+    }
+
+    /// Get the player's current streak of consecutive successful exits.
+    pub fn get_streak(env: Env, player: Address) -> u32 {
+        load_player_achievements(&env, &player).streak
+    }
+
+    /// Get the achievement table; an achievement's id is its index.
+    pub fn get_achievement_table(env: Env) -> Vec<AchievementRule> {
+        load_achievement_table(&env)
+    }
+
+    /// Get the ghost left by a successful run, keyed by its session id.
+    pub fn get_ghost(env: Env, session_id: u32) -> Option<GhostRun> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Ghost(session_id))
+    }
+
+    /// Get the largest stake a ghost's owner agreed to match, if published.
+    pub fn get_ghost_stake_cap(env: Env, session_id: u32) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GhostStakeCap(session_id))
+    }
+
+    /// Get a race by session id.
+    pub fn get_race(env: Env, session_id: u32) -> Option<Race> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Race(session_id))
+    }
+
+    /// Get the maze commitment shared by both players of a race.
+    pub fn get_race_maze_root(env: Env, session_id: u32, sense_id: u32) -> Option<BytesN<32>> {
+        if sense_id >= SENSE_COUNT {
+            return None;
+        }
+        let race: Race = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Race(session_id))?;
+        Some(expected_maze_root(&env, &race.player1, sense_id))
+    }
+
+    /// Get the current (or last) practice run for a player.
+    pub fn get_practice_session(env: Env, player: Address) -> Option<CalibrationSession> {
+        env.storage()
+            .persistent()
+            .get(&RunKind::Practice.session_key(&player))
+    }
+
+    /// Get a stored practice sense result for a player.
+    pub fn get_practice_result(env: Env, player: Address, sense_id: u32) -> Option<SenseResult> {
+        if sense_id >= SENSE_COUNT {
             return None;
         }
         env.storage()
             .persistent()
-            .get(&DataKey::SenseResult(player, sense_id))
+            .get(&RunKind::Practice.result_key(&player, sense_id))
+    }
+
+    /// Get the maze commitment a practice submission must match.
+    pub fn get_practice_maze_root(env: Env, player: Address, sense_id: u32) -> Option<BytesN<32>> {
+        if sense_id >= SENSE_COUNT {
+            return None;
+        }
+        let session: CalibrationSession = env
+            .storage()
+            .persistent()
+            .get(&RunKind::Practice.session_key(&player))?;
+        Some(expected_maze_root(&env, &session, sense_id))
+    }
+
+    /// Aggregate metrics for difficulty tuning: run-level counters plus one
+    /// entry per `(character, sense_id)` maze (character-major order).
+    pub fn get_stats(env: Env) -> CalibrationStats {
+        let mut senses = Vec::new(&env);
+        for character in 0..3u32 {
+            for sense_id in 0..SENSE_COUNT {
+                let stats = load_sense_stats(&env, character, sense_id);
+                let completions = stats.completions as u64;
+                senses.push_back(SenseStatsView {
+                    character,
+                    sense_id,
+                    attempts: stats.attempts,
+                    completions: stats.completions,
+                    min_score: stats.min_score,
+                    max_score: stats.max_score,
+                    mean_score: stats.score_sum.checked_div(completions).unwrap_or(0),
+                    mean_time_ms: stats.time_sum_ms.checked_div(completions).unwrap_or(0),
+                });
+            }
+        }
+
+        CalibrationStats {
+            run: load_run_stats(&env),
+            senses,
+        }
+    }
+
+    /// Get the rate limits currently applied to `start_game`.
+    pub fn get_rate_limits(env: Env) -> RateLimits {
+        get_rate_limits(&env)
+    }
+
+    /// Show when `player` can start their next session and how much of the
+    /// rolling-window quota they have used.
+    pub fn get_limits_status(env: Env, player: Address) -> LimitsStatus {
+        limits_status(&env, &player).0
     }
 
     /// Get leaderboard sorted by total_score ascending (lower = better).
+    /// Only the best `MAX_LEADERBOARD_LEN` (100) runs are kept.
     pub fn get_leaderboard(env: Env) -> Vec<LeaderboardEntry> {
-        let mut leaderboard: Vec<LeaderboardEntry> = env
-            .storage()
-            .instance()
-            .get(&DataKey::Leaderboard)
-            .unwrap_or(Vec::new(&env));
-
-        let len = leaderboard.len();
-        if len <= 1 {
-            return leaderboard;
-        }
-
-        // Bubble sort — acceptable for small leaderboards
-        for i in 0..len {
-            for j in 0..(len - i - 1) {
-                let curr_score = leaderboard.get(j).unwrap().total_score;
-                let next_score = leaderboard.get(j + 1).unwrap().total_score;
-                if curr_score > next_score {
-                    let curr = leaderboard.get(j).unwrap();
-                    let next = leaderboard.get(j + 1).unwrap();
-                    leaderboard.set(j, next);
-                    leaderboard.set(j + 1, curr);
-                }
+        load_leaderboard(&env)
+    }
+
+    /// 1-based leaderboard rank of the player's best run, if they have one.
+    pub fn get_rank(env: Env, player: Address) -> Option<u32> {
+        best_index(&load_leaderboard(&env), &player).map(|index| index + 1)
+    }
+
+    /// Get `limit` entries starting at 0-based `offset` (limit capped at 50).
+    pub fn get_leaderboard_page(env: Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        let leaderboard = load_leaderboard(&env);
+        leaderboard_slice(&env, &leaderboard, offset, limit.min(MAX_PAGE_SIZE))
+    }
+
+    /// Get the player's best entry with up to `radius` entries either side
+    /// (radius capped so the window fits one page). Empty if unranked.
+    pub fn get_leaderboard_around(env: Env, player: Address, radius: u32) -> Vec<LeaderboardEntry> {
+        let leaderboard = load_leaderboard(&env);
+        let Some(index) = best_index(&leaderboard, &player) else {
+            return Vec::new(&env);
+        };
+        let radius = radius.min(MAX_PAGE_SIZE / 2);
+        let start = index.saturating_sub(radius);
+        leaderboard_slice(&env, &leaderboard, start, index - start + radius + 1)
+    }
+
+    /// Get a page of the leaderboard restricted by character and/or timestamp
+    /// range. `offset` and `limit` apply to the filtered ordering.
+    pub fn get_leaderboard_filtered(
+        env: Env,
+        filter: LeaderboardFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<LeaderboardEntry> {
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut page = Vec::new(&env);
+        let mut skipped = 0u32;
+
+        for entry in load_leaderboard(&env).iter() {
+            if page.len() >= limit {
+                break;
             }
+            if filter.character.is_some_and(|character| entry.character != character)
+                || filter.tier.is_some_and(|tier| entry.tier != tier)
+                || filter.from_timestamp.is_some_and(|from| entry.timestamp < from)
+                || filter.to_timestamp.is_some_and(|to| entry.timestamp > to)
+            {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            page.push_back(entry);
         }
 
-        leaderboard
+        page
     }
 
     // ── Admin functions (SGS template pattern) ────────────────────────────────
 
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        sgs_common::get_admin(&env)
     }
 
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        sgs_common::set_admin(&env, &new_admin);
     }
 
     pub fn get_hub(env: Env) -> Address {
+        sgs_common::get_hub(&env)
+    }
+
+    pub fn set_hub(env: Env, new_hub: Address) {
+        sgs_common::set_hub(&env, &new_hub);
+    }
+
+    /// Get the rules currently applied to a tier (`None` for unknown tiers).
+    pub fn get_tier_config(env: Env, tier: u32) -> Option<TierConfig> {
+        tier_config(&env, tier)
+    }
+
+    /// Override a tier's cap, minimum stake and payout multiplier.
+    /// Applies to sessions started afterwards; running sessions keep the cap
+    /// and payout they started with.
+    pub fn set_tier_config(env: Env, tier: u32, config: TierConfig) -> Result<(), Error> {
+        sgs_common::require_admin(&env);
+
+        if tier > TIER_OVERCLOCK {
+            return Err(Error::InvalidTier);
+        }
+        if config.min_stake < 0 {
+            return Err(Error::InvalidStake);
+        }
+
         env.storage()
             .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+            .set(&DataKey::TierConfig(tier), &config);
+        Ok(())
     }
 
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
+    /// Get the progression registry exits report to, if configured.
+    pub fn get_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Registry)
+    }
+
+    /// Point exits at a progression registry. The registry admin must also
+    /// allowlist this contract as a writer.
+    pub fn set_registry(env: Env, registry: Address) {
+        sgs_common::require_admin(&env);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+    }
+
+    /// Stop reporting exits to the progression registry.
+    pub fn clear_registry(env: Env) {
+        sgs_common::require_admin(&env);
+        env.storage().instance().remove(&DataKey::Registry);
+    }
+
+    /// Append an achievement to the table and return its id.
+    /// Existing unlocks are unaffected; the new rule applies from now on.
+    pub fn add_achievement(env: Env, rule: AchievementRule) -> Result<u32, Error> {
+        sgs_common::require_admin(&env);
+
+        let mut table = load_achievement_table(&env);
+        if table.len() >= MAX_ACHIEVEMENTS {
+            return Err(Error::AchievementTableFull);
+        }
+        table.push_back(rule);
+        env.storage().instance().set(&DataKey::Achievements, &table);
+        Ok(table.len() - 1)
+    }
+
+    /// Replace the rule behind an existing achievement id
+    /// (use `AchievementRule::Retired` to retire it).
+    pub fn set_achievement(env: Env, achievement_id: u32, rule: AchievementRule) -> Result<(), Error> {
+        sgs_common::require_admin(&env);
+
+        let mut table = load_achievement_table(&env);
+        if achievement_id >= table.len() {
+            return Err(Error::InvalidAchievement);
+        }
+        table.set(achievement_id, rule);
+        env.storage().instance().set(&DataKey::Achievements, &table);
+        Ok(())
+    }
+
+    /// Set the senses (as a bitfield) that must be completed before
+    /// `sense_id` can be submitted; 0 removes the requirement. The graph must
+    /// stay acyclic so every sense remains reachable.
+    pub fn set_sense_prerequisites(env: Env, sense_id: u32, prerequisites: u32) -> Result<(), Error> {
+        sgs_common::require_admin(&env);
+
+        if sense_id >= SENSE_COUNT || prerequisites & !ALL_SENSES != 0 {
+            return Err(Error::InvalidSense);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SensePrerequisites(sense_id), &prerequisites);
+
+        // Completing every available sense in turn must reach all six
+        // (an error return rolls the write back)
+        let mut completed = 0u32;
+        loop {
+            let available = available_senses(&env, completed);
+            if available == 0 {
+                break;
+            }
+            completed |= available;
+        }
+        if completed != ALL_SENSES {
+            return Err(Error::PrerequisiteCycle);
+        }
+
+        Ok(())
+    }
+
+    /// Get the retry policy applied by `fail_sense`.
+    pub fn get_retry_policy(env: Env) -> RetryPolicy {
+        get_retry_policy(&env)
+    }
+
+    /// Configure how many times a failed sense may be retried and the
+    /// total_score penalty per retry.
+    pub fn set_retry_policy(env: Env, policy: RetryPolicy) {
+        sgs_common::require_admin(&env);
+
+        env.storage().instance().set(&DataKey::RetryPolicy, &policy);
+    }
+
+    /// Configure per-player rate limits for `start_game` (zero disables a limit).
+    pub fn set_rate_limits(env: Env, limits: RateLimits) {
+        sgs_common::require_admin(&env);
+
+        env.storage().instance().set(&DataKey::RateLimits, &limits);
+    }
+
+    /// Publish the Merkle root of a `(character, sense_id)` maze grid.
+    /// Overrides the session-derived commitment for that maze.
+    pub fn set_maze_root(
+        env: Env,
+        character: u32,
+        sense_id: u32,
+        root: BytesN<32>,
+    ) -> Result<(), Error> {
+        sgs_common::require_admin(&env);
+
+        if character > 2 {
+            return Err(Error::InvalidCharacter);
+        }
+        if sense_id >= SENSE_COUNT {
+            return Err(Error::InvalidSense);
+        }
+
+        env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .set(&DataKey::MazeRoot(character, sense_id), &root);
+        Ok(())
+    }
+
+    /// Remove a published maze root, falling back to the session-derived one.
+    pub fn clear_maze_root(env: Env, character: u32, sense_id: u32) {
+        sgs_common::require_admin(&env);
+
         env.storage()
             .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .remove(&DataKey::MazeRoot(character, sense_id));
     }
 
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        sgs_common::upgrade(&env, new_wasm_hash);
+    }
+
+    /// Bring storage written by an older version up to `SCHEMA_VERSION`.
+    /// Call once after `upgrade`; a no-op when already current.
+    ///
+    /// Rewrites the leaderboard sorted by score, with the newer entry fields
+    /// defaulted and capped at `MAX_LEADERBOARD_LEN`. Sessions and sense
+    /// results are per player, so they are converted when next read instead.
+    ///
+    /// Returns the schema version storage was at before the call.
+    pub fn migrate(env: Env) -> u32 {
+        sgs_common::require_admin(&env);
+
+        let from = schema_version(&env);
+        if from >= SCHEMA_VERSION {
+            return from;
+        }
+
+        let leaderboard = legacy_leaderboard(&env);
+        let storage = env.storage().instance();
+        storage.set(&DataKey::Leaderboard, &leaderboard);
+        storage.set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        from
+    }
+
+    /// Storage layout version; below the contract's current version until
+    /// `migrate` has run.
+    pub fn get_schema_version(env: Env) -> u32 {
+        schema_version(&env)
     }
-}
\ No newline at end of file
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
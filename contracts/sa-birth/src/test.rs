@@ -6,9 +6,24 @@
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, SaBirthContract, SaBirthContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use crate::{
+    AchievementRule, DataKey, EmbodimentCredentialsClient, Error, LeaderboardEntry,
+    LeaderboardFilter, LegacyCalibrationSession, LegacyLeaderboardEntry, LegacySenseResult,
+    MilestonesFailedEvent, RateLimits, RetryPolicy, RunPublicInputs, SaBirthContract,
+    SaBirthContractClient, SignedSensePayload, TierConfig,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use mock_game_hub::{
+    Faults, GameOutcome as HubOutcome, MockGameHub as Hub,
+    MockGameHubClient as HubClient, SessionStatus as HubStatus,
+};
+use progression_registry::{
+    ProgressionRegistry as Registry, ProgressionRegistryClient as RegistryClient,
+};
+use sgs_common::GameOutcome;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Event as _, Symbol, Vec};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -35,6 +50,10 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_v2(_env: Env, _session_id: u32, _outcome: GameOutcome) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -86,27 +105,8 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
-/// Assert that a Result contains a specific number_guess error
-///
-/// This helper provides type-safe error assertions following Stellar/Soroban best practices.
-/// Instead of using `assert_eq!(result, Err(Ok(Error::AlreadyGuessed)))`, this pattern:
-/// - Provides compile-time error checking
-/// - Makes tests more readable with named errors
-/// - Gives better failure messages
-///
-/// # Example
-/// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
-/// assert_number_guess_error(&result, Error::AlreadyGuessed);
-/// ```
-///
-/// # Type Signature
-/// The try_ methods return: `Result<Result<T, T::Error>, Result<E, InvokeError>>`
-/// - Ok(Ok(value)): Call succeeded, decode succeeded
-/// - Ok(Err(conv_err)): Call succeeded, decode failed
-/// - Err(Ok(error)): Contract reverted with custom error (THIS IS WHAT WE TEST)
-/// - Err(Err(invoke_err)): Low-level invocation failure
-fn assert_number_guess_error<T, E>(
+/// Assert that a Result contains a specific sa_birth error
+fn assert_sa_birth_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
     expected_error: Error,
 ) {
@@ -139,375 +139,2395 @@ fn assert_number_guess_error<T, E>(
     }
 }
 
+/// Start a session and pick a character.
+fn start_run(
+    client: &SaBirthContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    character: u32,
+) {
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    client.set_character(player1, &character);
+}
+
+fn dummy_proof(env: &Env) -> Bytes {
+    Bytes::from_array(env, &[0xab; 16])
+}
+
+/// Submit one sense with score = points × time_ms.
+fn submit_sense(
+    client: &SaBirthContractClient,
+    player: &Address,
+    character: u32,
+    sense_id: u32,
+    points: u64,
+    time_ms: u64,
+    proof: &Bytes,
+) {
+    let maze_root = client.get_maze_root(player, &sense_id).unwrap();
+    client.submit_sense_completion(
+        player,
+        &sense_id,
+        &((character << 8) | sense_id),
+        &maze_root,
+        &points,
+        &time_ms,
+        &0,
+        &(points * time_ms),
+        proof,
+    );
+}
+
+/// Submit all six senses with the same points/time, each with a proof.
+fn submit_all_senses(
+    env: &Env,
+    client: &SaBirthContractClient,
+    player: &Address,
+    character: u32,
+    points: u64,
+    time_ms: u64,
+) {
+    let proof = dummy_proof(env);
+    for sense_id in 0..6 {
+        submit_sense(client, player, character, sense_id, points, time_ms, &proof);
+    }
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
-fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_complete_run_reaches_leaderboard() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 1u32;
-    let points = 100_0000000;
+    start_run(&client, 1, &player1, &player2, 0);
 
-    // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let session = client.get_session(&player1).unwrap();
+    assert!(session.active);
+    assert_eq!(session.player2, player2);
+    assert_eq!(session.completed_senses, 0);
 
-    // Get game to verify state
-    let game = client.get_game(&session_id);
-    assert!(game.winning_number.is_none()); // Winning number not set yet
-    assert!(game.winner.is_none()); // Game is still active
-    assert_eq!(game.player1, player1);
-    assert_eq!(game.player2, player2);
-    assert_eq!(game.player1_points, points);
-    assert_eq!(game.player2_points, points);
+    // 6 × (20 × 10_000) = 1_200_000 ≤ SCORE_CAP
+    submit_all_senses(&env, &client, &player1, 0, 20, 10_000);
 
-    // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    let (success, total) = client.attempt_exit(&player1);
+    assert!(success);
+    assert_eq!(total, 1_200_000);
 
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    let session = client.get_session(&player1).unwrap();
+    assert!(!session.active);
 
-    // Verify game is ended and winning number is now set
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
-    assert_eq!(final_game.winner.unwrap(), winner);
-    assert!(final_game.winning_number.is_some());
-    let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    let leaderboard = client.get_leaderboard();
+    assert_eq!(leaderboard.len(), 1);
+    assert_eq!(leaderboard.get(0).unwrap().player, player1);
+    assert_eq!(leaderboard.get(0).unwrap().total_score, 1_200_000);
 }
 
 #[test]
-fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_incomplete_exit_house_wins() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 2, &player1, &player2, 1);
+    submit_sense(&client, &player1, 1, 0, 10, 1_000, &dummy_proof(&env));
+
+    let (success, total) = client.attempt_exit(&player1);
+    assert!(!success);
+    assert_eq!(total, 10_000);
+    assert_eq!(client.get_leaderboard().len(), 0);
+}
+
+#[test]
+fn test_overload_exit_house_wins() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 3, &player1, &player2, 2);
+    // 6 × (100 × 40_000) = 24_000_000 > SCORE_CAP
+    submit_all_senses(&env, &client, &player1, 2, 100, 40_000);
+
+    let (success, total) = client.attempt_exit(&player1);
+    assert!(!success);
+    assert_eq!(total, 24_000_000);
+    assert_eq!(client.get_leaderboard().len(), 0);
+}
+
+#[test]
+fn test_leaderboard_sorted_ascending() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    start_run(&client, 4, &player1, &player2, 0);
+    submit_all_senses(&env, &client, &player1, 0, 30, 10_000);
+    client.attempt_exit(&player1);
+
+    start_run(&client, 5, &player3, &player2, 1);
+    submit_all_senses(&env, &client, &player3, 1, 10, 10_000);
+    client.attempt_exit(&player3);
+
+    let leaderboard = client.get_leaderboard();
+    assert_eq!(leaderboard.len(), 2);
+    assert_eq!(leaderboard.get(0).unwrap().player, player3);
+    assert_eq!(leaderboard.get(1).unwrap().player, player1);
+}
+
+// ============================================================================
+// Leaderboard Query Tests
+// ============================================================================
+
+/// Play a full successful run for `player` scoring 6 × points × 10_000.
+fn finish_run(
+    env: &Env,
+    client: &SaBirthContractClient,
+    session_id: u32,
+    player: &Address,
+    house: &Address,
+    character: u32,
+    points: u64,
+) {
+    start_run(client, session_id, player, house, character);
+    submit_all_senses(env, client, player, character, points, 10_000);
+    client.attempt_exit(player);
+}
+
+#[test]
+fn test_rank_and_pages() {
+    let (env, client, _hub, _player1, house) = setup_test();
+
+    let players: [Address; 5] = core::array::from_fn(|_| Address::generate(&env));
+    // Inserted out of order: scores 30, 10, 50, 20, 40 (× 60_000)
+    for (i, points) in [30u64, 10, 50, 20, 40].iter().enumerate() {
+        finish_run(&env, &client, 100 + i as u32, &players[i], &house, 0, *points);
+    }
+
+    let leaderboard = client.get_leaderboard();
+    let expected = [600_000, 1_200_000, 1_800_000, 2_400_000, 3_000_000];
+    assert_eq!(leaderboard.len(), 5);
+    for (entry, score) in leaderboard.iter().zip(expected) {
+        assert_eq!(entry.total_score, score);
+    }
+
+    assert_eq!(client.get_rank(&players[1]), Some(1));
+    assert_eq!(client.get_rank(&players[0]), Some(3));
+    assert_eq!(client.get_rank(&players[2]), Some(5));
+    assert_eq!(client.get_rank(&Address::generate(&env)), None);
+
+    let page = client.get_leaderboard_page(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().player, players[3]);
+    assert_eq!(page.get(1).unwrap().player, players[0]);
+    assert_eq!(client.get_leaderboard_page(&4, &10).len(), 1);
+    assert_eq!(client.get_leaderboard_page(&9, &10).len(), 0);
+
+    let around = client.get_leaderboard_around(&players[0], &1);
+    assert_eq!(around.len(), 3);
+    assert_eq!(around.get(0).unwrap().player, players[3]);
+    assert_eq!(around.get(1).unwrap().player, players[0]);
+    assert_eq!(around.get(2).unwrap().player, players[4]);
+
+    // Window is clipped at the top of the board
+    let top = client.get_leaderboard_around(&players[1], &2);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.get(0).unwrap().player, players[1]);
+}
+
+#[test]
+fn test_rank_uses_best_run_and_ties_keep_order() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let player3 = Address::generate(&env);
 
-    let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    finish_run(&env, &client, 110, &player1, &house, 0, 20);
+    finish_run(&env, &client, 111, &player3, &house, 1, 20);
+    assert_eq!(client.get_rank(&player1), Some(1));
+    assert_eq!(client.get_rank(&player3), Some(2));
 
-    // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-    client.reveal_winner(&session_id);
+    // A better second run for player3 moves them to the top
+    finish_run(&env, &client, 112, &player3, &house, 1, 10);
+    assert_eq!(client.get_rank(&player3), Some(1));
+    assert_eq!(client.get_rank(&player1), Some(2));
+    assert_eq!(client.get_leaderboard().len(), 3);
+}
 
-    let game = client.get_game(&session_id);
-    let winning_number = game
-        .winning_number
-        .expect("Winning number should be set after reveal");
-    assert!(
-        winning_number >= 1 && winning_number <= 10,
-        "Winning number should be between 1 and 10"
+#[test]
+fn test_filtered_leaderboard() {
+    let (env, client, _hub, _player1, house) = setup_test();
+    let alice = Address::generate(&env);
+    let robert = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    finish_run(&env, &client, 120, &alice, &house, 0, 30);
+    env.ledger().with_mut(|li| li.timestamp += 1_000);
+    finish_run(&env, &client, 121, &robert, &house, 1, 10);
+    env.ledger().with_mut(|li| li.timestamp += 1_000);
+    finish_run(&env, &client, 122, &carol, &house, 0, 20);
+
+    let start = client.get_leaderboard().get(2).unwrap().timestamp;
+
+    let alice_only = client.get_leaderboard_filtered(
+        &LeaderboardFilter {
+            character: Some(0),
+            tier: None,
+            from_timestamp: None,
+            to_timestamp: None,
+        },
+        &0,
+        &10,
     );
+    assert_eq!(alice_only.len(), 2);
+    assert_eq!(alice_only.get(0).unwrap().player, carol);
+    assert_eq!(alice_only.get(1).unwrap().player, alice);
+
+    let later = client.get_leaderboard_filtered(
+        &LeaderboardFilter {
+            character: None,
+            tier: None,
+            from_timestamp: Some(start + 1_000),
+            to_timestamp: Some(start + 1_000),
+        },
+        &0,
+        &10,
+    );
+    assert_eq!(later.len(), 1);
+    assert_eq!(later.get(0).unwrap().player, robert);
+
+    let second_page = client.get_leaderboard_filtered(
+        &LeaderboardFilter {
+            character: Some(0),
+            tier: None,
+            from_timestamp: None,
+            to_timestamp: None,
+        },
+        &1,
+        &10,
+    );
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().player, alice);
+}
+
+// ============================================================================
+// Storage Migration Tests
+// ============================================================================
+
+/// Fill the leaderboard with 100 runs scoring `base`, `base + 1`, ...
+fn fill_leaderboard(env: &Env, client: &SaBirthContractClient, base: u64) {
+    env.as_contract(&client.address, || {
+        let mut full = Vec::new(env);
+        for index in 0..100u64 {
+            full.push_back(LeaderboardEntry {
+                player: Address::generate(env),
+                character: 0,
+                total_score: base + index,
+                timestamp: 0,
+                tier: 0,
+                session_id: 0,
+                retries: 0,
+                retry_penalty: 0,
+            });
+        }
+        env.storage().instance().set(&DataKey::Leaderboard, &full);
+    });
+}
+
+#[test]
+fn test_leaderboard_is_capped() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let player3 = Address::generate(&env);
+
+    // Fill the board with runs just faster than a 600_000 finish
+    fill_leaderboard(&env, &client, 500_000);
+
+    finish_run(&env, &client, 130, &player1, &house, 0, 10);
+    assert_eq!(client.get_leaderboard().len(), 100);
+    assert_eq!(client.get_rank(&player1), None);
+
+    // A faster run displaces the slowest entry
+    finish_run(&env, &client, 131, &player3, &house, 0, 1);
+    let leaderboard = client.get_leaderboard();
+    assert_eq!(leaderboard.len(), 100);
+    assert_eq!(client.get_rank(&player3), Some(1));
+    assert_eq!(leaderboard.get(99).unwrap().total_score, 500_098);
 }
 
 #[test]
-fn test_multiple_sessions() {
+fn test_migrate_rewrites_legacy_leaderboard() {
     let (env, client, _hub, player1, player2) = setup_test();
     let player3 = Address::generate(&env);
-    let player4 = Address::generate(&env);
+    assert_eq!(client.get_schema_version(), 1);
+
+    // Pre-version-1 layout: unsorted, four fields per entry
+    env.as_contract(&client.address, || {
+        let legacy = soroban_sdk::vec![
+            &env,
+            LegacyLeaderboardEntry {
+                player: player1.clone(),
+                character: 0,
+                total_score: 300,
+                timestamp: 1,
+            },
+            LegacyLeaderboardEntry {
+                player: player2.clone(),
+                character: 1,
+                total_score: 100,
+                timestamp: 2,
+            },
+            LegacyLeaderboardEntry {
+                player: player3.clone(),
+                character: 2,
+                total_score: 200,
+                timestamp: 3,
+            },
+        ];
+        env.storage().instance().set(&DataKey::Leaderboard, &legacy);
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+
+    // Readable (sorted) before the migration runs
+    assert_eq!(client.get_rank(&player2), Some(1));
+
+    assert_eq!(client.migrate(), 0);
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.migrate(), 1);
+
+    let leaderboard = client.get_leaderboard();
+    assert_eq!(leaderboard.len(), 3);
+    assert_eq!(leaderboard.get(0).unwrap().player, player2);
+    assert_eq!(leaderboard.get(1).unwrap().player, player3);
+    assert_eq!(leaderboard.get(2).unwrap().player, player1);
+    assert_eq!(leaderboard.get(0).unwrap().tier, 0);
+    assert_eq!(leaderboard.get(0).unwrap().timestamp, 2);
+}
+
+#[test]
+#[should_panic]
+fn test_migrate_requires_admin() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    env.set_auths(&[]);
 
-    let session1 = 3u32;
-    let session2 = 4u32;
+    client.migrate();
+}
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+#[test]
+fn test_legacy_session_and_results_are_converted() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Verify both games exist and are independent
-    let game1 = client.get_game(&session1);
-    let game2 = client.get_game(&session2);
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        storage.set(
+            &DataKey::Session(player1.clone()),
+            &LegacyCalibrationSession {
+                player: player1.clone(),
+                player2: player2.clone(),
+                character: 1,
+                completed_senses: 0b1,
+                total_score: 500,
+                session_id: 9,
+                player1_points: 10,
+                player2_points: 10,
+                active: true,
+            },
+        );
+        storage.set(
+            &DataKey::SenseResult(player1.clone(), 0),
+            &LegacySenseResult {
+                sense_id: 0,
+                points: 5,
+                time_ms: 100,
+                score: 500,
+            },
+        );
+    });
 
-    assert_eq!(game1.player1, player1);
-    assert_eq!(game2.player1, player3);
+    let session = client.get_session(&player1).unwrap();
+    assert_eq!(session.session_id, 9);
+    assert_eq!(session.completed_senses, 0b1);
+    assert_eq!(session.proven_senses, 0);
+    assert_eq!(session.tier, 0);
+    assert_eq!(session.session_key, None);
+    assert_eq!(session.score_cap, 20_000_000);
+
+    let result = client.get_sense_result(&player1, &0).unwrap();
+    assert_eq!(result.score, 500);
+    assert_eq!(result.proof_digest, BytesN::from_array(&env, &[0u8; 32]));
+
+    // The legacy run is still playable and can be exited
+    let proof = dummy_proof(&env);
+    submit_sense(&client, &player1, 1, 1, 5, 100, &proof);
+    assert_eq!(client.get_session(&player1).unwrap().completed_senses, 0b11);
+    let (won, _) = client.attempt_exit(&player1);
+    assert!(!won);
+    assert!(!client.get_session(&player1).unwrap().active);
 }
 
 // ============================================================================
-// Guess Logic Tests
+// Statistics Tests
 // ============================================================================
 
 #[test]
-fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_stats_track_runs_and_senses() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let player3 = Address::generate(&env);
+    let player4 = Address::generate(&env);
 
-    let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    // Embodied ROBERT run: every sense 20 × 10_000
+    finish_run(&env, &client, 130, &player1, &house, 1, 20);
+
+    // ROBERT run that exits after one sense (40 × 5_000)
+    start_run(&client, 131, &player3, &house, 1);
+    submit_sense(&client, &player3, 1, 0, 40, 5_000, &dummy_proof(&env));
+    client.attempt_exit(&player3);
+
+    // Overloaded CAROL run
+    start_run(&client, 132, &player4, &house, 2);
+    submit_all_senses(&env, &client, &player4, 2, 100, 40_000);
+    client.attempt_exit(&player4);
+
+    // Still-open run only counts as started
+    start_run(&client, 133, &player1, &house, 0);
+
+    let stats = client.get_stats();
+    assert_eq!(stats.run.sessions_started, 4);
+    assert_eq!(stats.run.exits, 1);
+    assert_eq!(stats.run.incompletes, 1);
+    assert_eq!(stats.run.overloads, 1);
+    assert_eq!(stats.senses.len(), 18);
+
+    // ROBERT hearing: two completions
+    let robert_hearing = stats.senses.get(6).unwrap();
+    assert_eq!((robert_hearing.character, robert_hearing.sense_id), (1, 0));
+    assert_eq!(robert_hearing.attempts, 2);
+    assert_eq!(robert_hearing.completions, 2);
+    assert_eq!(robert_hearing.min_score, 200_000);
+    assert_eq!(robert_hearing.max_score, 200_000);
+    assert_eq!(robert_hearing.mean_score, 200_000);
+    assert_eq!(robert_hearing.mean_time_ms, 7_500);
+
+    // ROBERT smell: attempted by both runs, completed once
+    let robert_smell = stats.senses.get(7).unwrap();
+    assert_eq!(robert_smell.attempts, 2);
+    assert_eq!(robert_smell.completions, 1);
+    assert_eq!(robert_smell.mean_score, 200_000);
+
+    let carol_sight = stats.senses.get(16).unwrap();
+    assert_eq!((carol_sight.character, carol_sight.sense_id), (2, 4));
+    assert_eq!(carol_sight.completions, 1);
+    assert_eq!(carol_sight.min_score, 4_000_000);
+
+    // Untouched maze
+    let alice_taste = stats.senses.get(2).unwrap();
+    assert_eq!(alice_taste.attempts, 0);
+    assert_eq!(alice_taste.mean_score, 0);
+}
 
-    // Player1 guesses closer (1 away from any number between 1-10)
-    // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+#[test]
+fn test_stats_count_orphaned_session_as_incomplete() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    start_run(&client, 134, &player1, &house, 0);
+    submit_sense(&client, &player1, 0, 3, 10, 1_000, &dummy_proof(&env));
+
+    // Starting again abandons the open session
+    start_run(&client, 135, &player1, &house, 0);
+
+    let stats = client.get_stats();
+    assert_eq!(stats.run.sessions_started, 2);
+    assert_eq!(stats.run.incompletes, 1);
+    assert_eq!(stats.senses.get(3).unwrap().attempts, 1);
+    assert_eq!(stats.senses.get(3).unwrap().completions, 1);
+    assert_eq!(stats.senses.get(0).unwrap().attempts, 1);
+    assert_eq!(stats.senses.get(0).unwrap().completions, 0);
+}
 
-    let winner = client.reveal_winner(&session_id);
+// ============================================================================
+// Error Handling Tests
+// ============================================================================
 
-    // Get the final game state to check the winning number
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
+#[test]
+fn test_cannot_submit_sense_twice() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    start_run(&client, 10, &player1, &player2, 0);
+    submit_sense(&client, &player1, 0, 3, 10, 1_000, &dummy_proof(&env));
 
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(
-        winner, expected_winner,
-        "Player with closer guess should win"
+    let result = client.try_submit_sense_completion(
+        &player1,
+        &3,
+        &3,
+        &client.get_maze_root(&player1, &3).unwrap(),
+        &10,
+        &1_000,
+        &0,
+        &10_000,
+        &dummy_proof(&env),
     );
+    assert_sa_birth_error(&result, Error::AlreadyGuessed);
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_rejects_wrong_maze_id() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 11, &player1, &player2, 1);
+
+    // maze_id for character 0 submitted by a ROBERT session
+    let result = client.try_submit_sense_completion(
+        &player1,
+        &2,
+        &2,
+        &client.get_maze_root(&player1, &2).unwrap(),
+        &10,
+        &1_000,
+        &0,
+        &10_000,
+        &dummy_proof(&env),
+    );
+    assert_sa_birth_error(&result, Error::VerificationFailed);
+}
+
+#[test]
+fn test_rejects_score_mismatch() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 12, &player1, &player2, 0);
+
+    let result = client.try_submit_sense_completion(
+        &player1,
+        &0,
+        &0,
+        &client.get_maze_root(&player1, &0).unwrap(),
+        &10,
+        &1_000,
+        &0,
+        &9_999,
+        &dummy_proof(&env),
+    );
+    assert_sa_birth_error(&result, Error::VerificationFailed);
+}
 
-    let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+#[test]
+fn test_rejects_invalid_sense_and_character() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&13, &player1, &player2, &100_0000000, &100_0000000);
+    assert_sa_birth_error(&client.try_set_character(&player1, &3), Error::InvalidCharacter);
+
+    client.set_character(&player1, &0);
+    let result = client.try_submit_sense_completion(
+        &player1,
+        &6,
+        &6,
+        &client.get_maze_root(&player1, &0).unwrap(),
+        &10,
+        &1_000,
+        &0,
+        &10_000,
+        &dummy_proof(&env),
+    );
+    assert_sa_birth_error(&result, Error::InvalidSense);
+}
+
+#[test]
+fn test_character_locked_after_first_sense() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 15, &player1, &player2, 0);
+    submit_all_senses(&env, &client, &player1, 0, 10, 20_000);
+
+    // Switching to ROBERT after clearing ALICE's mazes is rejected
+    assert_sa_birth_error(&client.try_set_character(&player1, &1), Error::SessionAlreadyActive);
 
-    // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    let (success, _) = client.attempt_exit(&player1);
+    assert!(success);
+    assert!(client.has_credential(&player1, &0));
+    assert!(!client.has_credential(&player1, &1));
 
-    let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+    let leaderboard = client.get_leaderboard();
+    assert_eq!(leaderboard.len(), 1);
+    assert_eq!(leaderboard.get(0).unwrap().character, 0);
+
+    // A reported failure locks the character too
+    start_run(&client, 16, &player1, &player2, 0);
+    client.fail_sense(&player1, &2);
+    assert_sa_birth_error(&client.try_set_character(&player1, &1), Error::SessionAlreadyActive);
 }
 
 #[test]
-fn test_exact_guess_wins() {
+fn test_cannot_exit_twice() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_run(&client, 14, &player1, &player2, 0);
+    client.attempt_exit(&player1);
 
-    // Player1 guesses 5 (middle), player2 guesses 10 (edge)
-    // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    assert_sa_birth_error(&client.try_attempt_exit(&player1), Error::SessionNotActive);
+}
 
-    let winner = client.reveal_winner(&session_id);
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
+#[test]
+fn test_exit_without_session() {
+    let (_env, client, _hub, player1, _player2) = setup_test();
 
-    // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(winner, expected_winner);
+    assert_sa_birth_error(&client.try_attempt_exit(&player1), Error::GameNotFound);
 }
 
 // ============================================================================
-// Error Handling Tests
+// Sense Ordering Tests
 // ============================================================================
 
 #[test]
-fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_senses_unordered_by_default() {
+    let (env, client, _hub, player1, house) = setup_test();
 
-    let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_run(&client, 90, &player1, &house, 0);
+    assert_eq!(client.get_available_senses(&player1), 0b11_1111);
+    submit_sense(&client, &player1, 0, 5, 10, 1_000, &dummy_proof(&env));
+    assert_eq!(client.get_available_senses(&player1), 0b01_1111);
+}
 
-    // Make first guess
-    client.make_guess(&session_id, &player1, &5);
+#[test]
+fn test_prerequisites_lock_senses() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    // proprioception (5) after touch (3) and sight (4)
+    client.set_sense_prerequisites(&5, &0b01_1000);
+    assert_eq!(client.get_sense_prerequisites(&5), 0b01_1000);
+
+    start_run(&client, 91, &player1, &house, 0);
+    assert_eq!(client.get_available_senses(&player1), 0b01_1111);
+
+    let maze_root = client.get_maze_root(&player1, &5).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_sense_completion(
+            &player1, &5, &5, &maze_root, &10, &1_000, &0, &10_000, &dummy_proof(&env),
+        ),
+        Error::SenseLocked,
+    );
 
-    // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
-    assert_number_guess_error(&result, Error::AlreadyGuessed);
+    submit_sense(&client, &player1, 0, 3, 10, 1_000, &dummy_proof(&env));
+    assert_eq!(client.get_available_senses(&player1), 0b01_0111);
+    submit_sense(&client, &player1, 0, 4, 10, 1_000, &dummy_proof(&env));
+    assert_eq!(client.get_available_senses(&player1), 0b10_0111);
+    submit_sense(&client, &player1, 0, 5, 10, 1_000, &dummy_proof(&env));
+
+    // Practice follows the same graph
+    client.start_practice(&player1, &0);
+    let practice_root = client.get_practice_maze_root(&player1, &5).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_practice_sense(
+            &player1, &5, &5, &practice_root, &10, &1_000, &0, &10_000, &Bytes::new(&env),
+        ),
+        Error::SenseLocked,
+    );
 }
 
 #[test]
-fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_prerequisite_graph_must_stay_acyclic() {
+    let (_env, client, _hub, _player1, _house) = setup_test();
+
+    client.set_sense_prerequisites(&1, &0b00_0001);
+    assert_sa_birth_error(
+        &client.try_set_sense_prerequisites(&0, &0b00_0010),
+        Error::PrerequisiteCycle,
+    );
+    assert_sa_birth_error(
+        &client.try_set_sense_prerequisites(&2, &0b00_0100),
+        Error::PrerequisiteCycle,
+    );
+    assert_eq!(client.get_sense_prerequisites(&0), 0);
+    assert_sa_birth_error(
+        &client.try_set_sense_prerequisites(&6, &0),
+        Error::InvalidSense,
+    );
+    assert_sa_birth_error(
+        &client.try_set_sense_prerequisites(&0, &0b100_0000),
+        Error::InvalidSense,
+    );
+}
+
+// ============================================================================
+// Retry Tests
+// ============================================================================
+
+#[test]
+fn test_senses_one_shot_by_default() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    start_run(&client, 95, &player1, &house, 0);
+    assert!(!client.fail_sense(&player1, &2));
+
+    let maze_root = client.get_maze_root(&player1, &2).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_sense_completion(
+            &player1, &2, &2, &maze_root, &10, &1_000, &0, &10_000, &dummy_proof(&env),
+        ),
+        Error::RetriesExhausted,
+    );
+    assert_sa_birth_error(&client.try_fail_sense(&player1, &2), Error::RetriesExhausted);
+
+    // The failed sense was counted once, not again at exit
+    client.attempt_exit(&player1);
+    assert_eq!(client.get_stats().senses.get(2).unwrap().attempts, 1);
+}
+
+#[test]
+fn test_retries_add_penalty_and_reach_leaderboard() {
+    let (env, client, _hub, player1, house) = setup_test();
+    client.set_retry_policy(&RetryPolicy {
+        max_retries: 2,
+        penalty: 50_000,
+    });
 
-    let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_run(&client, 96, &player1, &house, 0);
+    assert!(client.fail_sense(&player1, &0));
+    assert!(client.fail_sense(&player1, &0));
+    assert!(client.fail_sense(&player1, &4));
 
-    // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
+    let session = client.get_session(&player1).unwrap();
+    assert_eq!(session.total_score, 150_000);
+    assert_eq!(session.retry_penalty, 150_000);
+    assert_eq!(session.sense_failures.get(0), Some(2));
 
-    // Try to reveal winner - should fail
-    let result = client.try_reveal_winner(&session_id);
-    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+    submit_all_senses(&env, &client, &player1, 0, 10, 10_000);
+    assert_eq!(client.attempt_exit(&player1), (true, 750_000));
+
+    let entry = client.get_leaderboard().get(0).unwrap();
+    assert_eq!(entry.retries, 3);
+    assert_eq!(entry.retry_penalty, 150_000);
+    assert_eq!(client.get_stats().senses.get(0).unwrap().attempts, 3);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_aggregated_proof_accounts_for_retry_penalty() {
+    let (env, client, _hub, player1, house) = setup_test();
+    client.set_retry_policy(&RetryPolicy {
+        max_retries: 1,
+        penalty: 7,
+    });
 
-    let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
+    start_run(&client, 97, &player1, &house, 0);
+    client.fail_sense(&player1, &1);
+    for sense_id in 0..6 {
+        submit_sense(&client, &player1, 0, sense_id, 10, 10_000, &Bytes::new(&env));
+    }
+
+    let inputs = run_inputs(&env, [100_000; 6], 20_000_000, 0);
+    assert_eq!(
+        client.attempt_exit_with_proof(&player1, &inputs, &dummy_proof(&env)),
+        (true, 600_007)
     );
+}
+
+#[test]
+fn test_cannot_fail_completed_sense() {
+    let (env, client, _hub, player1, house) = setup_test();
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    start_run(&client, 98, &player1, &house, 0);
+    submit_sense(&client, &player1, 0, 0, 10, 1_000, &dummy_proof(&env));
+    assert_sa_birth_error(&client.try_fail_sense(&player1, &0), Error::AlreadyGuessed);
+    assert_sa_birth_error(&client.try_fail_sense(&player1, &6), Error::InvalidSense);
 }
 
+// ============================================================================
+// Maze Commitment Tests
+// ============================================================================
+
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_session_derived_maze_root() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 11u32;
-    client.start_game(
-        &session_id,
+    start_run(&client, 30, &player1, &player2, 1);
+
+    // keccak256(session_id ‖ character ‖ sense_id), big-endian u32s
+    let mut seed = [0u8; 12];
+    seed[0..4].copy_from_slice(&30u32.to_be_bytes());
+    seed[4..8].copy_from_slice(&1u32.to_be_bytes());
+    seed[8..12].copy_from_slice(&4u32.to_be_bytes());
+    let expected: BytesN<32> = env.crypto().keccak256(&Bytes::from_array(&env, &seed)).into();
+
+    assert_eq!(client.get_maze_root(&player1, &4), Some(expected.clone()));
+    assert_ne!(client.get_maze_root(&player1, &5), Some(expected));
+    assert_eq!(client.get_maze_root(&player1, &6), None);
+}
+
+#[test]
+fn test_rejects_wrong_maze_root() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 31, &player1, &player2, 0);
+
+    let result = client.try_submit_sense_completion(
         &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
+        &0,
+        &0,
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &10,
+        &1_000,
+        &0,
+        &10_000,
+        &dummy_proof(&env),
     );
+    assert_sa_birth_error(&result, Error::MazeCommitmentMismatch);
+}
+
+#[test]
+fn test_admin_maze_root_overrides_session_root() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let root = BytesN::from_array(&env, &[9u8; 32]);
+    client.set_maze_root(&2, &1, &root);
+
+    start_run(&client, 32, &player1, &player2, 2);
+    assert_eq!(client.get_maze_root(&player1, &1), Some(root.clone()));
+
+    submit_sense(&client, &player1, 2, 1, 10, 1_000, &dummy_proof(&env));
+    let stored = client.get_sense_result(&player1, &1).unwrap();
+    assert_eq!(stored.maze_root, root);
+
+    // Clearing it falls back to the session-derived root
+    client.clear_maze_root(&2, &1);
+    assert_ne!(client.get_maze_root(&player1, &1), Some(root));
+}
+
+#[test]
+fn test_set_maze_root_validates_indices() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    assert_sa_birth_error(&client.try_set_maze_root(&3, &0, &root), Error::InvalidCharacter);
+    assert_sa_birth_error(&client.try_set_maze_root(&0, &6, &root), Error::InvalidSense);
+}
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+// ============================================================================
+// Relayed Submission Tests
+// ============================================================================
+
+fn session_signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+/// Build a payload for `sense_id` at `nonce`, committing to `proof`.
+fn signed_payload(
+    env: &Env,
+    client: &SaBirthContractClient,
+    player: &Address,
+    character: u32,
+    sense_id: u32,
+    nonce: u64,
+    proof: &Bytes,
+) -> SignedSensePayload {
+    let session = client.get_session(player).unwrap();
+    SignedSensePayload {
+        contract: client.address.clone(),
+        player: player.clone(),
+        session_id: session.session_id,
+        sense_id,
+        maze_id: (character << 8) | sense_id,
+        maze_root: client.get_maze_root(player, &sense_id).unwrap(),
+        points: 10,
+        time_ms: 1_000,
+        hazards: 0,
+        score: 10_000,
+        proof_digest: env.crypto().keccak256(proof).into(),
+        nonce,
+    }
+}
+
+fn sign_payload(env: &Env, key: &SigningKey, payload: &SignedSensePayload) -> BytesN<64> {
+    let message = payload.clone().to_xdr(env);
+    let mut buf = [0u8; 1024];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes())
+}
+
+fn register_key(env: &Env, client: &SaBirthContractClient, player: &Address, key: &SigningKey) {
+    let public_key = BytesN::from_array(env, &key.verifying_key().to_bytes());
+    client.register_session_key(player, &public_key);
 }
 
 #[test]
-fn test_non_player_cannot_guess() {
+fn test_relayed_submission_records_sense() {
     let (env, client, _hub, player1, player2) = setup_test();
-    let non_player = Address::generate(&env);
 
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_run(&client, 40, &player1, &player2, 0);
+    let key = session_signing_key(1);
+    register_key(&env, &client, &player1, &key);
+
+    let proof = dummy_proof(&env);
+    let payload = signed_payload(&env, &client, &player1, 0, 2, 0, &proof);
+    let signature = sign_payload(&env, &key, &payload);
+
+    // Only the payload signature is checked — no player auth is mocked here
+    env.set_auths(&[]);
+    client.submit_sense_signed(&player1, &payload, &signature, &proof);
 
-    // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
-    assert_number_guess_error(&result, Error::NotPlayer);
+    let session = client.get_session(&player1).unwrap();
+    assert_eq!(session.completed_senses, 1 << 2);
+    assert_eq!(session.proven_senses, 1 << 2);
+    assert_eq!(session.next_nonce, 1);
+    assert_eq!(client.get_sense_result(&player1, &2).unwrap().score, 10_000);
 }
 
 #[test]
-fn test_cannot_reveal_nonexistent_game() {
-    let (_env, client, _hub, _player1, _player2) = setup_test();
+fn test_relayed_submission_rejects_replay() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 41, &player1, &player2, 1);
+    let key = session_signing_key(2);
+    register_key(&env, &client, &player1, &key);
+
+    let proof = dummy_proof(&env);
+    let payload = signed_payload(&env, &client, &player1, 1, 0, 0, &proof);
+    let signature = sign_payload(&env, &key, &payload);
+    client.submit_sense_signed(&player1, &payload, &signature, &proof);
+
+    // Same signed payload again
+    assert_sa_birth_error(
+        &client.try_submit_sense_signed(&player1, &payload, &signature, &proof),
+        Error::InvalidNonce,
+    );
 
-    let result = client.try_reveal_winner(&999);
-    assert_number_guess_error(&result, Error::GameNotFound);
+    // A fresh nonce is accepted for the next sense
+    let next = signed_payload(&env, &client, &player1, 1, 1, 1, &proof);
+    let next_sig = sign_payload(&env, &key, &next);
+    client.submit_sense_signed(&player1, &next, &next_sig, &proof);
+    assert_eq!(client.get_session(&player1).unwrap().next_nonce, 2);
 }
 
 #[test]
-fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_relayed_submission_requires_registered_key() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 42, &player1, &player2, 0);
+    let key = session_signing_key(3);
+    let proof = dummy_proof(&env);
+    let payload = signed_payload(&env, &client, &player1, 0, 0, 0, &proof);
+    let signature = sign_payload(&env, &key, &payload);
 
-    let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_sa_birth_error(
+        &client.try_submit_sense_signed(&player1, &payload, &signature, &proof),
+        Error::SessionKeyNotSet,
+    );
+}
+
+#[test]
+fn test_relayed_submission_rejects_foreign_signature() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    start_run(&client, 43, &player1, &player2, 0);
+    register_key(&env, &client, &player1, &session_signing_key(4));
 
-    // Reveal winner - game ends
-    let _winner = client.reveal_winner(&session_id);
+    let proof = dummy_proof(&env);
+    let payload = signed_payload(&env, &client, &player1, 0, 0, 0, &proof);
+    let signature = sign_payload(&env, &session_signing_key(5), &payload);
 
-    // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
-    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    assert!(client
+        .try_submit_sense_signed(&player1, &payload, &signature, &proof)
+        .is_err());
+    assert_eq!(client.get_session(&player1).unwrap().completed_senses, 0);
 }
 
 #[test]
-fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_relayed_submission_binds_proof_and_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 44, &player1, &player2, 0);
+    let key = session_signing_key(6);
+    register_key(&env, &client, &player1, &key);
 
-    let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let proof = dummy_proof(&env);
+    let payload = signed_payload(&env, &client, &player1, 0, 0, 0, &proof);
+    let signature = sign_payload(&env, &key, &payload);
+
+    // Relayer swaps the proof bytes
+    let other_proof = Bytes::from_array(&env, &[0xcd; 16]);
+    assert_sa_birth_error(
+        &client.try_submit_sense_signed(&player1, &payload, &signature, &other_proof),
+        Error::VerificationFailed,
+    );
+
+    // Payload signed for a different session id
+    let mut stale = payload.clone();
+    stale.session_id = 7;
+    let stale_sig = sign_payload(&env, &key, &stale);
+    assert_sa_birth_error(
+        &client.try_submit_sense_signed(&player1, &stale, &stale_sig, &proof),
+        Error::VerificationFailed,
+    );
+}
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+#[test]
+fn test_relayed_submission_binds_contract_and_player() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    start_run(&client, 45, &player1, &player2, 0);
+    let key = session_signing_key(7);
+    register_key(&env, &client, &player1, &key);
+
+    // A second deployment with a session of the same id and the same key
+    let other_id = env.register(SaBirthContract, (&client.get_admin(), &hub.address));
+    let other = SaBirthContractClient::new(&env, &other_id);
+    start_run(&other, 45, &player1, &player2, 0);
+    register_key(&env, &other, &player1, &key);
+
+    let proof = dummy_proof(&env);
+    let payload = signed_payload(&env, &client, &player1, 0, 0, 0, &proof);
+    let signature = sign_payload(&env, &key, &payload);
+    assert_sa_birth_error(
+        &other.try_submit_sense_signed(&player1, &payload, &signature, &proof),
+        Error::VerificationFailed,
+    );
 
-    // First reveal succeeds
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    // Payload signed for another player
+    let mut foreign = payload.clone();
+    foreign.player = player2.clone();
+    let foreign_sig = sign_payload(&env, &key, &foreign);
+    assert_sa_birth_error(
+        &client.try_submit_sense_signed(&player1, &foreign, &foreign_sig, &proof),
+        Error::VerificationFailed,
+    );
 
-    // Second reveal should return same winner (idempotent)
-    let winner2 = client.reveal_winner(&session_id);
-    assert_eq!(winner, winner2);
+    client.submit_sense_signed(&player1, &payload, &signature, &proof);
+    assert_eq!(client.get_session(&player1).unwrap().completed_senses, 1);
 }
 
 // ============================================================================
-// Multiple Games Tests
+// Aggregated Run Proof Tests
 // ============================================================================
 
+fn run_inputs(env: &Env, scores: [u64; 6], score_cap: u64, character: u32) -> RunPublicInputs {
+    let mut sense_scores = Vec::new(env);
+    let mut total_score = 0;
+    for score in scores {
+        sense_scores.push_back(score);
+        total_score += score;
+    }
+    RunPublicInputs {
+        sense_scores,
+        total_score,
+        score_cap,
+        character,
+    }
+}
+
 #[test]
-fn test_multiple_games_independent() {
+fn test_unproven_winning_run_requires_aggregated_proof() {
     let (env, client, _hub, player1, player2) = setup_test();
-    let player3 = Address::generate(&env);
-    let player4 = Address::generate(&env);
 
-    let session1 = 20u32;
-    let session2 = 21u32;
+    start_run(&client, 20, &player1, &player2, 0);
+    let empty = Bytes::new(&env);
+    for sense_id in 0..6 {
+        submit_sense(&client, &player1, 0, sense_id, 20, 10_000, &empty);
+    }
 
-    // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    assert_sa_birth_error(&client.try_attempt_exit(&player1), Error::ProofRequired);
+    assert!(client.get_session(&player1).unwrap().active);
+}
 
-    // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
+#[test]
+fn test_unproven_losing_run_exits_without_proof() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Reveal both winners
-    let winner1 = client.reveal_winner(&session1);
-    let winner2 = client.reveal_winner(&session2);
+    start_run(&client, 21, &player1, &player2, 0);
+    let empty = Bytes::new(&env);
+    submit_sense(&client, &player1, 0, 0, 20, 10_000, &empty);
 
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
+    let (success, _) = client.attempt_exit(&player1);
+    assert!(!success);
+}
 
-    // Verify both games are independent
-    let final_game1 = client.get_game(&session1);
-    let final_game2 = client.get_game(&session2);
+#[test]
+fn test_aggregated_proof_accepted() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    assert!(final_game1.winner.is_some()); // Game 1 has ended
-    assert!(final_game2.winner.is_some()); // Game 2 has ended
+    start_run(&client, 22, &player1, &player2, 1);
+    let empty = Bytes::new(&env);
+    for sense_id in 0..6 {
+        submit_sense(&client, &player1, 1, sense_id, 10 + sense_id as u64, 10_000, &empty);
+    }
 
-    // Note: winning numbers could be the same by chance, so we just verify they're both set
-    assert!(final_game1.winning_number.is_some());
-    assert!(final_game2.winning_number.is_some());
+    let inputs = run_inputs(
+        &env,
+        [100_000, 110_000, 120_000, 130_000, 140_000, 150_000],
+        20_000_000,
+        1,
+    );
+    let proof = dummy_proof(&env);
+    let (success, total) = client.attempt_exit_with_proof(&player1, &inputs, &proof);
+    assert!(success);
+    assert_eq!(total, 750_000);
+
+    let record = client.get_run_proof(&player1).unwrap();
+    assert_eq!(record.session_id, 22);
+    assert_eq!(record.inputs, inputs);
+    let digest: BytesN<32> = env.crypto().keccak256(&proof).into();
+    assert_eq!(record.proof_digest, digest);
+    assert_eq!(client.get_leaderboard().len(), 1);
 }
 
 #[test]
-fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_aggregated_proof_rejects_mismatched_inputs() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 23, &player1, &player2, 0);
+    let empty = Bytes::new(&env);
+    for sense_id in 0..6 {
+        submit_sense(&client, &player1, 0, sense_id, 10, 10_000, &empty);
+    }
+    let proof = dummy_proof(&env);
+
+    // One sense score differs from what was recorded
+    let wrong_score = run_inputs(&env, [100_000, 100_000, 100_000, 100_000, 100_000, 90_000], 20_000_000, 0);
+    assert_sa_birth_error(
+        &client.try_attempt_exit_with_proof(&player1, &wrong_score, &proof),
+        Error::VerificationFailed,
+    );
+
+    // Wrong character
+    let wrong_character = run_inputs(&env, [100_000; 6], 20_000_000, 2);
+    assert_sa_birth_error(
+        &client.try_attempt_exit_with_proof(&player1, &wrong_character, &proof),
+        Error::VerificationFailed,
+    );
+
+    // Wrong cap
+    let wrong_cap = run_inputs(&env, [100_000; 6], 30_000_000, 0);
+    assert_sa_birth_error(
+        &client.try_attempt_exit_with_proof(&player1, &wrong_cap, &proof),
+        Error::VerificationFailed,
+    );
 
-    let session_id = 15u32;
-    let points1 = 200_0000000;
-    let points2 = 50_0000000;
+    // Total that does not match the sum of the per-sense scores
+    let mut bad_total = run_inputs(&env, [100_000; 6], 20_000_000, 0);
+    bad_total.total_score = 500_000;
+    assert_sa_birth_error(
+        &client.try_attempt_exit_with_proof(&player1, &bad_total, &proof),
+        Error::VerificationFailed,
+    );
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    // Missing proof bytes
+    let good = run_inputs(&env, [100_000; 6], 20_000_000, 0);
+    assert_sa_birth_error(
+        &client.try_attempt_exit_with_proof(&player1, &good, &empty),
+        Error::VerificationFailed,
+    );
 
-    let game = client.get_game(&session_id);
-    assert_eq!(game.player1_points, points1);
-    assert_eq!(game.player2_points, points2);
+    let (success, _) = client.attempt_exit_with_proof(&player1, &good, &proof);
+    assert!(success);
+}
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
-    client.reveal_winner(&session_id);
+#[test]
+fn test_aggregated_proof_requires_all_senses() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 24, &player1, &player2, 0);
+    submit_sense(&client, &player1, 0, 0, 10, 10_000, &Bytes::new(&env));
+
+    let inputs = run_inputs(&env, [100_000, 0, 0, 0, 0, 0], 20_000_000, 0);
+    assert_sa_birth_error(
+        &client.try_attempt_exit_with_proof(&player1, &inputs, &dummy_proof(&env)),
+        Error::BothPlayersNotGuessed,
+    );
+}
+
+// ============================================================================
+// Practice Mode Tests
+// ============================================================================
+
+fn submit_practice(
+    client: &SaBirthContractClient,
+    player: &Address,
+    character: u32,
+    sense_id: u32,
+    points: u64,
+    time_ms: u64,
+) {
+    let maze_root = client.get_practice_maze_root(player, &sense_id).unwrap();
+    client.submit_practice_sense(
+        player,
+        &sense_id,
+        &((character << 8) | sense_id),
+        &maze_root,
+        &points,
+        &time_ms,
+        &0,
+        &(points * time_ms),
+        &Bytes::new(&client.env),
+    );
+}
+
+#[test]
+fn test_practice_run_projects_rank_without_leaderboard() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    finish_run(&env, &client, 1, &player3, &player2, 0, 20);
+
+    client.start_practice(&player1, &1);
+    for sense_id in 0..6 {
+        submit_practice(&client, &player1, 1, sense_id, 10, 10_000);
+    }
+
+    let result = client.get_practice_result(&player1, &0).unwrap();
+    assert_eq!(result.score, 100_000);
+    assert!(client.get_sense_result(&player1, &0).is_none());
+
+    let outcome = client.exit_practice(&player1);
+    assert!(outcome.success);
+    assert_eq!(outcome.total_score, 600_000);
+    assert_eq!(outcome.projected_rank, Some(1));
+
+    assert!(!client.get_practice_session(&player1).unwrap().active);
+    assert_eq!(client.get_leaderboard().len(), 1);
+    assert!(client.get_session(&player1).is_none());
+
+    let stats = client.get_stats();
+    assert_eq!(stats.run.sessions_started, 1);
+    assert_eq!(stats.senses.get(6).unwrap().attempts, 0);
+}
+
+#[test]
+fn test_practice_failure_has_no_rank() {
+    let (_env, client, _hub, player1, _player2) = setup_test();
+
+    client.start_practice(&player1, &0);
+    submit_practice(&client, &player1, 0, 0, 10, 1_000);
+
+    let outcome = client.exit_practice(&player1);
+    assert!(!outcome.success);
+    assert_eq!(outcome.total_score, 10_000);
+    assert_eq!(outcome.projected_rank, None);
+
+    assert_sa_birth_error(&client.try_exit_practice(&player1), Error::SessionNotActive);
+}
+
+#[test]
+fn test_practice_on_full_board_projects_no_rank() {
+    let (env, client, _hub, player1, _player2) = setup_test();
+    fill_leaderboard(&env, &client, 500_000);
+
+    // 600_000 is slower than every kept run
+    client.start_practice(&player1, &0);
+    for sense_id in 0..6 {
+        submit_practice(&client, &player1, 0, sense_id, 10, 10_000);
+    }
+    let outcome = client.exit_practice(&player1);
+    assert!(outcome.success);
+    assert_eq!(outcome.projected_rank, None);
+
+    // 500_050 ranks behind the 51 runs scoring up to it
+    client.start_practice(&player1, &0);
+    for sense_id in 0..5 {
+        submit_practice(&client, &player1, 0, sense_id, 10, 10_000);
+    }
+    submit_practice(&client, &player1, 0, 5, 1, 50);
+    assert_eq!(client.exit_practice(&player1).projected_rank, Some(52));
+}
+
+#[test]
+fn test_practice_runs_alongside_ranked_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    start_run(&client, 7, &player1, &player2, 0);
+    client.start_practice(&player1, &2);
+
+    submit_practice(&client, &player1, 2, 0, 10, 1_000);
+    assert_eq!(client.get_session(&player1).unwrap().completed_senses, 0);
+
+    submit_sense(&client, &player1, 0, 0, 10, 1_000, &dummy_proof(&env));
+    assert_eq!(client.get_practice_session(&player1).unwrap().completed_senses, 1);
+
+    // Restarting practice discards the previous practice results
+    client.start_practice(&player1, &2);
+    assert!(client.get_practice_result(&player1, &0).is_none());
+    assert!(client.get_sense_result(&player1, &0).is_some());
+}
+
+#[test]
+fn test_practice_validates_like_ranked() {
+    let (env, client, _hub, player1, _player2) = setup_test();
+
+    assert_sa_birth_error(&client.try_start_practice(&player1, &3), Error::InvalidCharacter);
+
+    client.start_practice(&player1, &0);
+    let maze_root = client.get_practice_maze_root(&player1, &0).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_practice_sense(
+            &player1, &0, &0, &maze_root, &10, &1_000, &0, &10_001, &Bytes::new(&env),
+        ),
+        Error::VerificationFailed,
+    );
+    assert_sa_birth_error(
+        &client.try_submit_practice_sense(
+            &player1,
+            &0,
+            &0,
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &10,
+            &1_000,
+            &0,
+            &10_000,
+            &Bytes::new(&env),
+        ),
+        Error::MazeCommitmentMismatch,
+    );
+}
+
+// ============================================================================
+// Race Mode Tests
+// ============================================================================
+
+fn submit_race(
+    env: &Env,
+    client: &SaBirthContractClient,
+    player: &Address,
+    session_id: u32,
+    character: u32,
+    sense_id: u32,
+    points: u64,
+) {
+    let maze_root = client.get_race_maze_root(&session_id, &sense_id).unwrap();
+    client.submit_race_sense(
+        player,
+        &session_id,
+        &sense_id,
+        &((character << 8) | sense_id),
+        &maze_root,
+        &points,
+        &10_000,
+        &0,
+        &(points * 10_000),
+        &dummy_proof(env),
+    );
+}
+
+#[test]
+fn test_race_lower_score_wins() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_race(&40, &player1, &player2, &100, &100, &1, &1_000);
+    for sense_id in 0..6 {
+        submit_race(&env, &client, &player1, 40, 1, sense_id, 30);
+        submit_race(&env, &client, &player2, 40, 1, sense_id, 20);
+    }
+
+    assert_eq!(client.settle_race(&40), GameOutcome::Player2);
+
+    let race = client.get_race(&40).unwrap();
+    assert!(race.settled);
+    assert_eq!(race.player1.total_score, 1_800_000);
+    assert_eq!(race.player2.total_score, 1_200_000);
+    assert_sa_birth_error(&client.try_settle_race(&40), Error::GameAlreadyEnded);
+    assert_eq!(client.get_leaderboard().len(), 0);
+}
+
+#[test]
+fn test_race_forfeit_after_deadline() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_race(&41, &player1, &player2, &100, &100, &0, &100);
+    for sense_id in 0..6 {
+        submit_race(&env, &client, &player2, 41, 0, sense_id, 50);
+    }
+    submit_race(&env, &client, &player1, 41, 0, 0, 1);
+
+    // player1 has not finished, so the race waits for the deadline
+    assert_sa_birth_error(&client.try_settle_race(&41), Error::DeadlineNotReached);
+
+    advance_ledgers(&env, 101);
+    let maze_root = client.get_race_maze_root(&41, &1).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_race_sense(
+            &player1, &41, &1, &1, &maze_root, &1, &10_000, &0, &10_000, &dummy_proof(&env),
+        ),
+        Error::DeadlinePassed,
+    );
+
+    assert_eq!(client.settle_race(&41), GameOutcome::Player2);
+}
+
+#[test]
+fn test_race_counts_only_proven_senses() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_race(&43, &player1, &player2, &100, &100, &0, &100);
+    submit_race(&env, &client, &player1, 43, 0, 0, 50);
+
+    // player2 completes more senses, but none of them with a proof
+    for sense_id in 0..3 {
+        let maze_root = client.get_race_maze_root(&43, &sense_id).unwrap();
+        client.submit_race_sense(
+            &player2,
+            &43,
+            &sense_id,
+            &sense_id,
+            &maze_root,
+            &1,
+            &10_000,
+            &0,
+            &10_000,
+            &Bytes::new(&env),
+        );
+    }
+
+    advance_ledgers(&env, 101);
+    assert_eq!(client.settle_race(&43), GameOutcome::Player1);
+}
+
+#[test]
+fn test_race_ties_draw_and_empty_races_cancel() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let hub = setup_hub(&env, &client, &[&player1, &player2]);
+
+    client.start_race(&44, &player1, &player2, &100, &100, &0, &100);
+    for sense_id in 0..6 {
+        submit_race(&env, &client, &player1, 44, 0, sense_id, 20);
+        submit_race(&env, &client, &player2, 44, 0, sense_id, 20);
+    }
+    assert_eq!(client.settle_race(&44), GameOutcome::Draw);
+    assert_eq!(hub.get_session(&44).unwrap().status, HubStatus::Drawn);
+
+    // Nobody plays: both stakes are refunded rather than paid to player1
+    client.start_race(&45, &player1, &player2, &100, &100, &0, &100);
+    advance_ledgers(&env, 101);
+    assert_eq!(client.settle_race(&45), GameOutcome::Cancelled);
+    assert_eq!(hub.get_session(&45).unwrap().status, HubStatus::Cancelled);
+    assert_eq!(hub.get_locked(&player1), 0);
+}
+
+#[test]
+fn test_race_rejects_outsiders_and_bad_setup() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let outsider = Address::generate(&env);
+
+    assert_sa_birth_error(
+        &client.try_start_race(&42, &player1, &player2, &100, &100, &3, &100),
+        Error::InvalidCharacter,
+    );
+    assert_sa_birth_error(
+        &client.try_start_race(&42, &player1, &player2, &100, &100, &0, &0),
+        Error::InvalidDeadline,
+    );
+
+    client.start_race(&42, &player1, &player2, &100, &100, &0, &100);
+    assert_sa_birth_error(
+        &client.try_start_race(&42, &player1, &player2, &100, &100, &0, &100),
+        Error::SessionAlreadyActive,
+    );
+
+    let maze_root = client.get_race_maze_root(&42, &0).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_race_sense(
+            &outsider, &42, &0, &0, &maze_root, &1, &10_000, &0, &10_000, &dummy_proof(&env),
+        ),
+        Error::NotPlayer,
+    );
+
+    submit_race(&env, &client, &player1, 42, 0, 0, 1);
+    assert_sa_birth_error(
+        &client.try_submit_race_sense(
+            &player1, &42, &0, &0, &maze_root, &1, &10_000, &0, &10_000, &dummy_proof(&env),
+        ),
+        Error::AlreadyGuessed,
+    );
+}
+
+#[test]
+fn test_race_senses_checked_for_hazards() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_race(&44, &player1, &player2, &100, &100, &0, &100);
+    let maze_root = client.get_race_maze_root(&44, &0).unwrap();
+
+    // A hazard's 20 s penalty doesn't fit in a 10 s sense
+    assert_sa_birth_error(
+        &client.try_submit_race_sense(
+            &player1, &44, &0, &0, &maze_root, &1, &10_000, &1, &10_000, &dummy_proof(&env),
+        ),
+        Error::VerificationFailed,
+    );
+    client.submit_race_sense(
+        &player1, &44, &0, &0, &maze_root, &1, &20_000, &1, &20_000, &dummy_proof(&env),
+    );
+    assert_eq!(client.get_race(&44).unwrap().player1.completed_senses, 0b1);
+}
+
+// ============================================================================
+// Ghost Challenge Tests
+// ============================================================================
+
+#[test]
+fn test_successful_run_leaves_ghost() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    finish_run(&env, &client, 50, &player1, &house, 2, 20);
+
+    let entry = client.get_leaderboard().get(0).unwrap();
+    assert_eq!(entry.session_id, 50);
+
+    let ghost = client.get_ghost(&50).unwrap();
+    assert_eq!(ghost.owner, player1);
+    assert_eq!(ghost.character, 2);
+    assert_eq!(ghost.total_score, 1_200_000);
+    assert_eq!(ghost.sense_scores.len(), 6);
+    assert_eq!(ghost.sense_scores.get(5).unwrap(), 200_000);
+}
+
+#[test]
+fn test_challenger_beating_every_sense_wins() {
+    let (env, client, _hub, owner, house) = setup_test();
+    let challenger = Address::generate(&env);
+
+    finish_run(&env, &client, 51, &owner, &house, 1, 20);
+    client.publish_ghost(&51, &500);
+
+    client.start_challenge(&52, &challenger, &51, &500);
+    let session = client.get_session(&challenger).unwrap();
+    assert_eq!(session.player2, owner);
+    assert_eq!(session.character, 1);
+    assert_eq!(session.ghost, Some(51));
+    assert_eq!(session.player2_points, 500);
+
+    // Switching character away from the ghost's is refused
+    assert_sa_birth_error(&client.try_set_character(&challenger, &0), Error::InvalidCharacter);
+    client.set_character(&challenger, &1);
+
+    submit_all_senses(&env, &client, &challenger, 1, 19, 10_000);
+    assert_eq!(client.attempt_exit(&challenger), (true, 1_140_000));
+    assert_eq!(client.get_rank(&challenger), Some(1));
+}
+
+#[test]
+fn test_challenger_must_beat_each_sense() {
+    let (env, client, _hub, owner, house) = setup_test();
+    let challenger = Address::generate(&env);
+
+    finish_run(&env, &client, 53, &owner, &house, 0, 20);
+    client.publish_ghost(&53, &500);
+    client.start_challenge(&54, &challenger, &53, &500);
+
+    // Far lower total, but sense 5 only ties the ghost
+    let proof = dummy_proof(&env);
+    for sense_id in 0..5 {
+        submit_sense(&client, &challenger, 0, sense_id, 1, 10_000, &proof);
+    }
+    submit_sense(&client, &challenger, 0, 5, 20, 10_000, &proof);
+
+    assert_eq!(client.attempt_exit(&challenger), (false, 250_000));
+    assert_eq!(client.get_leaderboard().len(), 1);
+    assert_eq!(client.get_stats().run.challenges_lost, 1);
+    assert!(client.get_ghost(&54).is_none());
+}
+
+#[test]
+fn test_challenge_requires_known_ghost() {
+    let (_env, client, _hub, player1, _house) = setup_test();
+
+    assert_sa_birth_error(
+        &client.try_start_challenge(&55, &player1, &99, &500),
+        Error::GameNotFound,
+    );
+}
+
+#[test]
+fn test_challenge_requires_owner_opt_in() {
+    let (env, client, _hub, owner, house) = setup_test();
+    let challenger = Address::generate(&env);
+
+    finish_run(&env, &client, 56, &owner, &house, 0, 20);
+    assert_eq!(client.get_ghost_stake_cap(&56), None);
+    assert_sa_birth_error(
+        &client.try_start_challenge(&57, &challenger, &56, &500),
+        Error::GhostNotPublished,
+    );
+
+    client.publish_ghost(&56, &300);
+    assert_eq!(env.auths()[0].0, owner);
+    assert_eq!(client.get_ghost_stake_cap(&56), Some(300));
+    assert_sa_birth_error(
+        &client.try_start_challenge(&57, &challenger, &56, &500),
+        Error::InvalidStake,
+    );
+    assert_sa_birth_error(&client.try_publish_ghost(&56, &-1), Error::InvalidStake);
+    assert_sa_birth_error(&client.try_publish_ghost(&99, &300), Error::GameNotFound);
+
+    client.start_challenge(&57, &challenger, &56, &300);
+    assert_eq!(client.get_session(&challenger).unwrap().player2_points, 300);
+}
+
+#[test]
+#[should_panic]
+fn test_only_owner_publishes_ghost() {
+    let (env, client, _hub, owner, house) = setup_test();
+
+    finish_run(&env, &client, 58, &owner, &house, 0, 20);
+    env.set_auths(&[]);
+    client.publish_ghost(&58, &300);
+}
+
+// ============================================================================
+// Credential Tests
+// ============================================================================
+
+#[test]
+fn test_exit_issues_and_improves_credential() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    assert!(!client.has_credential(&player1, &1));
+
+    finish_run(&env, &client, 60, &player1, &house, 1, 20);
+    let first = client.get_credential(&player1, &1).unwrap();
+    assert_eq!(first.best_score, 1_200_000);
+    assert_eq!(first.session_id, 60);
+    assert_eq!(first.embodiments, 1);
+
+    // A worse run counts but keeps the best score
+    env.ledger().with_mut(|li| li.timestamp += 500);
+    finish_run(&env, &client, 61, &player1, &house, 1, 30);
+    let worse = client.get_credential(&player1, &1).unwrap();
+    assert_eq!(worse.best_score, 1_200_000);
+    assert_eq!(worse.session_id, 60);
+    assert_eq!(worse.embodiments, 2);
+
+    env.ledger().with_mut(|li| li.timestamp += 500);
+    finish_run(&env, &client, 62, &player1, &house, 1, 10);
+    let better = client.get_credential(&player1, &1).unwrap();
+    assert_eq!(better.best_score, 600_000);
+    assert_eq!(better.session_id, 62);
+    assert_eq!(better.embodiments, 3);
+    assert_eq!(better.first_earned_at, first.earned_at);
+    assert_eq!(better.earned_at, first.earned_at + 1_000);
+
+    // Credentials are per character
+    assert!(!client.has_credential(&player1, &0));
+}
+
+#[test]
+fn test_failed_exit_issues_no_credential() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    start_run(&client, 63, &player1, &house, 2);
+    submit_all_senses(&env, &client, &player1, 2, 100, 40_000);
+    client.attempt_exit(&player1);
+
+    assert!(client.get_credential(&player1, &2).is_none());
+}
+
+#[test]
+fn test_credential_records_aggregated_proof_digest() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    start_run(&client, 64, &player1, &house, 0);
+    for sense_id in 0..6 {
+        submit_sense(&client, &player1, 0, sense_id, 10, 10_000, &Bytes::new(&env));
+    }
+    let inputs = run_inputs(&env, [100_000; 6], 20_000_000, 0);
+    client.attempt_exit_with_proof(&player1, &inputs, &dummy_proof(&env));
+
+    // Read through the cross-contract interface
+    let credentials = EmbodimentCredentialsClient::new(&env, &client.address);
+    assert!(credentials.has_credential(&player1, &0));
+    let credential = credentials.get_credential(&player1, &0).unwrap();
+    assert_eq!(
+        credential.proof_digest,
+        client.get_run_proof(&player1).unwrap().proof_digest
+    );
+}
+
+// ============================================================================
+// Progression Registry Tests
+// ============================================================================
+
+/// Deploy a registry, allowlist sa-birth and point sa-birth at it.
+fn setup_registry(env: &Env, client: &SaBirthContractClient) -> RegistryClient<'static> {
+    let registry_admin = Address::generate(env);
+    let registry_id = env.register(Registry, (&registry_admin,));
+    let registry = RegistryClient::new(env, &registry_id);
+    registry.allow_writer(&client.address);
+    client.set_registry(&registry_id);
+    registry
+}
+
+#[test]
+fn test_exit_records_milestones_in_registry() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let registry = setup_registry(&env, &client);
+
+    start_run(&client, 70, &player1, &house, 1);
+    let proof = dummy_proof(&env);
+    // Mastery threshold is 20_000_000 / 6 = 3_333_333 per sense
+    for sense_id in 0..5 {
+        submit_sense(&client, &player1, 1, sense_id, 10, 10_000, &proof);
+    }
+    submit_sense(&client, &player1, 1, 5, 400, 10_000, &proof);
+    client.attempt_exit(&player1);
+
+    let milestones = registry.get_milestones(&player1, &1);
+    assert_eq!(milestones.len(), 6);
+    assert!(registry.has_milestone(&player1, &1, &Symbol::new(&env, "embodied")));
+    assert!(registry.has_milestone(&player1, &1, &Symbol::new(&env, "mastered_hearing")));
+    assert!(!registry.has_milestone(
+        &player1,
+        &1,
+        &Symbol::new(&env, "mastered_proprioception")
+    ));
+
+    let record = registry
+        .get_milestone(&player1, &1, &Symbol::new(&env, "embodied"))
+        .unwrap();
+    assert_eq!(record.first_game, client.address);
+}
+
+#[test]
+fn test_overload_recorded_in_registry() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let registry = setup_registry(&env, &client);
+
+    start_run(&client, 71, &player1, &house, 0);
+    submit_all_senses(&env, &client, &player1, 0, 100, 40_000);
+    client.attempt_exit(&player1);
+
+    let milestones = registry.get_milestones(&player1, &0);
+    assert_eq!(milestones.len(), 1);
+    assert!(registry.has_milestone(&player1, &0, &Symbol::new(&env, "overloaded")));
+}
+
+#[test]
+fn test_registry_failure_does_not_block_exit() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let registry = setup_registry(&env, &client);
+    registry.revoke_writer(&client.address);
+
+    finish_run(&env, &client, 72, &player1, &house, 2, 20);
+
+    // The rejected write is reported instead of silently dropped
+    let mut milestones = soroban_sdk::vec![&env, Symbol::new(&env, "embodied")];
+    for name in [
+        "mastered_hearing",
+        "mastered_smell",
+        "mastered_taste",
+        "mastered_touch",
+        "mastered_sight",
+        "mastered_proprioception",
+    ] {
+        milestones.push_back(Symbol::new(&env, name));
+    }
+    let failed = MilestonesFailedEvent {
+        player: player1.clone(),
+        registry: registry.address.clone(),
+        milestones,
+    };
+    assert!(env
+        .events()
+        .all()
+        .events()
+        .contains(&failed.to_xdr(&env, &client.address)));
+
+    assert_eq!(client.get_rank(&player1), Some(1));
+    assert_eq!(registry.get_milestones(&player1, &2).len(), 0);
+
+    client.clear_registry();
+    assert!(client.get_registry().is_none());
+}
+
+#[test]
+fn test_character_switch_writes_no_milestones() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let registry = setup_registry(&env, &client);
+
+    start_run(&client, 74, &player1, &house, 0);
+    submit_sense(&client, &player1, 0, 0, 10, 10_000, &dummy_proof(&env));
+
+    // Switching to an unplayed character is rejected before anything is recorded
+    assert_sa_birth_error(&client.try_set_character(&player1, &2), Error::SessionAlreadyActive);
+    assert_eq!(registry.get_milestones(&player1, &2).len(), 0);
+
+    for sense_id in 1..6 {
+        submit_sense(&client, &player1, 0, sense_id, 10, 10_000, &dummy_proof(&env));
+    }
+    client.attempt_exit(&player1);
+
+    assert_eq!(registry.get_milestones(&player1, &2).len(), 0);
+    assert!(registry.has_milestone(&player1, &0, &Symbol::new(&env, "embodied")));
+}
+
+#[test]
+fn test_mastery_requires_proven_sense() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let registry = setup_registry(&env, &client);
+
+    start_run(&client, 73, &player1, &house, 1);
+    submit_sense(&client, &player1, 1, 0, 10, 10_000, &Bytes::new(&env));
+    submit_sense(&client, &player1, 1, 1, 10, 10_000, &dummy_proof(&env));
+    client.attempt_exit(&player1);
+
+    assert!(!registry.has_milestone(&player1, &1, &Symbol::new(&env, "mastered_hearing")));
+    assert!(registry.has_milestone(&player1, &1, &Symbol::new(&env, "mastered_smell")));
+}
+
+// ============================================================================
+// Achievement Tests
+// ============================================================================
+
+// Default table ids
+const ACH_ALL_SENSES_UNDER: u32 = 0;
+const ACH_ROBERT_NO_HAZARDS: u32 = 1;
+const ACH_EMBODIED_ALICE: u32 = 2;
+const ACH_ALL_CHARACTERS: u32 = 5;
+const ACH_STREAK: u32 = 6;
+
+fn has_achievement(client: &SaBirthContractClient, player: &Address, id: u32) -> bool {
+    client.get_achievements(player) & (1u128 << id) != 0
+}
+
+#[test]
+fn test_exit_unlocks_default_achievements() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    // 6 × 40 × 10_000: every sense 400_000 ≤ 500_000, 10 s per sense
+    finish_run(&env, &client, 80, &player1, &house, 1, 40);
+
+    assert!(has_achievement(&client, &player1, ACH_ALL_SENSES_UNDER));
+    assert!(has_achievement(&client, &player1, ACH_ROBERT_NO_HAZARDS));
+    assert!(!has_achievement(&client, &player1, ACH_EMBODIED_ALICE));
+    assert!(has_achievement(&client, &player1, 3));
+    assert!(!has_achievement(&client, &player1, ACH_ALL_CHARACTERS));
+
+    finish_run(&env, &client, 81, &player1, &house, 0, 60);
+    finish_run(&env, &client, 82, &player1, &house, 2, 60);
+    assert!(has_achievement(&client, &player1, ACH_EMBODIED_ALICE));
+    assert!(has_achievement(&client, &player1, ACH_ALL_CHARACTERS));
+    assert!(has_achievement(&client, &player1, ACH_STREAK));
+    assert_eq!(client.get_streak(&player1), 3);
+}
+
+#[test]
+fn test_hazards_forfeit_robert_achievement() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let proof = dummy_proof(&env);
+
+    // Hazard penalties must fit inside the reported time
+    start_run(&client, 88, &player1, &house, 1);
+    let maze_root = client.get_maze_root(&player1, &0).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_sense_completion(
+            &player1, &0, &(1 << 8), &maze_root, &10, &10_000, &1, &100_000, &proof,
+        ),
+        Error::VerificationFailed,
+    );
+
+    // One hazard, even on a fast sense, forfeits the achievement
+    client.submit_sense_completion(
+        &player1, &0, &(1 << 8), &maze_root, &10, &20_000, &1, &200_000, &proof,
+    );
+    assert_eq!(client.get_sense_result(&player1, &0).unwrap().hazards, 1);
+    for sense_id in 1..6 {
+        submit_sense(&client, &player1, 1, sense_id, 10, 1_000, &proof);
+    }
+    assert!(client.attempt_exit(&player1).0);
+    assert!(!has_achievement(&client, &player1, ACH_ROBERT_NO_HAZARDS));
+}
+
+#[test]
+fn test_achievements_follow_proven_character() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    start_run(&client, 90, &player1, &house, 0);
+    submit_all_senses(&env, &client, &player1, 0, 40, 10_000);
+    assert_eq!(client.get_sense_result(&player1, &0).unwrap().character, 0);
+
+    // Switching to ROBERT after proving ALICE's mazes unlocks nothing for ROBERT
+    assert_sa_birth_error(&client.try_set_character(&player1, &1), Error::SessionAlreadyActive);
+    assert!(client.attempt_exit(&player1).0);
+
+    assert!(has_achievement(&client, &player1, ACH_EMBODIED_ALICE));
+    assert!(!has_achievement(&client, &player1, ACH_ROBERT_NO_HAZARDS));
+    assert!(!has_achievement(&client, &player1, 3));
+}
+
+#[test]
+fn test_failed_exit_resets_streak() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    finish_run(&env, &client, 83, &player1, &house, 0, 60);
+    finish_run(&env, &client, 84, &player1, &house, 0, 60);
+    assert_eq!(client.get_streak(&player1), 2);
+
+    start_run(&client, 85, &player1, &house, 0);
+    client.attempt_exit(&player1);
+    assert_eq!(client.get_streak(&player1), 0);
+
+    finish_run(&env, &client, 86, &player1, &house, 0, 60);
+    assert_eq!(client.get_streak(&player1), 1);
+    assert!(!has_achievement(&client, &player1, ACH_STREAK));
+}
+
+#[test]
+fn test_admin_extends_achievement_table() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    let id = client.add_achievement(&AchievementRule::SenseUnder(3, 50_000));
+    assert_eq!(id, 7);
+    assert_eq!(client.get_achievement_table().len(), 8);
+
+    start_run(&client, 87, &player1, &house, 0);
+    submit_sense(&client, &player1, 0, 2, 1, 10_000, &dummy_proof(&env));
+    assert!(!has_achievement(&client, &player1, id));
+    // An unproven submission doesn't count
+    submit_sense(&client, &player1, 0, 3, 4, 10_000, &Bytes::new(&env));
+    assert!(!has_achievement(&client, &player1, id));
+
+    start_run(&client, 89, &player1, &house, 0);
+    submit_sense(&client, &player1, 0, 3, 4, 10_000, &dummy_proof(&env));
+    assert!(has_achievement(&client, &player1, id));
+
+    // Retiring the Streak rule keeps the other ids stable
+    client.set_achievement(&ACH_STREAK, &AchievementRule::Retired);
+    assert_eq!(
+        client.get_achievement_table().get(ACH_STREAK).unwrap(),
+        AchievementRule::Retired
+    );
+    assert_sa_birth_error(
+        &client.try_set_achievement(&8, &AchievementRule::Retired),
+        Error::InvalidAchievement,
+    );
+}
+
+// ============================================================================
+// Difficulty Tier Tests
+// ============================================================================
+
+/// Start a tiered run (house stake per the default multiplier) as ROBERT and
+/// submit all six senses with score = points × 10_000 each.
+fn play_tier_run(
+    env: &Env,
+    client: &SaBirthContractClient,
+    session_id: u32,
+    player: &Address,
+    house: &Address,
+    tier: u32,
+    points: u64,
+) {
+    let stake = 100_0000000i128;
+    let house_stake = stake * client.get_tier_config(&tier).unwrap().payout_bps as i128 / 10_000;
+    client.start_game_with_tier(&session_id, player, house, &stake, &house_stake, &tier);
+    client.set_character(player, &1);
+
+    let proof = dummy_proof(env);
+    for sense_id in 0..6u32 {
+        let maze_root = client.get_maze_root(player, &sense_id).unwrap();
+        client.submit_sense_completion(
+            player,
+            &sense_id,
+            &((tier << 16) | (1 << 8) | sense_id),
+            &maze_root,
+            &points,
+            &10_000,
+            &0,
+            &(points * 10_000),
+            &proof,
+        );
+    }
+}
+
+#[test]
+fn test_tier_caps_decide_outcome() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let player3 = Address::generate(&env);
+
+    // 6 × 25 × 10_000 = 1_500_000 — with a custom Overclock cap below it
+    client.set_tier_config(
+        &2,
+        &TierConfig {
+            score_cap: 1_000_000,
+            min_stake: 0,
+            payout_bps: 20_000,
+        },
+    );
+    play_tier_run(&env, &client, 1, &player1, &house, 2, 25);
+    assert_eq!(client.attempt_exit(&player1), (false, 1_500_000));
+
+    // Novice cap is looser than Standard
+    play_tier_run(&env, &client, 2, &player3, &house, 1, 400);
+    assert_eq!(client.attempt_exit(&player3), (true, 24_000_000));
+
+    let entry = client.get_leaderboard().get(0).unwrap();
+    assert_eq!(entry.tier, 1);
+    assert_eq!(client.get_session(&player3).unwrap().tier, 1);
+}
+
+#[test]
+fn test_tier_change_keeps_running_session_rules() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    client.start_game_with_tier(&1, &player1, &house, &100, &200, &2);
+    let session = client.get_session(&player1).unwrap();
+    assert_eq!(session.score_cap, 12_000_000);
+    assert_eq!(session.payout_bps, 20_000);
+
+    // Tightening the cap mid-run doesn't turn this win into a loss
+    client.set_tier_config(
+        &2,
+        &TierConfig {
+            score_cap: 1_000_000,
+            min_stake: 0,
+            payout_bps: 20_000,
+        },
+    );
+    client.set_character(&player1, &1);
+    let proof = dummy_proof(&env);
+    for sense_id in 0..6 {
+        let maze_root = client.get_maze_root(&player1, &sense_id).unwrap();
+        client.submit_sense_completion(
+            &player1,
+            &sense_id,
+            &((2 << 16) | (1 << 8) | sense_id),
+            &maze_root,
+            &25,
+            &10_000,
+            &0,
+            &250_000,
+            &proof,
+        );
+    }
+    assert_eq!(client.attempt_exit(&player1), (true, 1_500_000));
+}
+
+#[test]
+fn test_tier_brackets_on_leaderboard() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let player3 = Address::generate(&env);
+
+    play_tier_run(&env, &client, 1, &player1, &house, 2, 10);
+    client.attempt_exit(&player1);
+    finish_run(&env, &client, 2, &player3, &house, 1, 20);
+
+    let overclock = client.get_leaderboard_filtered(
+        &LeaderboardFilter {
+            character: None,
+            tier: Some(2),
+            from_timestamp: None,
+            to_timestamp: None,
+        },
+        &0,
+        &10,
+    );
+    assert_eq!(overclock.len(), 1);
+    assert_eq!(overclock.get(0).unwrap().player, player1);
+
+    let standard = client.get_leaderboard_filtered(
+        &LeaderboardFilter {
+            character: None,
+            tier: Some(0),
+            from_timestamp: None,
+            to_timestamp: None,
+        },
+        &0,
+        &10,
+    );
+    assert_eq!(standard.len(), 1);
+    assert_eq!(standard.get(0).unwrap().player, player3);
+}
+
+#[test]
+fn test_tier_separates_maze_ids() {
+    let (env, client, _hub, player1, house) = setup_test();
+
+    client.start_game_with_tier(&1, &player1, &house, &100, &200, &2);
+    client.set_character(&player1, &0);
+
+    // A Standard maze id is rejected in an Overclock run
+    let maze_root = client.get_maze_root(&player1, &0).unwrap();
+    assert_sa_birth_error(
+        &client.try_submit_sense_completion(
+            &player1, &0, &0, &maze_root, &10, &1_000, &0, &10_000, &dummy_proof(&env),
+        ),
+        Error::VerificationFailed,
+    );
+    client.submit_sense_completion(
+        &player1, &0, &(2 << 16), &maze_root, &10, &1_000, &0, &10_000, &dummy_proof(&env),
+    );
+}
+
+#[test]
+fn test_tier_stake_rules() {
+    let (_env, client, _hub, player1, house) = setup_test();
+
+    assert_sa_birth_error(
+        &client.try_start_game_with_tier(&1, &player1, &house, &100, &100, &3),
+        Error::InvalidTier,
+    );
+    // Novice house stake is half the player's stake
+    assert_sa_birth_error(
+        &client.try_start_game_with_tier(&1, &player1, &house, &100, &100, &1),
+        Error::InvalidStake,
+    );
+
+    client.set_tier_config(
+        &0,
+        &TierConfig {
+            score_cap: 20_000_000,
+            min_stake: 1_000,
+            payout_bps: 10_000,
+        },
+    );
+    assert_sa_birth_error(
+        &client.try_start_game(&1, &player1, &house, &999, &999),
+        Error::InvalidStake,
+    );
+
+    client.start_game_with_tier(&1, &player1, &house, &100, &50, &1);
+    assert_sa_birth_error(
+        &client.try_set_tier_config(
+            &3,
+            &TierConfig {
+                score_cap: 1,
+                min_stake: 0,
+                payout_bps: 0,
+            },
+        ),
+        Error::InvalidTier,
+    );
+}
+
+// ============================================================================
+// Stateful Hub Tests
+// ============================================================================
+
+/// Point sa-birth at the stateful mock-game-hub so tests can inject faults.
+fn setup_hub(env: &Env, client: &SaBirthContractClient, players: &[&Address]) -> HubClient<'static> {
+    let hub_admin = Address::generate(env);
+    let hub = HubClient::new(env, &env.register(Hub, (&hub_admin,)));
+    hub.add_game(&client.address);
+    for player in players {
+        hub.credit(player, &10_000_000_000);
+    }
+    client.set_hub(&hub.address);
+    hub
+}
+
+#[test]
+fn test_hub_start_failure_leaves_no_session() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let hub = setup_hub(&env, &client, &[&player1, &house]);
+
+    hub.set_faults(&Faults {
+        fail_start: true,
+        ..Faults::default()
+    });
+    let result = client.try_start_game(&1, &player1, &house, &100_0000000, &100_0000000);
+    assert!(result.is_err());
+    assert!(client.get_session(&player1).is_none());
+    assert_eq!(client.get_stats().run.sessions_started, 0);
+    assert_eq!(hub.get_locked(&player1), 0);
+
+    hub.set_faults(&Faults::default());
+    start_run(&client, 1, &player1, &house, 0);
+    assert_eq!(hub.get_session(&1).unwrap().status, HubStatus::Active);
+}
+
+#[test]
+fn test_orphan_cleanup_survives_hub_end_failure() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let hub = setup_hub(&env, &client, &[&player1, &house]);
+
+    start_run(&client, 1, &player1, &house, 0);
+    submit_sense(&client, &player1, 0, 0, 10, 1_000, &dummy_proof(&env));
+
+    // The hub refuses to end the orphan, but the new session still opens
+    hub.set_faults(&Faults {
+        fail_session: Some(1),
+        ..Faults::default()
+    });
+    start_run(&client, 2, &player1, &house, 1);
+
+    let session = client.get_session(&player1).unwrap();
+    assert_eq!(session.session_id, 2);
+    assert!(session.active);
+    assert_eq!(session.completed_senses, 0);
+    assert_eq!(client.get_stats().run.incompletes, 1);
+    assert_eq!(hub.get_session(&1).unwrap().status, HubStatus::Active);
+    assert_eq!(hub.get_session(&2).unwrap().status, HubStatus::Active);
+}
+
+#[test]
+fn test_exit_rolls_back_when_hub_end_fails() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let hub = setup_hub(&env, &client, &[&player1, &house]);
+
+    start_run(&client, 1, &player1, &house, 0);
+    submit_all_senses(&env, &client, &player1, 0, 20, 10_000);
+
+    hub.set_faults(&Faults {
+        fail_end: true,
+        ..Faults::default()
+    });
+    assert!(client.try_attempt_exit(&player1).is_err());
+
+    // Nothing was settled: the run can still exit once the hub recovers
+    assert!(client.get_session(&player1).unwrap().active);
+    assert_eq!(client.get_leaderboard().len(), 0);
+    assert_eq!(client.get_stats().run.exits, 0);
+    assert!(!client.has_credential(&player1, &0));
+
+    hub.set_faults(&Faults::default());
+    let (success, _) = client.attempt_exit(&player1);
+    assert!(success);
+    assert_eq!(hub.get_session(&1).unwrap().player1_won, Some(true));
+    assert_eq!(hub.get_balance(&player1), 11_000_000_000);
+}
+
+#[test]
+fn test_sessions_force_settled_by_the_hub_still_close() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let player2 = Address::generate(&env);
+    let hub = setup_hub(&env, &client, &[&player1, &player2, &house]);
+
+    start_run(&client, 1, &player1, &house, 0);
+    client.start_race(&2, &player2, &house, &100, &100, &0, &1_000);
+    submit_all_senses(&env, &client, &player1, 0, 20, 10_000);
+    for sense_id in 0..6 {
+        submit_race(&env, &client, &player2, 2, 0, sense_id, 20);
+        submit_race(&env, &client, &house, 2, 0, sense_id, 30);
+    }
+
+    // sa-birth sets no policy, so the hub's default timeout cancels both
+    advance_ledgers(&env, 17_281);
+    assert_eq!(hub.force_settle(&1), HubOutcome::Cancelled);
+    assert_eq!(hub.force_settle(&2), HubOutcome::Cancelled);
+
+    // The hub already settled both sessions, so closing them doesn't trap
+    let (success, _) = client.attempt_exit(&player1);
+    assert!(success);
+    assert!(!client.get_session(&player1).unwrap().active);
+    assert_eq!(client.settle_race(&2), GameOutcome::Player1);
+    assert!(client.get_race(&2).unwrap().settled);
+
+    // The hub's refund stands
+    assert_eq!(hub.get_session(&1).unwrap().status, HubStatus::Cancelled);
+    assert_eq!(hub.get_balance(&player1), 10_000_000_000);
+    assert_eq!(hub.get_locked(&player2), 0);
+}
+
+#[test]
+fn test_start_game_reserved_takes_hub_session_id() {
+    let (env, client, _hub, player1, house) = setup_test();
+    let hub = setup_hub(&env, &client, &[&player1, &house]);
+
+    // The hub skips ids already taken with a caller-chosen id
+    start_run(&client, 1, &player1, &house, 0);
+    let player2 = Address::generate(&env);
+    hub.credit(&player2, &10_000_000_000);
+
+    let session_id =
+        client.start_game_reserved(&player2, &house, &100_0000000, &100_0000000, &0);
+    assert_eq!(session_id, 2);
+    assert_eq!(client.get_session(&player2).unwrap().session_id, 2);
+    assert_eq!(hub.get_session(&2).unwrap().player1, player2);
+
+    client.set_character(&player2, &0);
+    submit_all_senses(&env, &client, &player2, 0, 20, 10_000);
+    assert!(client.attempt_exit(&player2).0);
+    assert_eq!(hub.get_session(&2).unwrap().player1_won, Some(true));
+}
+
+// ============================================================================
+// Rate Limit Tests
+// ============================================================================
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_unlimited_by_default() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    for session_id in 50..55 {
+        client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    }
+    assert!(client.get_limits_status(&player1).can_start);
+}
+
+#[test]
+fn test_races_are_rate_limited() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    client.set_rate_limits(&RateLimits {
+        min_ledger_gap: 10,
+        max_sessions: 0,
+        window_ledgers: 0,
+    });
+
+    client.start_race(&64, &player1, &player2, &100, &100, &0, &100);
+    assert_sa_birth_error(
+        &client.try_start_race(&65, &player3, &player2, &100, &100, &0, &100),
+        Error::CooldownActive,
+    );
+    assert_sa_birth_error(
+        &client.try_start_game(&66, &player1, &player3, &100_0000000, &100_0000000),
+        Error::CooldownActive,
+    );
+
+    advance_ledgers(&env, 10);
+    client.start_race(&65, &player3, &player2, &100, &100, &0, &100);
+}
+
+#[test]
+fn test_cooldown_between_sessions() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.set_rate_limits(&RateLimits {
+        min_ledger_gap: 10,
+        max_sessions: 0,
+        window_ledgers: 0,
+    });
+
+    client.start_game(&60, &player1, &player2, &100_0000000, &100_0000000);
+    let result = client.try_start_game(&61, &player1, &player2, &100_0000000, &100_0000000);
+    assert_sa_birth_error(&result, Error::CooldownActive);
+
+    let status = client.get_limits_status(&player1);
+    assert!(!status.can_start);
+    assert_eq!(status.next_allowed_ledger, status.current_ledger + 10);
+
+    // Other players are unaffected
+    let player3 = Address::generate(&env);
+    client.start_game(&62, &player3, &player2, &100_0000000, &100_0000000);
+
+    advance_ledgers(&env, 10);
+    assert!(client.get_limits_status(&player1).can_start);
+    client.start_game(&63, &player1, &player2, &100_0000000, &100_0000000);
+}
+
+#[test]
+fn test_session_quota_per_window() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.set_rate_limits(&RateLimits {
+        min_ledger_gap: 0,
+        max_sessions: 2,
+        window_ledgers: 100,
+    });
 
-    // Game completes successfully with asymmetric points
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    client.start_game(&70, &player1, &player2, &100_0000000, &100_0000000);
+    let first_ledger = env.ledger().sequence();
+    advance_ledgers(&env, 30);
+    client.start_game(&71, &player1, &player2, &100_0000000, &100_0000000);
+
+    let result = client.try_start_game(&72, &player1, &player2, &100_0000000, &100_0000000);
+    assert_sa_birth_error(&result, Error::SessionQuotaExceeded);
+
+    let status = client.get_limits_status(&player1);
+    assert_eq!(status.sessions_in_window, 2);
+    assert_eq!(status.max_sessions, 2);
+    assert_eq!(status.next_allowed_ledger, first_ledger + 100);
+
+    // Once the first start leaves the window a new session is allowed
+    advance_ledgers(&env, 70);
+    let status = client.get_limits_status(&player1);
+    assert!(status.can_start);
+    assert_eq!(status.sessions_in_window, 1);
+    client.start_game(&72, &player1, &player2, &100_0000000, &100_0000000);
 }
 
 // ============================================================================
@@ -10,28 +10,12 @@
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use sgs_common::extend_temporary;
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror,
     contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -87,20 +71,8 @@ pub struct Game {
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -150,10 +122,7 @@ impl TwentyOneContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_common::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -186,89 +155,53 @@ impl TwentyOneContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
+        Self::open_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        )
+    }
 
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
+    /// Start a new game like `start_game`, with the session ID issued by the
+    /// Game Hub instead of chosen by the caller.
+    ///
+    /// Players authorize only their points, since the session ID isn't known
+    /// until the hub issues it.
+    ///
+    /// # Returns
+    /// * `u32` - The session ID issued by the Game Hub
+    pub fn start_game_reserved(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
         }
 
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
-        }
+        // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player2_points.into_val(&env)]);
 
-        // Create game
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        // Ask the Game Hub for the next free session ID
+        let session_id = sgs_common::hub_reserve_session(&env);
+
+        Self::open_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_hand,
-            player2_hand,
-            player1_stuck: false,
-            player2_stuck: false,
-            winner: None,
-            round: 1,
-        };
-
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
-
-        // Event emitted by GameHub contract (GameStarted)
+        )?;
 
-        Ok(())
+        Ok(session_id)
     }
 
     /// Player draws another card ("hit").
@@ -320,7 +253,7 @@ impl TwentyOneContract {
             game.player2_hand.len()
         };
 
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
+        seed_bytes.append(&Bytes::from_array(&env, &card_count.to_be_bytes()));
         seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
 
         let card_seed = env.crypto().keccak256(&seed_bytes);
@@ -552,22 +485,94 @@ impl TwentyOneContract {
     // Internal Helper Functions
     // ========================================================================
 
-    /// Helper to end game with the Game Hub
-    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+    /// Helper to start the session with the Game Hub and store the new game
+    fn open_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        sgs_common::hub_start_game(
+            env,
+            session_id,
+            &player1,
+            &player2,
+            player1_points,
+            player2_points,
+        );
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        // Generate deterministic seed for card dealing
+        // Seed components (all deterministic and identical between sim/submit):
+        // 1. Session ID - unique per game
+        // 2. Player addresses - both players contribute
+        //
+        // Note: We do NOT include ledger sequence or timestamp because those differ
+        // between simulation and submission.
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&player1.to_string().to_bytes());
+        seed_bytes.append(&player2.to_string().to_bytes());
+        let base_seed = env.crypto().keccak256(&seed_bytes);
+
+        // Deal initial hands (2 cards each)
+        // Use different seeds for each card to ensure variety
+        let mut player1_hand = Bytes::new(env);
+        let mut player2_hand = Bytes::new(env);
+
+        // Deal 2 cards to player1
+        for i in 0..2 {
+            let mut card_seed_bytes = Bytes::new(env);
+            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+            card_seed_bytes.append(&Bytes::from_array(env, &[i, 1])); // [card_index, player]
+            let card_seed = env.crypto().keccak256(&card_seed_bytes);
+            player1_hand.push_back(deal_card(env, card_seed.into()));
+        }
 
+        // Deal 2 cards to player2
+        for i in 0..2 {
+            let mut card_seed_bytes = Bytes::new(env);
+            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+            card_seed_bytes.append(&Bytes::from_array(env, &[i, 2])); // [card_index, player]
+            let card_seed = env.crypto().keccak256(&card_seed_bytes);
+            player2_hand.push_back(deal_card(env, card_seed.into()));
+        }
+
+        // Create game
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_hand,
+            player2_hand,
+            player1_stuck: false,
+            player2_stuck: false,
+            winner: None,
+            round: 1,
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
+
+        // Set TTL to ensure game is retained for at least 30 days
+        extend_temporary(env, &game_key);
+
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Helper to end game with the Game Hub
+    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
         // Call the Game Hub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        game_hub.end_game(&session_id, &player1_won);
+        sgs_common::hub_end_game(env, session_id, player1_won);
 
         Ok(())
     }
@@ -581,10 +586,7 @@ impl TwentyOneContract {
     /// # Returns
     /// * `Address` - The admin address
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        sgs_common::get_admin(&env)
     }
 
     /// Set a new admin address
@@ -592,14 +594,7 @@ impl TwentyOneContract {
     /// # Arguments
     /// * `new_admin` - The new admin address
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        sgs_common::set_admin(&env, &new_admin);
     }
 
     /// Get the current GameHub contract address
@@ -607,10 +602,7 @@ impl TwentyOneContract {
     /// # Returns
     /// * `Address` - The GameHub contract address
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        sgs_common::get_hub(&env)
     }
 
     /// Set a new GameHub contract address
@@ -618,16 +610,7 @@ impl TwentyOneContract {
     /// # Arguments
     /// * `new_hub` - The new GameHub contract address
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        sgs_common::set_hub(&env, &new_hub);
     }
 
     /// Update the contract WASM hash (upgrade contract)
@@ -635,14 +618,7 @@ impl TwentyOneContract {
     /// # Arguments
     /// * `new_wasm_hash` - The hash of the new WASM binary
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        sgs_common::upgrade(&env, new_wasm_hash);
     }
 }
 
@@ -0,0 +1,189 @@
+#![no_std]
+
+//! # Stellar Game Studio Common
+//!
+//! Pieces every game contract in this workspace shares: the Game Hub client,
+//! the admin / hub / upgrade plumbing and storage TTL handling.
+//!
+//! **Storage:**
+//!   The admin and hub addresses live in instance storage under `CommonKey`.
+//!   A unit enum variant is stored as its name, so these are the same entries
+//!   the games' own `DataKey::Admin` / `DataKey::GameHubAddress` used, and
+//!   deployed contracts keep working after an upgrade.
+//!
+//! **Game Hub:**
+//!   `hub_start_game`, `hub_end_game` and `hub_reserve_session` call the hub
+//!   as the current contract, which is the `game_id` the hub authorizes.
+//!
+//! Contract entry points can't come from a library crate, so each game keeps
+//! thin `get_admin` / `set_admin` / `get_hub` / `set_hub` / `upgrade` methods
+//! that delegate here.
+
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, IntoVal, Val};
+
+// ============================================================================
+// Game Hub Interface
+// ============================================================================
+
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    /// End with any outcome; a draw or cancellation refunds both stakes.
+    fn end_game_v2(env: Env, session_id: u32, outcome: GameOutcome);
+
+    /// Issue the next free session id to `game_id`.
+    fn reserve_session(env: Env, game_id: Address) -> u32;
+}
+
+/// Outcome passed to `end_game_v2` (matches mock-game-hub's `GameOutcome`).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Player1,
+    Player2,
+    Draw,
+    Cancelled,
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub enum CommonKey {
+    /// Admin address (can upgrade the contract)
+    Admin,
+    /// Address of the Game Hub contract
+    GameHubAddress,
+}
+
+/// Game storage TTL — 30 days in ledgers (~5 s/ledger).
+pub const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Extend a temporary entry to live `GAME_TTL_LEDGERS` from now.
+pub fn extend_temporary<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .temporary()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Extend a persistent entry to live `GAME_TTL_LEDGERS` from now.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+// ============================================================================
+// Admin
+// ============================================================================
+
+/// Store the admin and Game Hub addresses. Call from `__constructor`.
+pub fn init(env: &Env, admin: &Address, game_hub: &Address) {
+    env.storage().instance().set(&CommonKey::Admin, admin);
+    env.storage()
+        .instance()
+        .set(&CommonKey::GameHubAddress, game_hub);
+}
+
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::Admin)
+        .expect("Admin not set")
+}
+
+/// Require the admin's authorization and return the admin.
+pub fn require_admin(env: &Env) -> Address {
+    let admin = get_admin(env);
+    admin.require_auth();
+    admin
+}
+
+/// Replace the admin. Requires the current admin.
+pub fn set_admin(env: &Env, new_admin: &Address) {
+    require_admin(env);
+    env.storage().instance().set(&CommonKey::Admin, new_admin);
+}
+
+/// Update the contract WASM hash. Requires the admin.
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
+    require_admin(env);
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+}
+
+// ============================================================================
+// Game Hub
+// ============================================================================
+
+/// The Game Hub address, if one was stored.
+pub fn hub_address(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&CommonKey::GameHubAddress)
+}
+
+pub fn get_hub(env: &Env) -> Address {
+    hub_address(env).expect("GameHub address not set")
+}
+
+/// Replace the Game Hub address. Requires the admin.
+pub fn set_hub(env: &Env, new_hub: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .set(&CommonKey::GameHubAddress, new_hub);
+}
+
+/// Client for the stored Game Hub.
+pub fn hub_client(env: &Env) -> GameHubClient<'static> {
+    GameHubClient::new(env, &get_hub(env))
+}
+
+/// Start a session in the Game Hub and lock both players' points.
+/// The hub authorizes the current contract as `game_id`.
+pub fn hub_start_game(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_points: i128,
+    player2_points: i128,
+) {
+    hub_client(env).start_game(
+        &env.current_contract_address(),
+        &session_id,
+        player1,
+        player2,
+        &player1_points,
+        &player2_points,
+    );
+}
+
+/// End a session in the Game Hub and declare the winner.
+pub fn hub_end_game(env: &Env, session_id: u32, player1_won: bool) {
+    hub_client(env).end_game(&session_id, &player1_won);
+}
+
+/// End a session in the Game Hub with any outcome, including a draw.
+pub fn hub_end_game_v2(env: &Env, session_id: u32, outcome: GameOutcome) {
+    hub_client(env).end_game_v2(&session_id, &outcome);
+}
+
+/// Ask the Game Hub for the next free session id for the current contract.
+pub fn hub_reserve_session(env: &Env) -> u32 {
+    hub_client(env).reserve_session(&env.current_contract_address())
+}
+
+#[cfg(test)]
+mod test;
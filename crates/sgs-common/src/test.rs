@@ -0,0 +1,301 @@
+#![cfg(test)]
+
+// Unit tests for the shared helpers, exercised through a minimal game
+// contract and a stub Game Hub that records what it was called with.
+
+use crate::{CommonKey, GameOutcome, GAME_TTL_LEDGERS};
+use soroban_sdk::testutils::{
+    storage::{Persistent as _, Temporary as _},
+    Address as _, Ledger as _,
+};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env};
+
+// ============================================================================
+// Stub Game Hub
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StartCall {
+    pub game_id: Address,
+    pub session_id: u32,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum HubKey {
+    Start,
+    End,
+    EndV2,
+    NextId,
+}
+
+#[contract]
+pub struct StubHub;
+
+#[contractimpl]
+impl StubHub {
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) {
+        game_id.require_auth();
+        let call = StartCall {
+            game_id,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        };
+        env.storage().instance().set(&HubKey::Start, &call);
+    }
+
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.storage()
+            .instance()
+            .set(&HubKey::End, &(session_id, player1_won));
+    }
+
+    pub fn end_game_v2(env: Env, session_id: u32, outcome: GameOutcome) {
+        env.storage()
+            .instance()
+            .set(&HubKey::EndV2, &(session_id, outcome));
+    }
+
+    pub fn reserve_session(env: Env, game_id: Address) -> u32 {
+        game_id.require_auth();
+        let id: u32 = env.storage().instance().get(&HubKey::NextId).unwrap_or(1);
+        env.storage().instance().set(&HubKey::NextId, &(id + 1));
+        id
+    }
+
+    pub fn last_start(env: Env) -> Option<StartCall> {
+        env.storage().instance().get(&HubKey::Start)
+    }
+
+    pub fn last_end(env: Env) -> Option<(u32, bool)> {
+        env.storage().instance().get(&HubKey::End)
+    }
+
+    pub fn last_end_v2(env: Env) -> Option<(u32, GameOutcome)> {
+        env.storage().instance().get(&HubKey::EndV2)
+    }
+}
+
+// ============================================================================
+// Test Game
+// ============================================================================
+
+#[contract]
+pub struct TestGame;
+
+#[contractimpl]
+impl TestGame {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        crate::init(&env, &admin, &game_hub);
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        crate::get_admin(&env)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        crate::set_admin(&env, &new_admin);
+    }
+
+    pub fn get_hub(env: Env) -> Address {
+        crate::get_hub(&env)
+    }
+
+    pub fn set_hub(env: Env, new_hub: Address) {
+        crate::set_hub(&env, &new_hub);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        crate::upgrade(&env, new_wasm_hash);
+    }
+
+    pub fn open(env: Env, player1: Address, player2: Address, points: i128) -> u32 {
+        let session_id = crate::hub_reserve_session(&env);
+        crate::hub_start_game(&env, session_id, &player1, &player2, points, points);
+        session_id
+    }
+
+    pub fn close(env: Env, session_id: u32, player1_won: bool) {
+        crate::hub_end_game(&env, session_id, player1_won);
+    }
+
+    pub fn settle(env: Env, session_id: u32, outcome: GameOutcome) {
+        crate::hub_end_game_v2(&env, session_id, outcome);
+    }
+
+    pub fn touch(env: Env, id: u32) {
+        env.storage().temporary().set(&id, &true);
+        crate::extend_temporary(&env, &id);
+        env.storage().persistent().set(&id, &true);
+        crate::extend_persistent(&env, &id);
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    TestGameClient<'static>,
+    StubHubClient<'static>,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 100_000;
+        li.min_temp_entry_ttl = 16;
+        li.min_persistent_entry_ttl = 16;
+        li.max_entry_ttl = GAME_TTL_LEDGERS + 1;
+    });
+
+    let hub_addr = env.register(StubHub, ());
+    let hub = StubHubClient::new(&env, &hub_addr);
+
+    let admin = Address::generate(&env);
+    let game_addr = env.register(TestGame, (&admin, &hub_addr));
+    let game = TestGameClient::new(&env, &game_addr);
+
+    (env, game, hub, admin)
+}
+
+// ============================================================================
+// Admin Tests
+// ============================================================================
+
+#[test]
+fn test_init_stores_admin_and_hub() {
+    let (env, game, hub, admin) = setup_test();
+
+    assert_eq!(game.get_admin(), admin);
+    assert_eq!(game.get_hub(), hub.address);
+
+    // Stored under the same keys the games used before the shared crate
+    env.as_contract(&game.address, || {
+        assert!(env.storage().instance().has(&CommonKey::Admin));
+        assert_eq!(crate::hub_address(&env), Some(hub.address.clone()));
+    });
+}
+
+#[test]
+fn test_set_admin_and_hub() {
+    let (env, game, _hub, admin) = setup_test();
+
+    let new_admin = Address::generate(&env);
+    game.set_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(game.get_admin(), new_admin);
+
+    let new_hub = Address::generate(&env);
+    game.set_hub(&new_hub);
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(game.get_hub(), new_hub);
+}
+
+#[test]
+#[should_panic]
+fn test_set_hub_requires_admin() {
+    let (env, game, _hub, _admin) = setup_test();
+    env.set_auths(&[]);
+
+    game.set_hub(&Address::generate(&env));
+}
+
+#[test]
+#[should_panic]
+fn test_upgrade_requires_admin() {
+    let (env, game, _hub, _admin) = setup_test();
+    env.set_auths(&[]);
+
+    game.upgrade(&BytesN::from_array(&env, &[0u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "GameHub address not set")]
+fn test_get_hub_without_hub_panics() {
+    let env = Env::default();
+    let game_addr = env.register(
+        TestGame,
+        (&Address::generate(&env), &Address::generate(&env)),
+    );
+
+    env.as_contract(&game_addr, || {
+        env.storage().instance().remove(&CommonKey::GameHubAddress);
+        assert_eq!(crate::hub_address(&env), None);
+        crate::get_hub(&env);
+    });
+}
+
+// ============================================================================
+// Game Hub Tests
+// ============================================================================
+
+#[test]
+fn test_hub_calls_are_made_as_the_game() {
+    let (env, game, hub, _admin) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    assert_eq!(game.open(&player1, &player2, &100), 1);
+    assert_eq!(game.open(&player1, &player2, &100), 2);
+
+    let start = hub.last_start().unwrap();
+    assert_eq!(start.game_id, game.address);
+    assert_eq!(start.session_id, 2);
+    assert_eq!(start.player1, player1);
+    assert_eq!(start.player2, player2);
+    assert_eq!(start.player1_points, 100);
+    assert_eq!(start.player2_points, 100);
+
+    game.close(&2, &true);
+    assert_eq!(hub.last_end(), Some((2, true)));
+
+    game.settle(&1, &GameOutcome::Draw);
+    assert_eq!(hub.last_end_v2(), Some((1, GameOutcome::Draw)));
+}
+
+#[test]
+fn test_hub_calls_follow_set_hub() {
+    let (env, game, old_hub, _admin) = setup_test();
+    let new_hub = StubHubClient::new(&env, &env.register(StubHub, ()));
+    game.set_hub(&new_hub.address);
+
+    game.close(&7, &false);
+
+    assert_eq!(new_hub.last_end(), Some((7, false)));
+    assert_eq!(old_hub.last_end(), None);
+}
+
+// ============================================================================
+// TTL Tests
+// ============================================================================
+
+#[test]
+fn test_extend_helpers_apply_game_ttl() {
+    let (env, game, _hub, _admin) = setup_test();
+
+    game.touch(&1);
+
+    env.as_contract(&game.address, || {
+        assert_eq!(env.storage().temporary().get_ttl(&1u32), GAME_TTL_LEDGERS);
+        assert_eq!(env.storage().persistent().get_ttl(&1u32), GAME_TTL_LEDGERS);
+    });
+}
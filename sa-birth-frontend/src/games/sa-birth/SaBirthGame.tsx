@@ -270,6 +270,7 @@ export function SaBirthGame({
     score:   number,
     points:  number,
     time_ms: number,
+    hazards: number,
   ) => {
     if (mazeCompleteInFlight.current) return;
     mazeCompleteInFlight.current = true;
@@ -314,6 +315,7 @@ export function SaBirthGame({
             mazeId,
             BigInt(points),
             BigInt(time_ms),
+            hazards,
             BigInt(score),
             proof!.proofHex,
             signer
@@ -542,8 +544,8 @@ export function SaBirthGame({
         <Maze
           sense={currentMaze}
           character={character}
-          onComplete={(score, points, time_ms) =>
-            handleMazeComplete(currentMaze, score, points, time_ms)
+          onComplete={(score, points, time_ms, hazards) =>
+            handleMazeComplete(currentMaze, score, points, time_ms, hazards)
           }
           onBack={() => {
             mazeCompleteInFlight.current = false;
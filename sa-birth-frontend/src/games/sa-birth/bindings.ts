@@ -50,15 +50,50 @@ export const Errors = {
   9: {message:"VerificationFailed"},
   10: {message:"SessionNotActive"},
   11: {message:"HubCallFailed"},
-  12: {message:"OverloadExceeded"}
+  12: {message:"OverloadExceeded"},
+  13: {message:"ProofRequired"},
+  14: {message:"MazeCommitmentMismatch"},
+  15: {message:"SessionKeyNotSet"},
+  16: {message:"InvalidNonce"},
+  17: {message:"CooldownActive"},
+  18: {message:"SessionQuotaExceeded"},
+  19: {message:"InvalidTier"},
+  20: {message:"InvalidStake"},
+  21: {message:"DeadlineNotReached"},
+  22: {message:"DeadlinePassed"},
+  23: {message:"InvalidDeadline"},
+  24: {message:"AchievementTableFull"},
+  25: {message:"InvalidAchievement"},
+  26: {message:"SenseLocked"},
+  27: {message:"PrerequisiteCycle"},
+  28: {message:"RetriesExhausted"},
+  29: {message:"GhostNotPublished"}
 }
 
 /**
- * DataKey enum mirrors the SGS template pattern so the setup script's
- * `__constructor` call works without modification.
+ * A player's active calibration session.
+ * Stored in instance storage, keyed by player address.
  */
-export type DataKey = {tag: "Session", values: readonly [string]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "SessionCounter", values: void} | {tag: "Leaderboard", values: void} | {tag: "SenseResult", values: readonly [string, u32]};
-
+export interface CalibrationSession {
+  active: boolean;
+  character: u32;
+  completed_senses: u32;
+  ghost: Option<u32>;
+  next_nonce: u64;
+  payout_bps: u32;
+  player: string;
+  player1_points: i128;
+  player2: string;
+  player2_points: i128;
+  proven_senses: u32;
+  retry_penalty: u64;
+  score_cap: u64;
+  sense_failures: Map<u32, u32>;
+  session_id: u32;
+  session_key: Option<Buffer>;
+  tier: u32;
+  total_score: u64;
+}
 
 
 /**
@@ -66,13 +101,119 @@ export type DataKey = {tag: "Session", values: readonly [string]} | {tag: "GameH
  * Note: Proofs are emitted in events for off-chain verification but not stored.
  */
 export interface SenseResult {
+  character: u32;
+  hazards: u32;
+  maze_root: Buffer;
   points: u64;
+  proof_digest: Buffer;
   score: u64;
   sense_id: u32;
   time_ms: u64;
 }
 
 
+/**
+ * Off-chain payload a player signs with their session key so that any
+ * relayer can submit the sense on their behalf (see `submit_sense_signed`).
+ * The signed message is the XDR encoding of this struct. It names the
+ * contract and player so a signature can't be replayed on another
+ * deployment or on another player's session with the same id.
+ */
+export interface SignedSensePayload {
+  contract: string;
+  hazards: u32;
+  maze_id: u32;
+  maze_root: Buffer;
+  nonce: u64;
+  player: string;
+  points: u64;
+  proof_digest: Buffer;
+  score: u64;
+  sense_id: u32;
+  session_id: u32;
+  time_ms: u64;
+}
+
+
+/**
+ * Public inputs of the aggregated whole-run circuit.
+ * `sense_scores` is indexed by sense_id and must hold exactly 6 entries.
+ */
+export interface RunPublicInputs {
+  character: u32;
+  score_cap: u64;
+  sense_scores: Array<u64>;
+  total_score: u64;
+}
+
+
+/**
+ * Aggregated proof accepted at exit, kept for off-chain auditability.
+ * Only the keccak256 digest of the proof bytes is stored.
+ */
+export interface RunProof {
+  inputs: RunPublicInputs;
+  proof_digest: Buffer;
+  session_id: u32;
+}
+
+
+/**
+ * Rules for one difficulty tier, set by the admin.
+ * 
+ * The house stake must equal `player1_points × payout_bps / 10_000`, so a
+ * winning player takes home their stake plus that multiple of it.
+ */
+export interface TierConfig {
+  min_stake: i128;
+  payout_bps: u32;
+  score_cap: u64;
+}
+
+
+/**
+ * How failed senses may be retried, set by the admin.
+ * 
+ * Each sense may be failed and retried `max_retries` times, each retry
+ * adding `penalty` to the run's total_score. One more failure locks the
+ * sense for the rest of the run. The default (0, 0) keeps senses one-shot.
+ */
+export interface RetryPolicy {
+  max_retries: u32;
+  penalty: u64;
+}
+
+
+/**
+ * Per-player rate limits on `start_game`, set by the admin.
+ * A zero field disables that limit; all zeros (the default) means unlimited.
+ */
+export interface RateLimits {
+  max_sessions: u32;
+  min_ledger_gap: u32;
+  window_ledgers: u32;
+}
+
+
+/**
+ * Recent session starts for one player, used to enforce `RateLimits`.
+ */
+export interface StartHistory {
+  last_start_ledger: u32;
+  recent_starts: Array<u32>;
+}
+
+
+/**
+ * Snapshot returned by `get_limits_status`.
+ */
+export interface LimitsStatus {
+  can_start: boolean;
+  current_ledger: u32;
+  max_sessions: u32;
+  next_allowed_ledger: u32;
+  sessions_in_window: u32;
+}
 
 
 /**
@@ -81,107 +222,415 @@ export interface SenseResult {
 export interface LeaderboardEntry {
   character: u32;
   player: string;
+  retries: u32;
+  retry_penalty: u64;
+  session_id: u32;
+  tier: u32;
   timestamp: u64;
   total_score: u64;
 }
 
 
 /**
- * A player's active calibration session.
- * Stored in instance storage, keyed by player address.
+ * Soulbound record of a player's embodiment as one character.
+ * 
+ * Issued on the first successful exit and updated when a later run beats
+ * `best_score`. There is no transfer or burn entry point.
  */
-export interface CalibrationSession {
-  active: boolean;
+export interface EmbodimentCredential {
+  best_score: u64;
   character: u32;
-  completed_senses: u32;
+  earned_at: u64;
+  embodiments: u32;
+  first_earned_at: u64;
   player: string;
-  player1_points: i128;
-  player2: string;
-  player2_points: i128;
+  proof_digest: Buffer;
   session_id: u32;
+  tier: u32;
+}
+
+
+/**
+ * Condition for one achievement. The achievement's id is its index in the
+ * table, which is also its bit in the player's bitset.
+ */
+export type AchievementRule = {tag: "Retired", values: void} | {tag: "SenseUnder", values: readonly [u32, u64]} | {tag: "AllSensesUnder", values: readonly [u64]} | {tag: "SwiftRun", values: readonly [u32, u64]} | {tag: "Embodied", values: readonly [u32]} | {tag: "AllCharactersEmbodied", values: void} | {tag: "Streak", values: readonly [u32]} | {tag: "NoHazards", values: readonly [u32]};
+
+
+/**
+ * A player's achievement state.
+ */
+export interface PlayerAchievements {
+  streak: u32;
+  unlocked: u128;
+}
+
+
+/**
+ * Snapshot of a successful run that others can challenge.
+ * `sense_scores` is indexed by sense_id.
+ */
+export interface GhostRun {
+  character: u32;
+  owner: string;
+  sense_scores: Array<u64>;
+  session_id: u32;
+  tier: u32;
   total_score: u64;
 }
 
 
+/**
+ * Running counters for one `(character, sense_id)` maze.
+ * 
+ * `attempts` counts every time the maze was played to an outcome: an accepted
+ * submission, or a run that closed without completing it. Means are derived
+ * from the sums in `get_stats`.
+ */
+export interface SenseStats {
+  attempts: u32;
+  completions: u32;
+  max_score: u64;
+  min_score: u64;
+  score_sum: u64;
+  time_sum_ms: u64;
+}
+
+
+/**
+ * Run-level counters. `exits` counts successful embodiments only.
+ */
+export interface RunStats {
+  challenges_lost: u32;
+  exits: u32;
+  incompletes: u32;
+  overloads: u32;
+  sessions_started: u32;
+}
+
+
+/**
+ * One maze's statistics as reported by `get_stats`.
+ */
+export interface SenseStatsView {
+  attempts: u32;
+  character: u32;
+  completions: u32;
+  max_score: u64;
+  mean_score: u64;
+  mean_time_ms: u64;
+  min_score: u64;
+  sense_id: u32;
+}
+
+
+/**
+ * Aggregate metrics returned by `get_stats`.
+ */
+export interface CalibrationStats {
+  run: RunStats;
+  senses: Array<SenseStatsView>;
+}
+
+
+/**
+ * A head-to-head race between two staking players.
+ * 
+ * Each player's progress is tracked in their own lane, a `CalibrationSession`
+ * whose `player2` is the opponent. Both lanes share the race's session_id
+ * and character, and so the same maze roots.
+ */
+export interface Race {
+  character: u32;
+  deadline_ledger: u32;
+  player1: CalibrationSession;
+  player2: CalibrationSession;
+  session_id: u32;
+  settled: boolean;
+}
+
+
+/**
+ * Result of `exit_practice`: what the run would have earned if staked.
+ * `projected_rank` is the 1-based leaderboard position a ranked run with
+ * this score would take today, `None` when it would not have qualified.
+ */
+export interface PracticeOutcome {
+  projected_rank: Option<u32>;
+  success: boolean;
+  total_score: u64;
+}
+
+
+/**
+ * Optional filters for `get_leaderboard_filtered`. Timestamps are inclusive.
+ */
+export interface LeaderboardFilter {
+  character: Option<u32>;
+  from_timestamp: Option<u64>;
+  tier: Option<u32>;
+  to_timestamp: Option<u64>;
+}
+
+
+/**
+ * Contract storage keys. The admin and Game Hub addresses live under
+ * `sgs_common::CommonKey`.
+ */
+export type DataKey = {tag: "Session", values: readonly [string]} | {tag: "Leaderboard", values: void} | {tag: "SenseResult", values: readonly [string, u32]} | {tag: "RunProof", values: readonly [string]} | {tag: "MazeRoot", values: readonly [u32, u32]} | {tag: "RateLimits", values: void} | {tag: "StartHistory", values: readonly [string]} | {tag: "RunStats", values: void} | {tag: "SenseStats", values: readonly [u32, u32]} | {tag: "PracticeSession", values: readonly [string]} | {tag: "PracticeResult", values: readonly [string, u32]} | {tag: "TierConfig", values: readonly [u32]} | {tag: "Race", values: readonly [u32]} | {tag: "Ghost", values: readonly [u32]} | {tag: "GhostStakeCap", values: readonly [u32]} | {tag: "Credential", values: readonly [string, u32]} | {tag: "Registry", values: void} | {tag: "Achievements", values: void} | {tag: "PlayerAchievements", values: readonly [string]} | {tag: "SensePrerequisites", values: readonly [u32]} | {tag: "RetryPolicy", values: void} | {tag: "SchemaVersion", values: void};
+
+
+/**
+ * Outcome passed to `end_game_v2` (matches mock-game-hub's `GameOutcome`).
+ */
+export type GameOutcome = {tag: "Player1", values: void} | {tag: "Player2", values: void} | {tag: "Draw", values: void} | {tag: "Cancelled", values: void};
+
+
 export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a calibration session.
+   * 
+   * Called by the frontend when Player 1 wants to begin a new game.
+   * Player 2 is the system/house wallet — only Player 1 needs to authorize
+   * since they're committing their own stake. The Hub locks both players' points.
+   * 
+   * After calling this, the frontend should call `set_character()` to record
+   * the player's chosen character (ALICE/ROBERT/CAROL).
+   * 
+   * Subject to the admin's `RateLimits`: fails with `CooldownActive` or
+   * `SessionQuotaExceeded` (see `get_limits_status`).
+   * 
+   * Plays the Standard tier; see `start_game_with_tier` for the others.
+   * 
+   * Returns Ok(()) on success.
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a start_game_with_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a calibration session in a difficulty tier
+   * (0=Standard, 1=Novice, 2=Overclock).
+   * 
+   * The tier's `TierConfig` fixes the stakes: player1_points must be at
+   * least `min_stake` and player2_points must equal
+   * `player1_points × payout_bps / 10_000`, otherwise `InvalidStake`.
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  start_game_with_tier: ({session_id, player1, player2, player1_points, player2_points, tier}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, tier: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a start_game_reserved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a calibration session like `start_game_with_tier`, with the
+   * session_id issued by the hub's `reserve_session` instead of chosen by
+   * the frontend. Player 1 authorizes the stake and tier only.
+   * 
+   * Returns the issued session_id.
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  start_game_reserved: ({player1, player2, player1_points, player2_points, tier}: {player1: string, player2: string, player1_points: i128, player2_points: i128, tier: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current (or last) calibration session for a player.
+   * Construct and simulate a publish_ghost transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer a ghost run up for challenges. Must be called by the ghost's owner.
+   * 
+   * Challengers may stake at most `max_stake`, which the owner matches as
+   * Player 2. Publish again to change the cap; a cap of 0 only admits
+   * unstaked challenges. Fails with `GameNotFound` for an unknown ghost.
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<CalibrationSession>>>
+  publish_ghost: ({session_id, max_stake}: {session_id: u32, max_stake: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a start_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a session that challenges a published ghost run.
+   * 
+   * `ghost_session_id` is the `session_id` of a leaderboard entry. The
+   * ghost's owner takes the house's place as Player 2 and matches the
+   * challenger's stake; the run uses the ghost's character and tier. The
+   * challenger wins only by completing all six senses, each with a lower
+   * score than the ghost recorded, otherwise the owner is paid.
+   * 
+   * The owner must have published the ghost (see `publish_ghost`), else
+   * `GhostNotPublished`; a stake above their cap fails with `InvalidStake`.
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  start_challenge: ({session_id, challenger, ghost_session_id, challenger_points}: {session_id: u32, challenger: string, ghost_session_id: u32, challenger_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_character transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Record the player's chosen character (ALICE/ROBERT/CAROL).
+   * 
+   * Must be called after `start_game()` and before any maze attempts; once a
+   * sense is submitted or failed the character is locked.
+   * Returns the session_id for the active session.
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_character: ({player, character}: {player: string, character: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a calibration session.
+   * Construct and simulate a submit_sense_completion transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit the result of one maze sense.
    * 
-   * Called by the frontend when Player 1 wants to begin a new game.
-   * Player 2 is the system/house wallet — only Player 1 needs to authorize
-   * since they're committing their own stake. The Hub locks both players' points.
+   * On-chain validation mirrors the ZK circuit constraints:
+   * • score == points × time_ms
+   * • maze_id == (tier << 16) | (character << 8) | sense_id
+   * • maze_root == committed root of the maze (see `get_maze_root`)
+   * • time_ms includes HAZARD_PENALTY_MS for each of the `hazards` hit
    * 
-   * After calling this, the frontend should call `set_character()` to record
-   * the player's chosen character (ALICE/ROBERT/CAROL).
+   * Senses with unmet prerequisites fail with `SenseLocked`; see
+   * `get_available_senses`.
    * 
-   * Returns Ok(()) on success.
+   * `proof_hex` may be empty when the player intends to cover the run with a
+   * single aggregated proof at `attempt_exit_with_proof`. Senses submitted
+   * with a proof are marked in `proven_senses`; only those unlock
+   * `SenseUnder` achievements.
+   * 
+   * Full Barretenberg UltraHonk verification can be wired in here once a
+   * Soroban-native verifier is available; for now proofs are stored for
+   * off-chain auditability.
+   * 
+   * `sense_id`: 0=hearing, 1=smell, 2=taste, 3=touch, 4=sight, 5=proprioception
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  submit_sense_completion: ({player, sense_id, maze_id, maze_root, points, time_ms, hazards, score, proof_hex}: {player: string, sense_id: u32, maze_id: u32, maze_root: Buffer, points: u64, time_ms: u64, hazards: u32, score: u64, proof_hex: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get calibration session by player address (preferred over get_game).
+   * Construct and simulate a fail_sense transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Record that the player failed a sense (the frontend's SenseFailure).
+   * 
+   * Within the admin's `RetryPolicy` the sense may be attempted again and
+   * `penalty` is added to total_score. Once the retries are used up, the
+   * next failure locks the sense, so the run can no longer complete.
+   * 
+   * Returns whether the sense can still be retried.
    */
-  get_session: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<CalibrationSession>>>
+  fail_sense: ({player, sense_id}: {player: string, sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a register_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register the ed25519 public key that signs relayed submissions for the
+   * player's active session. Re-registering replaces the key; the nonce
+   * sequence carries on so old signatures stay unusable.
+   */
+  register_session_key: ({player, public_key}: {player: string, public_key: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_sense_signed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Relayer entry point: submit a sense on behalf of `player` without their
+   * transaction signature.
+   * 
+   * The payload must be signed with the session key registered through
+   * `register_session_key`, name this contract and `player`, target the
+   * player's active session, carry the
+   * session's next nonce and commit to `proof_hex` via `proof_digest`. An
+   * invalid signature traps in the host. The sense is then validated exactly
+   * like `submit_sense_completion`.
+   */
+  submit_sense_signed: ({player, payload, signature, proof_hex}: {player: string, payload: SignedSensePayload, signature: Buffer, proof_hex: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a attempt_exit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Attempt to exit calibration (complete embodiment).
    * 
-   * Success requires all 6 senses completed AND total_score ≤ SCORE_CAP.
+   * Success requires all 6 senses completed AND total_score ≤ the tier cap.
    * Regardless of outcome, closes the session and calls hub.end_game().
    * 
+   * A winning run must have a proof for every sense; runs whose senses were
+   * submitted without proofs must exit via `attempt_exit_with_proof`.
+   * 
    * Returns (success: bool, total_score: u64) tuple.
    * success=true means player won; success=false means overload or incomplete.
    */
   attempt_exit: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [boolean, u64]>>>
 
   /**
-   * Construct and simulate a set_character transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Record the player's chosen character (ALICE/ROBERT/CAROL).
+   * Construct and simulate a attempt_exit_with_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Exit calibration with one aggregated proof covering all six senses.
    * 
-   * Must be called after `start_game()` and before any maze attempts.
-   * Returns the session_id for the active session.
+   * The public inputs must match what the contract recorded for the run:
+   * • sense_scores[i] == stored score of sense i (all 6 completed)
+   * • total_score == Σ sense_scores == session.total_score − retry penalties
+   * • score_cap == the session's cap (its tier's cap when it started)
+   * • character == session.character
+   * 
+   * As with per-sense proofs, the proof itself is checked client-side; the
+   * contract stores its digest so the run can be audited later.
    */
-  set_character: ({player, character}: {player: string, character: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+  attempt_exit_with_proof: ({player, inputs, proof_hex}: {player: string, inputs: RunPublicInputs, proof_hex: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [boolean, u64]>>>
 
   /**
-   * Construct and simulate a get_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get leaderboard sorted by total_score ascending (lower = better).
+   * Construct and simulate a start_practice transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start an unstaked practice run with the given character.
+   * 
+   * Practice runs never call the hub, are not rate limited and never touch
+   * the leaderboard or statistics. They use their own session and result
+   * keys, so a practice run can sit alongside a ranked one. Starting again
+   * discards the previous practice run. Its session_id is always 0, which
+   * seeds the derived maze roots.
    */
-  get_leaderboard: (options?: MethodOptions) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>
+  start_practice: ({player, character}: {player: string, character: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_practice_sense transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit one maze sense for the player's practice run.
+   * 
+   * Validated exactly like `submit_sense_completion`, against the practice
+   * session's maze roots (see `get_practice_maze_root`).
+   */
+  submit_practice_sense: ({player, sense_id, maze_id, maze_root, points, time_ms, hazards, score, proof_hex}: {player: string, sense_id: u32, maze_id: u32, maze_root: Buffer, points: u64, time_ms: u64, hazards: u32, score: u64, proof_hex: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a exit_practice transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close the practice run and report what it would have earned.
+   * 
+   * `success` follows the Standard-tier rule (all 6 senses, total_score ≤ SCORE_CAP)
+   * but no proofs are required. A successful run reports the rank it would
+   * take on the current leaderboard.
+   */
+  exit_practice: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<PracticeOutcome>>>
+
+  /**
+   * Construct and simulate a start_race transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open a head-to-head race between two human players.
+   * 
+   * Both players authorize their own stake, which the hub locks as usual.
+   * Senses are accepted for `duration_ledgers` ledgers. Races are played
+   * in the Standard tier and don't feed the leaderboard or statistics.
+   * 
+   * Subject to the admin's `RateLimits` for both players, like `start_game`.
+   */
+  start_race: ({session_id, player1, player2, player1_points, player2_points, character, duration_ledgers}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, character: u32, duration_ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_race_sense transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit one maze sense for the player's lane of a race.
+   * 
+   * Validated like `submit_sense_completion`, hazard penalties included,
+   * against the race's maze roots (see `get_race_maze_root`). Fails with
+   * `DeadlinePassed` once the race deadline has gone by.
+   */
+  submit_race_sense: ({player, session_id, sense_id, maze_id, maze_root, points, time_ms, hazards, score, proof_hex}: {player: string, session_id: u32, sense_id: u32, maze_id: u32, maze_root: Buffer, points: u64, time_ms: u64, hazards: u32, score: u64, proof_hex: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a settle_race transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a race through the hub. Callable by anyone.
+   * 
+   * Before the deadline both players must have completed all six senses.
+   * After the deadline the race settles as it stands. Either way more
+   * proven senses wins (so a player who never finishes forfeits), then the
+   * lower total score. A full tie settles as a `Draw` and a race without a
+   * single proven sense as `Cancelled`; both refund the stakes.
+   * 
+   * Returns the outcome reported to the hub.
+   */
+  settle_race: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameOutcome>>>
+
+  /**
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current (or last) calibration session for a player.
+   */
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<CalibrationSession>>>
+
+  /**
+   * Construct and simulate a get_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get calibration session by player address (preferred over get_game).
+   */
+  get_session: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<CalibrationSession>>>
 
   /**
    * Construct and simulate a get_sense_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -190,21 +639,292 @@ export interface Client {
   get_sense_result: ({player, sense_id}: {player: string, sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SenseResult>>>
 
   /**
-   * Construct and simulate a submit_sense_completion transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Submit the result of one maze sense.
+   * Construct and simulate a get_available_senses transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the senses the player can attempt next in their active session, as
+   * a bitfield like `completed_senses`. 0 without an active session.
+   */
+  get_available_senses: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_sense_prerequisites transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the mask of senses that must be completed before `sense_id`.
+   */
+  get_sense_prerequisites: ({sense_id}: {sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_run_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the last aggregated run proof accepted for a player.
+   */
+  get_run_proof: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<RunProof>>>
+
+  /**
+   * Construct and simulate a get_maze_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maze commitment a sense submission must match.
    * 
-   * On-chain validation mirrors the ZK circuit constraints:
-   * • score == points × time_ms
-   * • maze_id == (character << 8) | sense_id
-   * • proof_hex must be non-empty
+   * Returns the admin-published root when set, otherwise the root derived
+   * from the player's current session seed (which does not commit to the
+   * maze layout; see `set_maze_root`). `None` without a session.
+   */
+  get_maze_root: ({player, sense_id}: {player: string, sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a get_credential transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's embodiment credential for one character.
+   * Part of the stable `EmbodimentCredentials` interface.
+   */
+  get_credential: ({player, character}: {player: string, character: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<EmbodimentCredential>>>
+
+  /**
+   * Construct and simulate a has_credential transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether the player has ever embodied `character`.
+   * Part of the stable `EmbodimentCredentials` interface.
+   */
+  has_credential: ({player, character}: {player: string, character: u32}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_achievements transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the player's unlocked achievements as a bitset (bit i = id i).
+   */
+  get_achievements: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u128>>
+
+  /**
+   * Construct and simulate a get_streak transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the player's current streak of consecutive successful exits.
+   */
+  get_streak: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_achievement_table transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the achievement table; an achievement's id is its index.
+   */
+  get_achievement_table: (options?: MethodOptions) => Promise<AssembledTransaction<Array<AchievementRule>>>
+
+  /**
+   * Construct and simulate a get_ghost transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ghost left by a successful run, keyed by its session id.
+   */
+  get_ghost: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<GhostRun>>>
+
+  /**
+   * Construct and simulate a get_ghost_stake_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the largest stake a ghost's owner agreed to match, if published.
+   */
+  get_ghost_stake_cap: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<i128>>>
+
+  /**
+   * Construct and simulate a get_race transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a race by session id.
+   */
+  get_race: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Race>>>
+
+  /**
+   * Construct and simulate a get_race_maze_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maze commitment shared by both players of a race.
+   */
+  get_race_maze_root: ({session_id, sense_id}: {session_id: u32, sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a get_practice_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current (or last) practice run for a player.
+   */
+  get_practice_session: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<CalibrationSession>>>
+
+  /**
+   * Construct and simulate a get_practice_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a stored practice sense result for a player.
+   */
+  get_practice_result: ({player, sense_id}: {player: string, sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SenseResult>>>
+
+  /**
+   * Construct and simulate a get_practice_maze_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maze commitment a practice submission must match.
+   */
+  get_practice_maze_root: ({player, sense_id}: {player: string, sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a get_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Aggregate metrics for difficulty tuning: run-level counters plus one
+   * entry per `(character, sense_id)` maze (character-major order).
+   */
+  get_stats: (options?: MethodOptions) => Promise<AssembledTransaction<CalibrationStats>>
+
+  /**
+   * Construct and simulate a get_rate_limits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the rate limits currently applied to `start_game`.
+   */
+  get_rate_limits: (options?: MethodOptions) => Promise<AssembledTransaction<RateLimits>>
+
+  /**
+   * Construct and simulate a get_limits_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Show when `player` can start their next session and how much of the
+   * rolling-window quota they have used.
+   */
+  get_limits_status: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<LimitsStatus>>
+
+  /**
+   * Construct and simulate a get_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get leaderboard sorted by total_score ascending (lower = better).
+   * Only the best `MAX_LEADERBOARD_LEN` (100) runs are kept.
+   */
+  get_leaderboard: (options?: MethodOptions) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>
+
+  /**
+   * Construct and simulate a get_rank transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * 1-based leaderboard rank of the player's best run, if they have one.
+   */
+  get_rank: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a get_leaderboard_page transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get `limit` entries starting at 0-based `offset` (limit capped at 50).
+   */
+  get_leaderboard_page: ({offset, limit}: {offset: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>
+
+  /**
+   * Construct and simulate a get_leaderboard_around transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the player's best entry with up to `radius` entries either side
+   * (radius capped so the window fits one page). Empty if unranked.
+   */
+  get_leaderboard_around: ({player, radius}: {player: string, radius: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>
+
+  /**
+   * Construct and simulate a get_leaderboard_filtered transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the leaderboard restricted by character and/or timestamp
+   * range. `offset` and `limit` apply to the filtered ordering.
+   */
+  get_leaderboard_filtered: ({filter, offset, limit}: {filter: LeaderboardFilter, offset: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_tier_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the rules currently applied to a tier (`None` for unknown tiers).
+   */
+  get_tier_config: ({tier}: {tier: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<TierConfig>>>
+
+  /**
+   * Construct and simulate a set_tier_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Override a tier's cap, minimum stake and payout multiplier.
+   * Applies to sessions started afterwards; running sessions keep the cap
+   * and payout they started with.
+   */
+  set_tier_config: ({tier, config}: {tier: u32, config: TierConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the progression registry exits report to, if configured.
+   */
+  get_registry: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Point exits at a progression registry. The registry admin must also
+   * allowlist this contract as a writer.
+   */
+  set_registry: ({registry}: {registry: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a clear_registry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop reporting exits to the progression registry.
+   */
+  clear_registry: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a add_achievement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Append an achievement to the table and return its id.
+   * Existing unlocks are unaffected; the new rule applies from now on.
+   */
+  add_achievement: ({rule}: {rule: AchievementRule}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_achievement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the rule behind an existing achievement id
+   * (use `AchievementRule::Retired` to retire it).
+   */
+  set_achievement: ({achievement_id, rule}: {achievement_id: u32, rule: AchievementRule}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_sense_prerequisites transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the senses (as a bitfield) that must be completed before
+   * `sense_id` can be submitted; 0 removes the requirement. The graph must
+   * stay acyclic so every sense remains reachable.
+   */
+  set_sense_prerequisites: ({sense_id, prerequisites}: {sense_id: u32, prerequisites: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_retry_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the retry policy applied by `fail_sense`.
+   */
+  get_retry_policy: (options?: MethodOptions) => Promise<AssembledTransaction<RetryPolicy>>
+
+  /**
+   * Construct and simulate a set_retry_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Configure how many times a failed sense may be retried and the
+   * total_score penalty per retry.
+   */
+  set_retry_policy: ({policy}: {policy: RetryPolicy}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_rate_limits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Configure per-player rate limits for `start_game` (zero disables a limit).
+   */
+  set_rate_limits: ({limits}: {limits: RateLimits}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_maze_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Publish the Merkle root of a `(character, sense_id)` maze grid.
+   * Overrides the session-derived commitment for that maze.
+   */
+  set_maze_root: ({character, sense_id, root}: {character: u32, sense_id: u32, root: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a clear_maze_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove a published maze root, falling back to the session-derived one.
+   */
+  clear_maze_root: ({character, sense_id}: {character: u32, sense_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bring storage written by an older version up to `SCHEMA_VERSION`.
+   * Call once after `upgrade`; a no-op when already current.
    * 
-   * Full Barretenberg UltraHonk verification can be wired in here once a
-   * Soroban-native verifier is available; for now proofs are stored for
-   * off-chain auditability.
+   * Rewrites the leaderboard sorted by score, with the newer entry fields
+   * defaulted and capped at `MAX_LEADERBOARD_LEN`. Sessions and sense
+   * results are per player, so they are converted when next read instead.
    * 
-   * `sense_id`: 0=hearing, 1=smell, 2=taste, 3=touch, 4=sight, 5=proprioception
+   * Returns the schema version storage was at before the call.
+   */
+  migrate: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Storage layout version; below the contract's current version until
+   * `migrate` has run.
    */
-  submit_sense_completion: ({player, sense_id, maze_id, points, time_ms, score, proof_hex}: {player: string, sense_id: u32, maze_id: u32, points: u64, time_ms: u64, score: u64, proof_hex: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_schema_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
 }
 export class Client extends ContractClient {
@@ -226,45 +946,176 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAABRTZXNzaW9uQWxyZWFkeUFjdGl2ZQAAAAYAAAAAAAAAEEludmFsaWRDaGFyYWN0ZXIAAAAHAAAAAAAAAAxJbnZhbGlkU2Vuc2UAAAAIAAAAAAAAABJWZXJpZmljYXRpb25GYWlsZWQAAAAAAAkAAAAAAAAAEFNlc3Npb25Ob3RBY3RpdmUAAAAKAAAAAAAAAA1IdWJDYWxsRmFpbGVkAAAAAAAACwAAAAAAAAAQT3ZlcmxvYWRFeGNlZWRlZAAAAAw=",
-        "AAAAAgAAAHREYXRhS2V5IGVudW0gbWlycm9ycyB0aGUgU0dTIHRlbXBsYXRlIHBhdHRlcm4gc28gdGhlIHNldHVwIHNjcmlwdCdzCmBfX2NvbnN0cnVjdG9yYCBjYWxsIHdvcmtzIHdpdGhvdXQgbW9kaWZpY2F0aW9uLgAAAAAAAAAHRGF0YUtleQAAAAAGAAAAAQAAADxBY3RpdmUvY29tcGxldGVkIGNhbGlicmF0aW9uIHNlc3Npb24ga2V5ZWQgYnkgcGxheWVyIGFkZHJlc3MAAAAHU2Vzc2lvbgAAAAABAAAAEwAAAAAAAAAsQWRkcmVzcyBvZiB0aGUgZGVwbG95ZWQgTW9ja0dhbWVIdWIgY29udHJhY3QAAAAOR2FtZUh1YkFkZHJlc3MAAAAAAAAAAAAoQWRtaW4gYWRkcmVzcyAoY2FuIHVwZ3JhZGUgdGhlIGNvbnRyYWN0KQAAAAVBZG1pbgAAAAAAAAAAAAAhQXV0by1pbmNyZW1lbnRpbmcgc2Vzc2lvbiBjb3VudGVyAAAAAAAADlNlc3Npb25Db3VudGVyAAAAAAAAAAAAI0xlYWRlcmJvYXJkIChWZWM8TGVhZGVyYm9hcmRFbnRyeT4pAAAAAAtMZWFkZXJib2FyZAAAAAABAAAAOFBlci1wbGF5ZXIsIHBlci1zZW5zZSByZXN1bHQga2V5ZWQgYnkgKHBsYXllciwgc2Vuc2VfaWQpAAAAC1NlbnNlUmVzdWx0AAAAAAIAAAATAAAABA==",
-        "AAAABQAAAAAAAAAAAAAAClN0YXJ0RXZlbnQAAAAAAAEAAAAFc3RhcnQAAAAAAAACAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAC",
-        "AAAAAQAAAG9QZXItc2Vuc2UgcmVzdWx0IHN0b3JlZCBvbi1jaGFpbi4KTm90ZTogUHJvb2ZzIGFyZSBlbWl0dGVkIGluIGV2ZW50cyBmb3Igb2ZmLWNoYWluIHZlcmlmaWNhdGlvbiBidXQgbm90IHN0b3JlZC4AAAAAAAAAAAtTZW5zZVJlc3VsdAAAAAAEAAAAAAAAAAZwb2ludHMAAAAAAAYAAAAAAAAABXNjb3JlAAAAAAAABgAAAAAAAAAIc2Vuc2VfaWQAAAAEAAAAAAAAAAd0aW1lX21zAAAAAAY=",
+      new ContractSpec([ "AAAABQAAAAAAAAAAAAAAClN0YXJ0RXZlbnQAAAAAAAEAAAAFc3RhcnQAAAAAAAACAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE1NlbnNlQ29tcGxldGVkRXZlbnQAAAAAAQAAAA9zZW5zZV9jb21wbGV0ZWQAAAAABAAAAAAAAAAIc2Vuc2VfaWQAAAAEAAAAAAAAAAAAAAAFc2NvcmUAAAAAAAAGAAAAAAAAAAAAAAAGcG9pbnRzAAAAAAAGAAAAAAAAAAAAAAAHdGltZV9tcwAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEFNlbnNlRmFpbGVkRXZlbnQAAAABAAAADHNlbnNlX2ZhaWxlZAAAAAQAAAAAAAAACHNlbnNlX2lkAAAABAAAAAAAAAAAAAAACGZhaWx1cmVzAAAABAAAAAAAAAAAAAAAB3BlbmFsdHkAAAAABgAAAAAAAAAAAAAACWNhbl9yZXRyeQAAAAAAAAEAAAAAAAAAAg==",
         "AAAABQAAAAAAAAAAAAAADUNvbXBsZXRlRXZlbnQAAAAAAAABAAAACGNvbXBsZXRlAAAAAgAAAAAAAAAJY2hhcmFjdGVyAAAAAAAABAAAAAAAAAAAAAAAC3RvdGFsX3Njb3JlAAAAAAYAAAAAAAAAAg==",
         "AAAABQAAAAAAAAAAAAAADU92ZXJsb2FkRXZlbnQAAAAAAAABAAAACG92ZXJsb2FkAAAAAgAAAAAAAAAJY2hhcmFjdGVyAAAAAAAABAAAAAAAAAAAAAAAC3RvdGFsX3Njb3JlAAAAAAYAAAAAAAAAAg==",
-        "AAAAAQAAAElMZWFkZXJib2FyZCBlbnRyeSDigJQgb25seSBzdWNjZXNzZnVsIChzY29yZSDiiaQgY2FwKSBleGl0cyBhcmUgcmVjb3JkZWQuAAAAAAAAAAAAABBMZWFkZXJib2FyZEVudHJ5AAAABAAAAAAAAAAJY2hhcmFjdGVyAAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAt0b3RhbF9zY29yZQAAAAAG",
-        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAEFJhY2VTZXR0bGVkRXZlbnQAAAABAAAADHJhY2Vfc2V0dGxlZAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAAAAAAdvdXRjb21lAAAAB9AAAAALR2FtZU91dGNvbWUAAAAAAAAAAAAAAAANcGxheWVyMV9zY29yZQAAAAAAAAYAAAAAAAAAAAAAAA1wbGF5ZXIyX3Njb3JlAAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADkNoYWxsZW5nZUV2ZW50AAAAAAABAAAACWNoYWxsZW5nZQAAAAAAAAMAAAAAAAAAEGdob3N0X3Nlc3Npb25faWQAAAAEAAAAAAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAAAAAAOY2hhbGxlbmdlcl93b24AAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAD0NyZWRlbnRpYWxFdmVudAAAAAABAAAACmNyZWRlbnRpYWwAAAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAAAAAAAAAAAAApiZXN0X3Njb3JlAAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAGEFjaGlldmVtZW50VW5sb2NrZWRFdmVudAAAAAEAAAAUYWNoaWV2ZW1lbnRfdW5sb2NrZWQAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAAAAAAA5hY2hpZXZlbWVudF9pZAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFU1pbGVzdG9uZXNGYWlsZWRFdmVudAAAAAAAAAEAAAARbWlsZXN0b25lc19mYWlsZWQAAAAAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAAAAAAAhyZWdpc3RyeQAAABMAAAAAAAAAAAAAAAptaWxlc3RvbmVzAAAAAAPqAAAAEQAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADVJ1blByb29mRXZlbnQAAAAAAAABAAAACXJ1bl9wcm9vZgAAAAAAAAMAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAAAAAAAAAAAAAt0b3RhbF9zY29yZQAAAAAGAAAAAAAAAAAAAAAMcHJvb2ZfZGlnZXN0AAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAHQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAABRTZXNzaW9uQWxyZWFkeUFjdGl2ZQAAAAYAAAAAAAAAEEludmFsaWRDaGFyYWN0ZXIAAAAHAAAAAAAAAAxJbnZhbGlkU2Vuc2UAAAAIAAAAAAAAABJWZXJpZmljYXRpb25GYWlsZWQAAAAAAAkAAAAAAAAAEFNlc3Npb25Ob3RBY3RpdmUAAAAKAAAAAAAAAA1IdWJDYWxsRmFpbGVkAAAAAAAACwAAAAAAAAAQT3ZlcmxvYWRFeGNlZWRlZAAAAAwAAAAAAAAADVByb29mUmVxdWlyZWQAAAAAAAANAAAAAAAAABZNYXplQ29tbWl0bWVudE1pc21hdGNoAAAAAAAOAAAAAAAAABBTZXNzaW9uS2V5Tm90U2V0AAAADwAAAAAAAAAMSW52YWxpZE5vbmNlAAAAEAAAAAAAAAAOQ29vbGRvd25BY3RpdmUAAAAAABEAAAAAAAAAFFNlc3Npb25RdW90YUV4Y2VlZGVkAAAAEgAAAAAAAAALSW52YWxpZFRpZXIAAAAAEwAAAAAAAAAMSW52YWxpZFN0YWtlAAAAFAAAAAAAAAASRGVhZGxpbmVOb3RSZWFjaGVkAAAAAAAVAAAAAAAAAA5EZWFkbGluZVBhc3NlZAAAAAAAFgAAAAAAAAAPSW52YWxpZERlYWRsaW5lAAAAABcAAAAAAAAAFEFjaGlldmVtZW50VGFibGVGdWxsAAAAGAAAAAAAAAASSW52YWxpZEFjaGlldmVtZW50AAAAAAAZAAAAAAAAAAtTZW5zZUxvY2tlZAAAAAAaAAAAAAAAABFQcmVyZXF1aXNpdGVDeWNsZQAAAAAAABsAAAAAAAAAEFJldHJpZXNFeGhhdXN0ZWQAAAAcAAAAAAAAABFHaG9zdE5vdFB1Ymxpc2hlZAAAAAAAAB0=",
+        "AAAAAQAAAFtBIHBsYXllcidzIGFjdGl2ZSBjYWxpYnJhdGlvbiBzZXNzaW9uLgpTdG9yZWQgaW4gaW5zdGFuY2Ugc3RvcmFnZSwga2V5ZWQgYnkgcGxheWVyIGFkZHJlc3MuAAAAAAAAAAASQ2FsaWJyYXRpb25TZXNzaW9uAAAAAAASAAAAAAAAAAZhY3RpdmUAAAAAAAEAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAAAAAAAEGNvbXBsZXRlZF9zZW5zZXMAAAAEAAAAAAAAAAVnaG9zdAAAAAAAA+gAAAAEAAAAAAAAAApuZXh0X25vbmNlAAAAAAAGAAAAAAAAAApwYXlvdXRfYnBzAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAA1wcm92ZW5fc2Vuc2VzAAAAAAAABAAAAAAAAAANcmV0cnlfcGVuYWx0eQAAAAAAAAYAAAAAAAAACXNjb3JlX2NhcAAAAAAAAAYAAAAAAAAADnNlbnNlX2ZhaWx1cmVzAAAAAAPsAAAABAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAC3Nlc3Npb25fa2V5AAAAA+gAAAPuAAAAIAAAAAAAAAAEdGllcgAAAAQAAAAAAAAAC3RvdGFsX3Njb3JlAAAAAAY=",
+        "AAAAAQAAAG9QZXItc2Vuc2UgcmVzdWx0IHN0b3JlZCBvbi1jaGFpbi4KTm90ZTogUHJvb2ZzIGFyZSBlbWl0dGVkIGluIGV2ZW50cyBmb3Igb2ZmLWNoYWluIHZlcmlmaWNhdGlvbiBidXQgbm90IHN0b3JlZC4AAAAAAAAAAAtTZW5zZVJlc3VsdAAAAAAIAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAAAAAAdoYXphcmRzAAAAAAQAAAAAAAAACW1hemVfcm9vdAAAAAAAA+4AAAAgAAAAAAAAAAZwb2ludHMAAAAAAAYAAAAAAAAADHByb29mX2RpZ2VzdAAAA+4AAAAgAAAAAAAAAAVzY29yZQAAAAAAAAYAAAAAAAAACHNlbnNlX2lkAAAABAAAAAAAAAAHdGltZV9tcwAAAAAG",
+        "AAAAAQAAAU1PZmYtY2hhaW4gcGF5bG9hZCBhIHBsYXllciBzaWducyB3aXRoIHRoZWlyIHNlc3Npb24ga2V5IHNvIHRoYXQgYW55CnJlbGF5ZXIgY2FuIHN1Ym1pdCB0aGUgc2Vuc2Ugb24gdGhlaXIgYmVoYWxmIChzZWUgYHN1Ym1pdF9zZW5zZV9zaWduZWRgKS4KVGhlIHNpZ25lZCBtZXNzYWdlIGlzIHRoZSBYRFIgZW5jb2Rpbmcgb2YgdGhpcyBzdHJ1Y3QuIEl0IG5hbWVzIHRoZQpjb250cmFjdCBhbmQgcGxheWVyIHNvIGEgc2lnbmF0dXJlIGNhbid0IGJlIHJlcGxheWVkIG9uIGFub3RoZXIKZGVwbG95bWVudCBvciBvbiBhbm90aGVyIHBsYXllcidzIHNlc3Npb24gd2l0aCB0aGUgc2FtZSBpZC4AAAAAAAAAAAAAElNpZ25lZFNlbnNlUGF5bG9hZAAAAAAADAAAAAAAAAAIY29udHJhY3QAAAATAAAAAAAAAAdoYXphcmRzAAAAAAQAAAAAAAAAB21hemVfaWQAAAAABAAAAAAAAAAJbWF6ZV9yb290AAAAAAAD7gAAACAAAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAYAAAAAAAAADHByb29mX2RpZ2VzdAAAA+4AAAAgAAAAAAAAAAVzY29yZQAAAAAAAAYAAAAAAAAACHNlbnNlX2lkAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHdGltZV9tcwAAAAAG",
+        "AAAAAQAAAHlQdWJsaWMgaW5wdXRzIG9mIHRoZSBhZ2dyZWdhdGVkIHdob2xlLXJ1biBjaXJjdWl0Lgpgc2Vuc2Vfc2NvcmVzYCBpcyBpbmRleGVkIGJ5IHNlbnNlX2lkIGFuZCBtdXN0IGhvbGQgZXhhY3RseSA2IGVudHJpZXMuAAAAAAAAAAAAAA9SdW5QdWJsaWNJbnB1dHMAAAAABAAAAAAAAAAJY2hhcmFjdGVyAAAAAAAABAAAAAAAAAAJc2NvcmVfY2FwAAAAAAAABgAAAAAAAAAMc2Vuc2Vfc2NvcmVzAAAD6gAAAAYAAAAAAAAAC3RvdGFsX3Njb3JlAAAAAAY=",
+        "AAAAAQAAAHtBZ2dyZWdhdGVkIHByb29mIGFjY2VwdGVkIGF0IGV4aXQsIGtlcHQgZm9yIG9mZi1jaGFpbiBhdWRpdGFiaWxpdHkuCk9ubHkgdGhlIGtlY2NhazI1NiBkaWdlc3Qgb2YgdGhlIHByb29mIGJ5dGVzIGlzIHN0b3JlZC4AAAAAAAAAAAhSdW5Qcm9vZgAAAAMAAAAAAAAABmlucHV0cwAAAAAH0AAAAA9SdW5QdWJsaWNJbnB1dHMAAAAAAAAAAAxwcm9vZl9kaWdlc3QAAAPuAAAAIAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABA==",
+        "AAAAAQAAALpSdWxlcyBmb3Igb25lIGRpZmZpY3VsdHkgdGllciwgc2V0IGJ5IHRoZSBhZG1pbi4KClRoZSBob3VzZSBzdGFrZSBtdXN0IGVxdWFsIGBwbGF5ZXIxX3BvaW50cyDDlyBwYXlvdXRfYnBzIC8gMTBfMDAwYCwgc28gYQp3aW5uaW5nIHBsYXllciB0YWtlcyBob21lIHRoZWlyIHN0YWtlIHBsdXMgdGhhdCBtdWx0aXBsZSBvZiBpdC4AAAAAAAAAAAAKVGllckNvbmZpZwAAAAAAAwAAAAAAAAAJbWluX3N0YWtlAAAAAAAACwAAAAAAAAAKcGF5b3V0X2JwcwAAAAAABAAAAAAAAAAJc2NvcmVfY2FwAAAAAAAABg==",
+        "AAAAAQAAAQhIb3cgZmFpbGVkIHNlbnNlcyBtYXkgYmUgcmV0cmllZCwgc2V0IGJ5IHRoZSBhZG1pbi4KCkVhY2ggc2Vuc2UgbWF5IGJlIGZhaWxlZCBhbmQgcmV0cmllZCBgbWF4X3JldHJpZXNgIHRpbWVzLCBlYWNoIHJldHJ5CmFkZGluZyBgcGVuYWx0eWAgdG8gdGhlIHJ1bidzIHRvdGFsX3Njb3JlLiBPbmUgbW9yZSBmYWlsdXJlIGxvY2tzIHRoZQpzZW5zZSBmb3IgdGhlIHJlc3Qgb2YgdGhlIHJ1bi4gVGhlIGRlZmF1bHQgKDAsIDApIGtlZXBzIHNlbnNlcyBvbmUtc2hvdC4AAAAAAAAAC1JldHJ5UG9saWN5AAAAAAIAAAAAAAAAC21heF9yZXRyaWVzAAAAAAQAAAAAAAAAB3BlbmFsdHkAAAAABg==",
+        "AAAAAQAAAIRQZXItcGxheWVyIHJhdGUgbGltaXRzIG9uIGBzdGFydF9nYW1lYCwgc2V0IGJ5IHRoZSBhZG1pbi4KQSB6ZXJvIGZpZWxkIGRpc2FibGVzIHRoYXQgbGltaXQ7IGFsbCB6ZXJvcyAodGhlIGRlZmF1bHQpIG1lYW5zIHVubGltaXRlZC4AAAAAAAAAClJhdGVMaW1pdHMAAAAAAAMAAAAAAAAADG1heF9zZXNzaW9ucwAAAAQAAAAAAAAADm1pbl9sZWRnZXJfZ2FwAAAAAAAEAAAAAAAAAA53aW5kb3dfbGVkZ2VycwAAAAAABA==",
+        "AAAAAQAAAENSZWNlbnQgc2Vzc2lvbiBzdGFydHMgZm9yIG9uZSBwbGF5ZXIsIHVzZWQgdG8gZW5mb3JjZSBgUmF0ZUxpbWl0c2AuAAAAAAAAAAAMU3RhcnRIaXN0b3J5AAAAAgAAAAAAAAARbGFzdF9zdGFydF9sZWRnZXIAAAAAAAAEAAAAAAAAAA1yZWNlbnRfc3RhcnRzAAAAAAAD6gAAAAQ=",
+        "AAAAAQAAAClTbmFwc2hvdCByZXR1cm5lZCBieSBgZ2V0X2xpbWl0c19zdGF0dXNgLgAAAAAAAAAAAAAMTGltaXRzU3RhdHVzAAAABQAAAAAAAAAJY2FuX3N0YXJ0AAAAAAAAAQAAAAAAAAAOY3VycmVudF9sZWRnZXIAAAAAAAQAAAAAAAAADG1heF9zZXNzaW9ucwAAAAQAAAAAAAAAE25leHRfYWxsb3dlZF9sZWRnZXIAAAAABAAAAAAAAAASc2Vzc2lvbnNfaW5fd2luZG93AAAAAAAE",
+        "AAAAAQAAAElMZWFkZXJib2FyZCBlbnRyeSDigJQgb25seSBzdWNjZXNzZnVsIChzY29yZSDiiaQgY2FwKSBleGl0cyBhcmUgcmVjb3JkZWQuAAAAAAAAAAAAABBMZWFkZXJib2FyZEVudHJ5AAAACAAAAAAAAAAJY2hhcmFjdGVyAAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdyZXRyaWVzAAAAAAQAAAAAAAAADXJldHJ5X3BlbmFsdHkAAAAAAAAGAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAR0aWVyAAAABAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABgAAAAAAAAALdG90YWxfc2NvcmUAAAAABg==",
+        "AAAAAQAAALtTb3VsYm91bmQgcmVjb3JkIG9mIGEgcGxheWVyJ3MgZW1ib2RpbWVudCBhcyBvbmUgY2hhcmFjdGVyLgoKSXNzdWVkIG9uIHRoZSBmaXJzdCBzdWNjZXNzZnVsIGV4aXQgYW5kIHVwZGF0ZWQgd2hlbiBhIGxhdGVyIHJ1biBiZWF0cwpgYmVzdF9zY29yZWAuIFRoZXJlIGlzIG5vIHRyYW5zZmVyIG9yIGJ1cm4gZW50cnkgcG9pbnQuAAAAAAAAAAAURW1ib2RpbWVudENyZWRlbnRpYWwAAAAJAAAAAAAAAApiZXN0X3Njb3JlAAAAAAAGAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAAAAAAllYXJuZWRfYXQAAAAAAAAGAAAAAAAAAAtlbWJvZGltZW50cwAAAAAEAAAAAAAAAA9maXJzdF9lYXJuZWRfYXQAAAAABgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAxwcm9vZl9kaWdlc3QAAAPuAAAAIAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAEdGllcgAAAAQ=",
+        "AAAAAgAAAHxDb25kaXRpb24gZm9yIG9uZSBhY2hpZXZlbWVudC4gVGhlIGFjaGlldmVtZW50J3MgaWQgaXMgaXRzIGluZGV4IGluIHRoZQp0YWJsZSwgd2hpY2ggaXMgYWxzbyBpdHMgYml0IGluIHRoZSBwbGF5ZXIncyBiaXRzZXQuAAAAAAAAAA9BY2hpZXZlbWVudFJ1bGUAAAAACAAAAAAAAABBTmV2ZXIgdW5sb2NrczsgdXNlZCB0byByZXRpcmUgYW4gaWQgd2l0aG91dCByZW51bWJlcmluZyB0aGUgdGFibGUAAAAAAAAHUmV0aXJlZAAAAAABAAAARShzZW5zZV9pZCwgbWF4X3Njb3JlKTogYSBzaW5nbGUgc2Vuc2Ugc3VibWl0dGVkIGF0IG9yIHVuZGVyIG1heF9zY29yZQAAAAAAAApTZW5zZVVuZGVyAAAAAAACAAAABAAAAAYAAAABAAAAQFN1Y2Nlc3NmdWwgZXhpdCB3aXRoIGV2ZXJ5IHNlbnNlIHNjb3JlIGF0IG9yIHVuZGVyIHRoZSB0aHJlc2hvbGQAAAAOQWxsU2Vuc2VzVW5kZXIAAAAAAAEAAAAGAAAAAQAAAFsoY2hhcmFjdGVyLCBsaW1pdF9tcyk6IHN1Y2Nlc3NmdWwgZXhpdCBhcyBgY2hhcmFjdGVyYCB3aXRoIGV2ZXJ5IHNlbnNlCmZhc3RlciB0aGFuIGxpbWl0X21zAAAAAAhTd2lmdFJ1bgAAAAIAAAAEAAAABgAAAAEAAAAmU3VjY2Vzc2Z1bCBleGl0IGFzIHRoZSBnaXZlbiBjaGFyYWN0ZXIAAAAAAAhFbWJvZGllZAAAAAEAAAAEAAAAAAAAACZFdmVyeSBjaGFyYWN0ZXIgZW1ib2RpZWQgYXQgbGVhc3Qgb25jZQAAAAAAFUFsbENoYXJhY3RlcnNFbWJvZGllZAAAAAAAAAEAAAAmVGhpcyBtYW55IGNvbnNlY3V0aXZlIHN1Y2Nlc3NmdWwgZXhpdHMAAAAAAAZTdHJlYWsAAAAAAAEAAAAEAAAAAQAAAFBTdWNjZXNzZnVsIGV4aXQgYXMgdGhlIGdpdmVuIGNoYXJhY3RlciB3aXRoIGV2ZXJ5IHNlbnNlIHByb3ZlbiBhbmQKbm8gaGF6YXJkIGhpdAAAAAlOb0hhemFyZHMAAAAAAAABAAAABA==",
+        "AAAAAQAAAB1BIHBsYXllcidzIGFjaGlldmVtZW50IHN0YXRlLgAAAAAAAAAAAAASUGxheWVyQWNoaWV2ZW1lbnRzAAAAAAACAAAAAAAAAAZzdHJlYWsAAAAAAAQAAAAAAAAACHVubG9ja2VkAAAACg==",
+        "AAAAAQAAAF5TbmFwc2hvdCBvZiBhIHN1Y2Nlc3NmdWwgcnVuIHRoYXQgb3RoZXJzIGNhbiBjaGFsbGVuZ2UuCmBzZW5zZV9zY29yZXNgIGlzIGluZGV4ZWQgYnkgc2Vuc2VfaWQuAAAAAAAAAAAACEdob3N0UnVuAAAABgAAAAAAAAAJY2hhcmFjdGVyAAAAAAAABAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAxzZW5zZV9zY29yZXMAAAPqAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAEdGllcgAAAAQAAAAAAAAAC3RvdGFsX3Njb3JlAAAAAAY=",
+        "AAAAAQAAAOtSdW5uaW5nIGNvdW50ZXJzIGZvciBvbmUgYChjaGFyYWN0ZXIsIHNlbnNlX2lkKWAgbWF6ZS4KCmBhdHRlbXB0c2AgY291bnRzIGV2ZXJ5IHRpbWUgdGhlIG1hemUgd2FzIHBsYXllZCB0byBhbiBvdXRjb21lOiBhbiBhY2NlcHRlZApzdWJtaXNzaW9uLCBvciBhIHJ1biB0aGF0IGNsb3NlZCB3aXRob3V0IGNvbXBsZXRpbmcgaXQuIE1lYW5zIGFyZSBkZXJpdmVkCmZyb20gdGhlIHN1bXMgaW4gYGdldF9zdGF0c2AuAAAAAAAAAAAKU2Vuc2VTdGF0cwAAAAAABgAAAAAAAAAIYXR0ZW1wdHMAAAAEAAAAAAAAAAtjb21wbGV0aW9ucwAAAAAEAAAAAAAAAAltYXhfc2NvcmUAAAAAAAAGAAAAAAAAAAltaW5fc2NvcmUAAAAAAAAGAAAAAAAAAAlzY29yZV9zdW0AAAAAAAAGAAAAAAAAAAt0aW1lX3N1bV9tcwAAAAAG",
+        "AAAAAQAAAD9SdW4tbGV2ZWwgY291bnRlcnMuIGBleGl0c2AgY291bnRzIHN1Y2Nlc3NmdWwgZW1ib2RpbWVudHMgb25seS4AAAAAAAAAAAhSdW5TdGF0cwAAAAUAAAAAAAAAD2NoYWxsZW5nZXNfbG9zdAAAAAAEAAAAAAAAAAVleGl0cwAAAAAAAAQAAAAAAAAAC2luY29tcGxldGVzAAAAAAQAAAAAAAAACW92ZXJsb2FkcwAAAAAAAAQAAAAAAAAAEHNlc3Npb25zX3N0YXJ0ZWQAAAAE",
+        "AAAAAQAAADFPbmUgbWF6ZSdzIHN0YXRpc3RpY3MgYXMgcmVwb3J0ZWQgYnkgYGdldF9zdGF0c2AuAAAAAAAAAAAAAA5TZW5zZVN0YXRzVmlldwAAAAAACAAAAAAAAAAIYXR0ZW1wdHMAAAAEAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAAAAAAtjb21wbGV0aW9ucwAAAAAEAAAAAAAAAAltYXhfc2NvcmUAAAAAAAAGAAAAAAAAAAptZWFuX3Njb3JlAAAAAAAGAAAAAAAAAAxtZWFuX3RpbWVfbXMAAAAGAAAAAAAAAAltaW5fc2NvcmUAAAAAAAAGAAAAAAAAAAhzZW5zZV9pZAAAAAQ=",
+        "AAAAAQAAACpBZ2dyZWdhdGUgbWV0cmljcyByZXR1cm5lZCBieSBgZ2V0X3N0YXRzYC4AAAAAAAAAAAAQQ2FsaWJyYXRpb25TdGF0cwAAAAIAAAAAAAAAA3J1bgAAAAfQAAAACFJ1blN0YXRzAAAAAAAAAAZzZW5zZXMAAAAAA+oAAAfQAAAADlNlbnNlU3RhdHNWaWV3AAA=",
+        "AAAAAQAAAPBBIGhlYWQtdG8taGVhZCByYWNlIGJldHdlZW4gdHdvIHN0YWtpbmcgcGxheWVycy4KCkVhY2ggcGxheWVyJ3MgcHJvZ3Jlc3MgaXMgdHJhY2tlZCBpbiB0aGVpciBvd24gbGFuZSwgYSBgQ2FsaWJyYXRpb25TZXNzaW9uYAp3aG9zZSBgcGxheWVyMmAgaXMgdGhlIG9wcG9uZW50LiBCb3RoIGxhbmVzIHNoYXJlIHRoZSByYWNlJ3Mgc2Vzc2lvbl9pZAphbmQgY2hhcmFjdGVyLCBhbmQgc28gdGhlIHNhbWUgbWF6ZSByb290cy4AAAAAAAAABFJhY2UAAAAGAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAAAAAA9kZWFkbGluZV9sZWRnZXIAAAAABAAAAAAAAAAHcGxheWVyMQAAAAfQAAAAEkNhbGlicmF0aW9uU2Vzc2lvbgAAAAAAAAAAAAdwbGF5ZXIyAAAAB9AAAAASQ2FsaWJyYXRpb25TZXNzaW9uAAAAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3NldHRsZWQAAAAAAQ==",
+        "AAAAAQAAANFSZXN1bHQgb2YgYGV4aXRfcHJhY3RpY2VgOiB3aGF0IHRoZSBydW4gd291bGQgaGF2ZSBlYXJuZWQgaWYgc3Rha2VkLgpgcHJvamVjdGVkX3JhbmtgIGlzIHRoZSAxLWJhc2VkIGxlYWRlcmJvYXJkIHBvc2l0aW9uIGEgcmFua2VkIHJ1biB3aXRoCnRoaXMgc2NvcmUgd291bGQgdGFrZSB0b2RheSwgYE5vbmVgIHdoZW4gaXQgd291bGQgbm90IGhhdmUgcXVhbGlmaWVkLgAAAAAAAAAAAAAPUHJhY3RpY2VPdXRjb21lAAAAAAMAAAAAAAAADnByb2plY3RlZF9yYW5rAAAAAAPoAAAABAAAAAAAAAAHc3VjY2VzcwAAAAABAAAAAAAAAAt0b3RhbF9zY29yZQAAAAAG",
+        "AAAAAQAAAEpPcHRpb25hbCBmaWx0ZXJzIGZvciBgZ2V0X2xlYWRlcmJvYXJkX2ZpbHRlcmVkYC4gVGltZXN0YW1wcyBhcmUgaW5jbHVzaXZlLgAAAAAAAAAAABFMZWFkZXJib2FyZEZpbHRlcgAAAAAAAAQAAAAAAAAACWNoYXJhY3RlcgAAAAAAA+gAAAAEAAAAAAAAAA5mcm9tX3RpbWVzdGFtcAAAAAAD6AAAAAYAAAAAAAAABHRpZXIAAAPoAAAABAAAAAAAAAAMdG9fdGltZXN0YW1wAAAD6AAAAAY=",
+        "AAAAAgAAAFtDb250cmFjdCBzdG9yYWdlIGtleXMuIFRoZSBhZG1pbiBhbmQgR2FtZSBIdWIgYWRkcmVzc2VzIGxpdmUgdW5kZXIKYHNnc19jb21tb246OkNvbW1vbktleWAuAAAAAAAAAAAHRGF0YUtleQAAAAAWAAAAAQAAADxBY3RpdmUvY29tcGxldGVkIGNhbGlicmF0aW9uIHNlc3Npb24ga2V5ZWQgYnkgcGxheWVyIGFkZHJlc3MAAAAHU2Vzc2lvbgAAAAABAAAAEwAAAAAAAABETGVhZGVyYm9hcmQgKFZlYzxMZWFkZXJib2FyZEVudHJ5Piwgc29ydGVkIGJ5IHRvdGFsX3Njb3JlIGFzY2VuZGluZykAAAALTGVhZGVyYm9hcmQAAAAAAQAAADhQZXItcGxheWVyLCBwZXItc2Vuc2UgcmVzdWx0IGtleWVkIGJ5IChwbGF5ZXIsIHNlbnNlX2lkKQAAAAtTZW5zZVJlc3VsdAAAAAACAAAAEwAAAAQAAAABAAAAL0xhc3QgYWdncmVnYXRlZCBydW4gcHJvb2YgYWNjZXB0ZWQgZm9yIGEgcGxheWVyAAAAAAhSdW5Qcm9vZgAAAAEAAAATAAAAAQAAAD5BZG1pbi1wdWJsaXNoZWQgbWF6ZSBjb21taXRtZW50IGtleWVkIGJ5IChjaGFyYWN0ZXIsIHNlbnNlX2lkKQAAAAAACE1hemVSb290AAAAAgAAAAQAAAAEAAAAAAAAACNSYXRlIGxpbWl0cyBhcHBsaWVkIHRvIGBzdGFydF9nYW1lYAAAAAAKUmF0ZUxpbWl0cwAAAAAAAQAAAC1SZWNlbnQgc2Vzc2lvbiBzdGFydHMga2V5ZWQgYnkgcGxheWVyIGFkZHJlc3MAAAAAAAAMU3RhcnRIaXN0b3J5AAAAAQAAABMAAAAAAAAAHVJ1bi1sZXZlbCBjb3VudGVycyAoUnVuU3RhdHMpAAAAAAAACFJ1blN0YXRzAAAAAQAAADBQZXItbWF6ZSBjb3VudGVycyBrZXllZCBieSAoY2hhcmFjdGVyLCBzZW5zZV9pZCkAAAAKU2Vuc2VTdGF0cwAAAAAAAgAAAAQAAAAEAAAAAQAAADFVbnN0YWtlZCBwcmFjdGljZSBzZXNzaW9uIGtleWVkIGJ5IHBsYXllciBhZGRyZXNzAAAAAAAAD1ByYWN0aWNlU2Vzc2lvbgAAAAABAAAAEwAAAAEAAAAxUHJhY3RpY2Ugc2Vuc2UgcmVzdWx0IGtleWVkIGJ5IChwbGF5ZXIsIHNlbnNlX2lkKQAAAAAAAA5QcmFjdGljZVJlc3VsdAAAAAAAAgAAABMAAAAEAAAAAQAAAC1BZG1pbiBvdmVycmlkZSBvZiBhIHRpZXIncyBydWxlcyAoVGllckNvbmZpZykAAAAAAAAKVGllckNvbmZpZwAAAAAAAQAAAAQAAAABAAAAJUhlYWQtdG8taGVhZCByYWNlIGtleWVkIGJ5IHNlc3Npb25faWQAAAAAAAAEUmFjZQAAAAEAAAAEAAAAAQAAADtHaG9zdCBydW4gc25hcHNob3Qga2V5ZWQgYnkgdGhlIHNlc3Npb25faWQgdGhhdCBwcm9kdWNlZCBpdAAAAAAFR2hvc3QAAAAAAAABAAAABAAAAAEAAABDTGFyZ2VzdCBzdGFrZSB0aGUgZ2hvc3QncyBvd25lciBhZ3JlZWQgdG8gbWF0Y2gsIGtleWVkIGxpa2UgYEdob3N0YAAAAAANR2hvc3RTdGFrZUNhcAAAAAAAAAEAAAAEAAAAAQAAADJFbWJvZGltZW50IGNyZWRlbnRpYWwga2V5ZWQgYnkgKHBsYXllciwgY2hhcmFjdGVyKQAAAAAACkNyZWRlbnRpYWwAAAAAAAIAAAATAAAABAAAAAAAAAA9QWRkcmVzcyBvZiB0aGUgY3Jvc3MtZ2FtZSBwcm9ncmVzc2lvbiByZWdpc3RyeSwgaWYgY29uZmlndXJlZAAAAAAAAAhSZWdpc3RyeQAAAAAAAABEQWNoaWV2ZW1lbnQgdGFibGUgKFZlYzxBY2hpZXZlbWVudFJ1bGU+KTsgZGVmYXVsdHMgYXBwbHkgd2hpbGUgdW5zZXQAAAAMQWNoaWV2ZW1lbnRzAAAAAQAAAClBY2hpZXZlbWVudCBzdGF0ZSBrZXllZCBieSBwbGF5ZXIgYWRkcmVzcwAAAAAAABJQbGF5ZXJBY2hpZXZlbWVudHMAAAAAAAEAAAATAAAAAQAAAD1NYXNrIG9mIHNlbnNlcyB0aGF0IG11c3QgYmUgY29tcGxldGVkIGJlZm9yZSBzZW5zZV9pZCB1bmxvY2tzAAAAAAAAElNlbnNlUHJlcmVxdWlzaXRlcwAAAAAAAQAAAAQAAAAAAAAAHlJldHJ5IHBvbGljeSBmb3IgZmFpbGVkIHNlbnNlcwAAAAAAC1JldHJ5UG9saWN5AAAAAAAAAAA/U3RvcmFnZSBsYXlvdXQgdmVyc2lvbiAoU0NIRU1BX1ZFUlNJT04pOyB1bnNldCBiZWZvcmUgdmVyc2lvbiAxAAAAAA1TY2hlbWFWZXJzaW9uAAAA",
+        "AAAAAAAAAHVDYWxsZWQgb25jZSBieSB0aGUgU0dTIGRlcGxveSBzY3JpcHQ6CmBzdGVsbGFyIGNvbnRyYWN0IGludm9rZSAuLi4gLS0gX19jb25zdHJ1Y3RvciAtLWFkbWluIDxBRERSPiAtLWdhbWVfaHViIDxBRERSPmAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAA==",
+        "AAAAAAAAAkpTdGFydCBhIGNhbGlicmF0aW9uIHNlc3Npb24uCgpDYWxsZWQgYnkgdGhlIGZyb250ZW5kIHdoZW4gUGxheWVyIDEgd2FudHMgdG8gYmVnaW4gYSBuZXcgZ2FtZS4KUGxheWVyIDIgaXMgdGhlIHN5c3RlbS9ob3VzZSB3YWxsZXQg4oCUIG9ubHkgUGxheWVyIDEgbmVlZHMgdG8gYXV0aG9yaXplCnNpbmNlIHRoZXkncmUgY29tbWl0dGluZyB0aGVpciBvd24gc3Rha2UuIFRoZSBIdWIgbG9ja3MgYm90aCBwbGF5ZXJzJyBwb2ludHMuCgpBZnRlciBjYWxsaW5nIHRoaXMsIHRoZSBmcm9udGVuZCBzaG91bGQgY2FsbCBgc2V0X2NoYXJhY3RlcigpYCB0byByZWNvcmQKdGhlIHBsYXllcidzIGNob3NlbiBjaGFyYWN0ZXIgKEFMSUNFL1JPQkVSVC9DQVJPTCkuCgpTdWJqZWN0IHRvIHRoZSBhZG1pbidzIGBSYXRlTGltaXRzYDogZmFpbHMgd2l0aCBgQ29vbGRvd25BY3RpdmVgIG9yCmBTZXNzaW9uUXVvdGFFeGNlZWRlZGAgKHNlZSBgZ2V0X2xpbWl0c19zdGF0dXNgKS4KClBsYXlzIHRoZSBTdGFuZGFyZCB0aWVyOyBzZWUgYHN0YXJ0X2dhbWVfd2l0aF90aWVyYCBmb3IgdGhlIG90aGVycy4KClJldHVybnMgT2soKCkpIG9uIHN1Y2Nlc3MuAAAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAQ1TdGFydCBhIGNhbGlicmF0aW9uIHNlc3Npb24gaW4gYSBkaWZmaWN1bHR5IHRpZXIKKDA9U3RhbmRhcmQsIDE9Tm92aWNlLCAyPU92ZXJjbG9jaykuCgpUaGUgdGllcidzIGBUaWVyQ29uZmlnYCBmaXhlcyB0aGUgc3Rha2VzOiBwbGF5ZXIxX3BvaW50cyBtdXN0IGJlIGF0CmxlYXN0IGBtaW5fc3Rha2VgIGFuZCBwbGF5ZXIyX3BvaW50cyBtdXN0IGVxdWFsCmBwbGF5ZXIxX3BvaW50cyDDlyBwYXlvdXRfYnBzIC8gMTBfMDAwYCwgb3RoZXJ3aXNlIGBJbnZhbGlkU3Rha2VgLgAAAAAAABRzdGFydF9nYW1lX3dpdGhfdGllcgAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAABHRpZXIAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAOJTdGFydCBhIGNhbGlicmF0aW9uIHNlc3Npb24gbGlrZSBgc3RhcnRfZ2FtZV93aXRoX3RpZXJgLCB3aXRoIHRoZQpzZXNzaW9uX2lkIGlzc3VlZCBieSB0aGUgaHViJ3MgYHJlc2VydmVfc2Vzc2lvbmAgaW5zdGVhZCBvZiBjaG9zZW4gYnkKdGhlIGZyb250ZW5kLiBQbGF5ZXIgMSBhdXRob3JpemVzIHRoZSBzdGFrZSBhbmQgdGllciBvbmx5LgoKUmV0dXJucyB0aGUgaXNzdWVkIHNlc3Npb25faWQuAAAAAAATc3RhcnRfZ2FtZV9yZXNlcnZlZAAAAAAFAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAR0aWVyAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAARdPZmZlciBhIGdob3N0IHJ1biB1cCBmb3IgY2hhbGxlbmdlcy4gTXVzdCBiZSBjYWxsZWQgYnkgdGhlIGdob3N0J3Mgb3duZXIuCgpDaGFsbGVuZ2VycyBtYXkgc3Rha2UgYXQgbW9zdCBgbWF4X3N0YWtlYCwgd2hpY2ggdGhlIG93bmVyIG1hdGNoZXMgYXMKUGxheWVyIDIuIFB1Ymxpc2ggYWdhaW4gdG8gY2hhbmdlIHRoZSBjYXA7IGEgY2FwIG9mIDAgb25seSBhZG1pdHMKdW5zdGFrZWQgY2hhbGxlbmdlcy4gRmFpbHMgd2l0aCBgR2FtZU5vdEZvdW5kYCBmb3IgYW4gdW5rbm93biBnaG9zdC4AAAAADXB1Ymxpc2hfZ2hvc3QAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAltYXhfc3Rha2UAAAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAg9TdGFydCBhIHNlc3Npb24gdGhhdCBjaGFsbGVuZ2VzIGEgcHVibGlzaGVkIGdob3N0IHJ1bi4KCmBnaG9zdF9zZXNzaW9uX2lkYCBpcyB0aGUgYHNlc3Npb25faWRgIG9mIGEgbGVhZGVyYm9hcmQgZW50cnkuIFRoZQpnaG9zdCdzIG93bmVyIHRha2VzIHRoZSBob3VzZSdzIHBsYWNlIGFzIFBsYXllciAyIGFuZCBtYXRjaGVzIHRoZQpjaGFsbGVuZ2VyJ3Mgc3Rha2U7IHRoZSBydW4gdXNlcyB0aGUgZ2hvc3QncyBjaGFyYWN0ZXIgYW5kIHRpZXIuIFRoZQpjaGFsbGVuZ2VyIHdpbnMgb25seSBieSBjb21wbGV0aW5nIGFsbCBzaXggc2Vuc2VzLCBlYWNoIHdpdGggYSBsb3dlcgpzY29yZSB0aGFuIHRoZSBnaG9zdCByZWNvcmRlZCwgb3RoZXJ3aXNlIHRoZSBvd25lciBpcyBwYWlkLgoKVGhlIG93bmVyIG11c3QgaGF2ZSBwdWJsaXNoZWQgdGhlIGdob3N0IChzZWUgYHB1Ymxpc2hfZ2hvc3RgKSwgZWxzZQpgR2hvc3ROb3RQdWJsaXNoZWRgOyBhIHN0YWtlIGFib3ZlIHRoZWlyIGNhcCBmYWlscyB3aXRoIGBJbnZhbGlkU3Rha2VgLgAAAAAPc3RhcnRfY2hhbGxlbmdlAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAAEGdob3N0X3Nlc3Npb25faWQAAAAEAAAAAAAAABFjaGFsbGVuZ2VyX3BvaW50cwAAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAOlSZWNvcmQgdGhlIHBsYXllcidzIGNob3NlbiBjaGFyYWN0ZXIgKEFMSUNFL1JPQkVSVC9DQVJPTCkuCgpNdXN0IGJlIGNhbGxlZCBhZnRlciBgc3RhcnRfZ2FtZSgpYCBhbmQgYmVmb3JlIGFueSBtYXplIGF0dGVtcHRzOyBvbmNlIGEKc2Vuc2UgaXMgc3VibWl0dGVkIG9yIGZhaWxlZCB0aGUgY2hhcmFjdGVyIGlzIGxvY2tlZC4KUmV0dXJucyB0aGUgc2Vzc2lvbl9pZCBmb3IgdGhlIGFjdGl2ZSBzZXNzaW9uLgAAAAAAAA1zZXRfY2hhcmFjdGVyAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAA2xTdWJtaXQgdGhlIHJlc3VsdCBvZiBvbmUgbWF6ZSBzZW5zZS4KCk9uLWNoYWluIHZhbGlkYXRpb24gbWlycm9ycyB0aGUgWksgY2lyY3VpdCBjb25zdHJhaW50czoK4oCiIHNjb3JlID09IHBvaW50cyDDlyB0aW1lX21zCuKAoiBtYXplX2lkID09ICh0aWVyIDw8IDE2KSB8IChjaGFyYWN0ZXIgPDwgOCkgfCBzZW5zZV9pZArigKIgbWF6ZV9yb290ID09IGNvbW1pdHRlZCByb290IG9mIHRoZSBtYXplIChzZWUgYGdldF9tYXplX3Jvb3RgKQrigKIgdGltZV9tcyBpbmNsdWRlcyBIQVpBUkRfUEVOQUxUWV9NUyBmb3IgZWFjaCBvZiB0aGUgYGhhemFyZHNgIGhpdAoKU2Vuc2VzIHdpdGggdW5tZXQgcHJlcmVxdWlzaXRlcyBmYWlsIHdpdGggYFNlbnNlTG9ja2VkYDsgc2VlCmBnZXRfYXZhaWxhYmxlX3NlbnNlc2AuCgpgcHJvb2ZfaGV4YCBtYXkgYmUgZW1wdHkgd2hlbiB0aGUgcGxheWVyIGludGVuZHMgdG8gY292ZXIgdGhlIHJ1biB3aXRoIGEKc2luZ2xlIGFnZ3JlZ2F0ZWQgcHJvb2YgYXQgYGF0dGVtcHRfZXhpdF93aXRoX3Byb29mYC4gU2Vuc2VzIHN1Ym1pdHRlZAp3aXRoIGEgcHJvb2YgYXJlIG1hcmtlZCBpbiBgcHJvdmVuX3NlbnNlc2A7IG9ubHkgdGhvc2UgdW5sb2NrCmBTZW5zZVVuZGVyYCBhY2hpZXZlbWVudHMuCgpGdWxsIEJhcnJldGVuYmVyZyBVbHRyYUhvbmsgdmVyaWZpY2F0aW9uIGNhbiBiZSB3aXJlZCBpbiBoZXJlIG9uY2UgYQpTb3JvYmFuLW5hdGl2ZSB2ZXJpZmllciBpcyBhdmFpbGFibGU7IGZvciBub3cgcHJvb2ZzIGFyZSBzdG9yZWQgZm9yCm9mZi1jaGFpbiBhdWRpdGFiaWxpdHkuCgpgc2Vuc2VfaWRgOiAwPWhlYXJpbmcsIDE9c21lbGwsIDI9dGFzdGUsIDM9dG91Y2gsIDQ9c2lnaHQsIDU9cHJvcHJpb2NlcHRpb24AAAAXc3VibWl0X3NlbnNlX2NvbXBsZXRpb24AAAAACQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhzZW5zZV9pZAAAAAQAAAAAAAAAB21hemVfaWQAAAAABAAAAAAAAAAJbWF6ZV9yb290AAAAAAAD7gAAACAAAAAAAAAABnBvaW50cwAAAAAABgAAAAAAAAAHdGltZV9tcwAAAAAGAAAAAAAAAAdoYXphcmRzAAAAAAQAAAAAAAAABXNjb3JlAAAAAAAABgAAAAAAAAAJcHJvb2ZfaGV4AAAAAAAADgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAUJSZWNvcmQgdGhhdCB0aGUgcGxheWVyIGZhaWxlZCBhIHNlbnNlICh0aGUgZnJvbnRlbmQncyBTZW5zZUZhaWx1cmUpLgoKV2l0aGluIHRoZSBhZG1pbidzIGBSZXRyeVBvbGljeWAgdGhlIHNlbnNlIG1heSBiZSBhdHRlbXB0ZWQgYWdhaW4gYW5kCmBwZW5hbHR5YCBpcyBhZGRlZCB0byB0b3RhbF9zY29yZS4gT25jZSB0aGUgcmV0cmllcyBhcmUgdXNlZCB1cCwgdGhlCm5leHQgZmFpbHVyZSBsb2NrcyB0aGUgc2Vuc2UsIHNvIHRoZSBydW4gY2FuIG5vIGxvbmdlciBjb21wbGV0ZS4KClJldHVybnMgd2hldGhlciB0aGUgc2Vuc2UgY2FuIHN0aWxsIGJlIHJldHJpZWQuAAAAAAAKZmFpbF9zZW5zZQAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhzZW5zZV9pZAAAAAQAAAABAAAD6QAAAAEAAAAD",
+        "AAAAAAAAAL9SZWdpc3RlciB0aGUgZWQyNTUxOSBwdWJsaWMga2V5IHRoYXQgc2lnbnMgcmVsYXllZCBzdWJtaXNzaW9ucyBmb3IgdGhlCnBsYXllcidzIGFjdGl2ZSBzZXNzaW9uLiBSZS1yZWdpc3RlcmluZyByZXBsYWNlcyB0aGUga2V5OyB0aGUgbm9uY2UKc2VxdWVuY2UgY2FycmllcyBvbiBzbyBvbGQgc2lnbmF0dXJlcyBzdGF5IHVudXNhYmxlLgAAAAAUcmVnaXN0ZXJfc2Vzc2lvbl9rZXkAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAbhSZWxheWVyIGVudHJ5IHBvaW50OiBzdWJtaXQgYSBzZW5zZSBvbiBiZWhhbGYgb2YgYHBsYXllcmAgd2l0aG91dCB0aGVpcgp0cmFuc2FjdGlvbiBzaWduYXR1cmUuCgpUaGUgcGF5bG9hZCBtdXN0IGJlIHNpZ25lZCB3aXRoIHRoZSBzZXNzaW9uIGtleSByZWdpc3RlcmVkIHRocm91Z2gKYHJlZ2lzdGVyX3Nlc3Npb25fa2V5YCwgbmFtZSB0aGlzIGNvbnRyYWN0IGFuZCBgcGxheWVyYCwgdGFyZ2V0IHRoZQpwbGF5ZXIncyBhY3RpdmUgc2Vzc2lvbiwgY2FycnkgdGhlCnNlc3Npb24ncyBuZXh0IG5vbmNlIGFuZCBjb21taXQgdG8gYHByb29mX2hleGAgdmlhIGBwcm9vZl9kaWdlc3RgLiBBbgppbnZhbGlkIHNpZ25hdHVyZSB0cmFwcyBpbiB0aGUgaG9zdC4gVGhlIHNlbnNlIGlzIHRoZW4gdmFsaWRhdGVkIGV4YWN0bHkKbGlrZSBgc3VibWl0X3NlbnNlX2NvbXBsZXRpb25gLgAAABNzdWJtaXRfc2Vuc2Vfc2lnbmVkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAHcGF5bG9hZAAAAAfQAAAAElNpZ25lZFNlbnNlUGF5bG9hZAAAAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAAAAAAJcHJvb2ZfaGV4AAAAAAAADgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAclBdHRlbXB0IHRvIGV4aXQgY2FsaWJyYXRpb24gKGNvbXBsZXRlIGVtYm9kaW1lbnQpLgoKU3VjY2VzcyByZXF1aXJlcyBhbGwgNiBzZW5zZXMgY29tcGxldGVkIEFORCB0b3RhbF9zY29yZSDiiaQgdGhlIHRpZXIgY2FwLgpSZWdhcmRsZXNzIG9mIG91dGNvbWUsIGNsb3NlcyB0aGUgc2Vzc2lvbiBhbmQgY2FsbHMgaHViLmVuZF9nYW1lKCkuCgpBIHdpbm5pbmcgcnVuIG11c3QgaGF2ZSBhIHByb29mIGZvciBldmVyeSBzZW5zZTsgcnVucyB3aG9zZSBzZW5zZXMgd2VyZQpzdWJtaXR0ZWQgd2l0aG91dCBwcm9vZnMgbXVzdCBleGl0IHZpYSBgYXR0ZW1wdF9leGl0X3dpdGhfcHJvb2ZgLgoKUmV0dXJucyAoc3VjY2VzczogYm9vbCwgdG90YWxfc2NvcmU6IHU2NCkgdHVwbGUuCnN1Y2Nlc3M9dHJ1ZSBtZWFucyBwbGF5ZXIgd29uOyBzdWNjZXNzPWZhbHNlIG1lYW5zIG92ZXJsb2FkIG9yIGluY29tcGxldGUuAAAAAAAADGF0dGVtcHRfZXhpdAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAIAAAABAAAABgAAAAM=",
+        "AAAAAAAAAgNFeGl0IGNhbGlicmF0aW9uIHdpdGggb25lIGFnZ3JlZ2F0ZWQgcHJvb2YgY292ZXJpbmcgYWxsIHNpeCBzZW5zZXMuCgpUaGUgcHVibGljIGlucHV0cyBtdXN0IG1hdGNoIHdoYXQgdGhlIGNvbnRyYWN0IHJlY29yZGVkIGZvciB0aGUgcnVuOgrigKIgc2Vuc2Vfc2NvcmVzW2ldID09IHN0b3JlZCBzY29yZSBvZiBzZW5zZSBpIChhbGwgNiBjb21wbGV0ZWQpCuKAoiB0b3RhbF9zY29yZSA9PSDOoyBzZW5zZV9zY29yZXMgPT0gc2Vzc2lvbi50b3RhbF9zY29yZSDiiJIgcmV0cnkgcGVuYWx0aWVzCuKAoiBzY29yZV9jYXAgPT0gdGhlIHNlc3Npb24ncyBjYXAgKGl0cyB0aWVyJ3MgY2FwIHdoZW4gaXQgc3RhcnRlZCkK4oCiIGNoYXJhY3RlciA9PSBzZXNzaW9uLmNoYXJhY3RlcgoKQXMgd2l0aCBwZXItc2Vuc2UgcHJvb2ZzLCB0aGUgcHJvb2YgaXRzZWxmIGlzIGNoZWNrZWQgY2xpZW50LXNpZGU7IHRoZQpjb250cmFjdCBzdG9yZXMgaXRzIGRpZ2VzdCBzbyB0aGUgcnVuIGNhbiBiZSBhdWRpdGVkIGxhdGVyLgAAAAAXYXR0ZW1wdF9leGl0X3dpdGhfcHJvb2YAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZpbnB1dHMAAAAAB9AAAAAPUnVuUHVibGljSW5wdXRzAAAAAAAAAAAJcHJvb2ZfaGV4AAAAAAAADgAAAAEAAAPpAAAD7QAAAAIAAAABAAAABgAAAAM=",
+        "AAAAAAAAAXBTdGFydCBhbiB1bnN0YWtlZCBwcmFjdGljZSBydW4gd2l0aCB0aGUgZ2l2ZW4gY2hhcmFjdGVyLgoKUHJhY3RpY2UgcnVucyBuZXZlciBjYWxsIHRoZSBodWIsIGFyZSBub3QgcmF0ZSBsaW1pdGVkIGFuZCBuZXZlciB0b3VjaAp0aGUgbGVhZGVyYm9hcmQgb3Igc3RhdGlzdGljcy4gVGhleSB1c2UgdGhlaXIgb3duIHNlc3Npb24gYW5kIHJlc3VsdAprZXlzLCBzbyBhIHByYWN0aWNlIHJ1biBjYW4gc2l0IGFsb25nc2lkZSBhIHJhbmtlZCBvbmUuIFN0YXJ0aW5nIGFnYWluCmRpc2NhcmRzIHRoZSBwcmV2aW91cyBwcmFjdGljZSBydW4uIEl0cyBzZXNzaW9uX2lkIGlzIGFsd2F5cyAwLCB3aGljaApzZWVkcyB0aGUgZGVyaXZlZCBtYXplIHJvb3RzLgAAAA5zdGFydF9wcmFjdGljZQAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAALFTdWJtaXQgb25lIG1hemUgc2Vuc2UgZm9yIHRoZSBwbGF5ZXIncyBwcmFjdGljZSBydW4uCgpWYWxpZGF0ZWQgZXhhY3RseSBsaWtlIGBzdWJtaXRfc2Vuc2VfY29tcGxldGlvbmAsIGFnYWluc3QgdGhlIHByYWN0aWNlCnNlc3Npb24ncyBtYXplIHJvb3RzIChzZWUgYGdldF9wcmFjdGljZV9tYXplX3Jvb3RgKS4AAAAAAAAVc3VibWl0X3ByYWN0aWNlX3NlbnNlAAAAAAAACQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhzZW5zZV9pZAAAAAQAAAAAAAAAB21hemVfaWQAAAAABAAAAAAAAAAJbWF6ZV9yb290AAAAAAAD7gAAACAAAAAAAAAABnBvaW50cwAAAAAABgAAAAAAAAAHdGltZV9tcwAAAAAGAAAAAAAAAAdoYXphcmRzAAAAAAQAAAAAAAAABXNjb3JlAAAAAAAABgAAAAAAAAAJcHJvb2ZfaGV4AAAAAAAADgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAPhDbG9zZSB0aGUgcHJhY3RpY2UgcnVuIGFuZCByZXBvcnQgd2hhdCBpdCB3b3VsZCBoYXZlIGVhcm5lZC4KCmBzdWNjZXNzYCBmb2xsb3dzIHRoZSBTdGFuZGFyZC10aWVyIHJ1bGUgKGFsbCA2IHNlbnNlcywgdG90YWxfc2NvcmUg4omkIFNDT1JFX0NBUCkKYnV0IG5vIHByb29mcyBhcmUgcmVxdWlyZWQuIEEgc3VjY2Vzc2Z1bCBydW4gcmVwb3J0cyB0aGUgcmFuayBpdCB3b3VsZAp0YWtlIG9uIHRoZSBjdXJyZW50IGxlYWRlcmJvYXJkLgAAAA1leGl0X3ByYWN0aWNlAAAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAfQAAAAD1ByYWN0aWNlT3V0Y29tZQAAAAAD",
+        "AAAAAAAAAUxPcGVuIGEgaGVhZC10by1oZWFkIHJhY2UgYmV0d2VlbiB0d28gaHVtYW4gcGxheWVycy4KCkJvdGggcGxheWVycyBhdXRob3JpemUgdGhlaXIgb3duIHN0YWtlLCB3aGljaCB0aGUgaHViIGxvY2tzIGFzIHVzdWFsLgpTZW5zZXMgYXJlIGFjY2VwdGVkIGZvciBgZHVyYXRpb25fbGVkZ2Vyc2AgbGVkZ2Vycy4gUmFjZXMgYXJlIHBsYXllZAppbiB0aGUgU3RhbmRhcmQgdGllciBhbmQgZG9uJ3QgZmVlZCB0aGUgbGVhZGVyYm9hcmQgb3Igc3RhdGlzdGljcy4KClN1YmplY3QgdG8gdGhlIGFkbWluJ3MgYFJhdGVMaW1pdHNgIGZvciBib3RoIHBsYXllcnMsIGxpa2UgYHN0YXJ0X2dhbWVgLgAAAApzdGFydF9yYWNlAAAAAAAHAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAAAAABBkdXJhdGlvbl9sZWRnZXJzAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAPZTdWJtaXQgb25lIG1hemUgc2Vuc2UgZm9yIHRoZSBwbGF5ZXIncyBsYW5lIG9mIGEgcmFjZS4KClZhbGlkYXRlZCBsaWtlIGBzdWJtaXRfc2Vuc2VfY29tcGxldGlvbmAsIGhhemFyZCBwZW5hbHRpZXMgaW5jbHVkZWQsCmFnYWluc3QgdGhlIHJhY2UncyBtYXplIHJvb3RzIChzZWUgYGdldF9yYWNlX21hemVfcm9vdGApLiBGYWlscyB3aXRoCmBEZWFkbGluZVBhc3NlZGAgb25jZSB0aGUgcmFjZSBkZWFkbGluZSBoYXMgZ29uZSBieS4AAAAAABFzdWJtaXRfcmFjZV9zZW5zZQAAAAAAAAoAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAIc2Vuc2VfaWQAAAAEAAAAAAAAAAdtYXplX2lkAAAAAAQAAAAAAAAACW1hemVfcm9vdAAAAAAAA+4AAAAgAAAAAAAAAAZwb2ludHMAAAAAAAYAAAAAAAAAB3RpbWVfbXMAAAAABgAAAAAAAAAHaGF6YXJkcwAAAAAEAAAAAAAAAAVzY29yZQAAAAAAAAYAAAAAAAAACXByb29mX2hleAAAAAAAAA4AAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAa5TZXR0bGUgYSByYWNlIHRocm91Z2ggdGhlIGh1Yi4gQ2FsbGFibGUgYnkgYW55b25lLgoKQmVmb3JlIHRoZSBkZWFkbGluZSBib3RoIHBsYXllcnMgbXVzdCBoYXZlIGNvbXBsZXRlZCBhbGwgc2l4IHNlbnNlcy4KQWZ0ZXIgdGhlIGRlYWRsaW5lIHRoZSByYWNlIHNldHRsZXMgYXMgaXQgc3RhbmRzLiBFaXRoZXIgd2F5IG1vcmUKcHJvdmVuIHNlbnNlcyB3aW5zIChzbyBhIHBsYXllciB3aG8gbmV2ZXIgZmluaXNoZXMgZm9yZmVpdHMpLCB0aGVuIHRoZQpsb3dlciB0b3RhbCBzY29yZS4gQSBmdWxsIHRpZSBzZXR0bGVzIGFzIGEgYERyYXdgIGFuZCBhIHJhY2Ugd2l0aG91dCBhCnNpbmdsZSBwcm92ZW4gc2Vuc2UgYXMgYENhbmNlbGxlZGA7IGJvdGggcmVmdW5kIHRoZSBzdGFrZXMuCgpSZXR1cm5zIHRoZSBvdXRjb21lIHJlcG9ydGVkIHRvIHRoZSBodWIuAAAAAAALc2V0dGxlX3JhY2UAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAAtHYW1lT3V0Y29tZQAAAAAD",
         "AAAAAAAAADdHZXQgY3VycmVudCAob3IgbGFzdCkgY2FsaWJyYXRpb24gc2Vzc2lvbiBmb3IgYSBwbGF5ZXIuAAAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAASQ2FsaWJyYXRpb25TZXNzaW9uAAA=",
-        "AAAAAQAAAFtBIHBsYXllcidzIGFjdGl2ZSBjYWxpYnJhdGlvbiBzZXNzaW9uLgpTdG9yZWQgaW4gaW5zdGFuY2Ugc3RvcmFnZSwga2V5ZWQgYnkgcGxheWVyIGFkZHJlc3MuAAAAAAAAAAASQ2FsaWJyYXRpb25TZXNzaW9uAAAAAAAJAAAAAAAAAAZhY3RpdmUAAAAAAAEAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAAAAAAAEGNvbXBsZXRlZF9zZW5zZXMAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAt0b3RhbF9zY29yZQAAAAAG",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAY5TdGFydCBhIGNhbGlicmF0aW9uIHNlc3Npb24uCgpDYWxsZWQgYnkgdGhlIGZyb250ZW5kIHdoZW4gUGxheWVyIDEgd2FudHMgdG8gYmVnaW4gYSBuZXcgZ2FtZS4KUGxheWVyIDIgaXMgdGhlIHN5c3RlbS9ob3VzZSB3YWxsZXQg4oCUIG9ubHkgUGxheWVyIDEgbmVlZHMgdG8gYXV0aG9yaXplCnNpbmNlIHRoZXkncmUgY29tbWl0dGluZyB0aGVpciBvd24gc3Rha2UuIFRoZSBIdWIgbG9ja3MgYm90aCBwbGF5ZXJzJyBwb2ludHMuCgpBZnRlciBjYWxsaW5nIHRoaXMsIHRoZSBmcm9udGVuZCBzaG91bGQgY2FsbCBgc2V0X2NoYXJhY3RlcigpYCB0byByZWNvcmQKdGhlIHBsYXllcidzIGNob3NlbiBjaGFyYWN0ZXIgKEFMSUNFL1JPQkVSVC9DQVJPTCkuCgpSZXR1cm5zIE9rKCgpKSBvbiBzdWNjZXNzLgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
-        "AAAABQAAAAAAAAAAAAAAE1NlbnNlQ29tcGxldGVkRXZlbnQAAAAAAQAAAA9zZW5zZV9jb21wbGV0ZWQAAAAABAAAAAAAAAAIc2Vuc2VfaWQAAAAEAAAAAAAAAAAAAAAFc2NvcmUAAAAAAAAGAAAAAAAAAAAAAAAGcG9pbnRzAAAAAAAGAAAAAAAAAAAAAAAHdGltZV9tcwAAAAAGAAAAAAAAAAI=",
         "AAAAAAAAAERHZXQgY2FsaWJyYXRpb24gc2Vzc2lvbiBieSBwbGF5ZXIgYWRkcmVzcyAocHJlZmVycmVkIG92ZXIgZ2V0X2dhbWUpLgAAAAtnZXRfc2Vzc2lvbgAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6AAAB9AAAAASQ2FsaWJyYXRpb25TZXNzaW9uAAA=",
-        "AAAAAAAAATtBdHRlbXB0IHRvIGV4aXQgY2FsaWJyYXRpb24gKGNvbXBsZXRlIGVtYm9kaW1lbnQpLgoKU3VjY2VzcyByZXF1aXJlcyBhbGwgNiBzZW5zZXMgY29tcGxldGVkIEFORCB0b3RhbF9zY29yZSDiiaQgU0NPUkVfQ0FQLgpSZWdhcmRsZXNzIG9mIG91dGNvbWUsIGNsb3NlcyB0aGUgc2Vzc2lvbiBhbmQgY2FsbHMgaHViLmVuZF9nYW1lKCkuCgpSZXR1cm5zIChzdWNjZXNzOiBib29sLCB0b3RhbF9zY29yZTogdTY0KSB0dXBsZS4Kc3VjY2Vzcz10cnVlIG1lYW5zIHBsYXllciB3b247IHN1Y2Nlc3M9ZmFsc2UgbWVhbnMgb3ZlcmxvYWQgb3IgaW5jb21wbGV0ZS4AAAAADGF0dGVtcHRfZXhpdAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAIAAAABAAAABgAAAAM=",
-        "AAAAAAAAAHVDYWxsZWQgb25jZSBieSB0aGUgU0dTIGRlcGxveSBzY3JpcHQ6CmBzdGVsbGFyIGNvbnRyYWN0IGludm9rZSAuLi4gLS0gX19jb25zdHJ1Y3RvciAtLWFkbWluIDxBRERSPiAtLWdhbWVfaHViIDxBRERSPmAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAA==",
-        "AAAAAAAAAKxSZWNvcmQgdGhlIHBsYXllcidzIGNob3NlbiBjaGFyYWN0ZXIgKEFMSUNFL1JPQkVSVC9DQVJPTCkuCgpNdXN0IGJlIGNhbGxlZCBhZnRlciBgc3RhcnRfZ2FtZSgpYCBhbmQgYmVmb3JlIGFueSBtYXplIGF0dGVtcHRzLgpSZXR1cm5zIHRoZSBzZXNzaW9uX2lkIGZvciB0aGUgYWN0aXZlIHNlc3Npb24uAAAADXNldF9jaGFyYWN0ZXIAAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
-        "AAAAAAAAAEFHZXQgbGVhZGVyYm9hcmQgc29ydGVkIGJ5IHRvdGFsX3Njb3JlIGFzY2VuZGluZyAobG93ZXIgPSBiZXR0ZXIpLgAAAAAAAA9nZXRfbGVhZGVyYm9hcmQAAAAAAAAAAAEAAAPqAAAH0AAAABBMZWFkZXJib2FyZEVudHJ5",
         "AAAAAAAAADxHZXQgc3RvcmVkIHNlbnNlIHJlc3VsdCBmb3IgYSBwbGF5ZXIgKGluY2x1ZGVzIHByb29mIGJ5dGVzKS4AAAAQZ2V0X3NlbnNlX3Jlc3VsdAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIc2Vuc2VfaWQAAAAEAAAAAQAAA+gAAAfQAAAAC1NlbnNlUmVzdWx0AA==",
-        "AAAAAAAAAbZTdWJtaXQgdGhlIHJlc3VsdCBvZiBvbmUgbWF6ZSBzZW5zZS4KCk9uLWNoYWluIHZhbGlkYXRpb24gbWlycm9ycyB0aGUgWksgY2lyY3VpdCBjb25zdHJhaW50czoK4oCiIHNjb3JlID09IHBvaW50cyDDlyB0aW1lX21zCuKAoiBtYXplX2lkID09IChjaGFyYWN0ZXIgPDwgOCkgfCBzZW5zZV9pZArigKIgcHJvb2ZfaGV4IG11c3QgYmUgbm9uLWVtcHR5CgpGdWxsIEJhcnJldGVuYmVyZyBVbHRyYUhvbmsgdmVyaWZpY2F0aW9uIGNhbiBiZSB3aXJlZCBpbiBoZXJlIG9uY2UgYQpTb3JvYmFuLW5hdGl2ZSB2ZXJpZmllciBpcyBhdmFpbGFibGU7IGZvciBub3cgcHJvb2ZzIGFyZSBzdG9yZWQgZm9yCm9mZi1jaGFpbiBhdWRpdGFiaWxpdHkuCgpgc2Vuc2VfaWRgOiAwPWhlYXJpbmcsIDE9c21lbGwsIDI9dGFzdGUsIDM9dG91Y2gsIDQ9c2lnaHQsIDU9cHJvcHJpb2NlcHRpb24AAAAAABdzdWJtaXRfc2Vuc2VfY29tcGxldGlvbgAAAAAHAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACHNlbnNlX2lkAAAABAAAAAAAAAAHbWF6ZV9pZAAAAAAEAAAAAAAAAAZwb2ludHMAAAAAAAYAAAAAAAAAB3RpbWVfbXMAAAAABgAAAAAAAAAFc2NvcmUAAAAAAAAGAAAAAAAAAAlwcm9vZl9oZXgAAAAAAAAOAAAAAQAAA+kAAAACAAAAAw==" ]),
+        "AAAAAAAAAIdHZXQgdGhlIHNlbnNlcyB0aGUgcGxheWVyIGNhbiBhdHRlbXB0IG5leHQgaW4gdGhlaXIgYWN0aXZlIHNlc3Npb24sIGFzCmEgYml0ZmllbGQgbGlrZSBgY29tcGxldGVkX3NlbnNlc2AuIDAgd2l0aG91dCBhbiBhY3RpdmUgc2Vzc2lvbi4AAAAAFGdldF9hdmFpbGFibGVfc2Vuc2VzAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAEBHZXQgdGhlIG1hc2sgb2Ygc2Vuc2VzIHRoYXQgbXVzdCBiZSBjb21wbGV0ZWQgYmVmb3JlIGBzZW5zZV9pZGAuAAAAF2dldF9zZW5zZV9wcmVyZXF1aXNpdGVzAAAAAAEAAAAAAAAACHNlbnNlX2lkAAAABAAAAAEAAAAE",
+        "AAAAAAAAADhHZXQgdGhlIGxhc3QgYWdncmVnYXRlZCBydW4gcHJvb2YgYWNjZXB0ZWQgZm9yIGEgcGxheWVyLgAAAA1nZXRfcnVuX3Byb29mAAAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAfQAAAACFJ1blByb29m",
+        "AAAAAAAAAP9HZXQgdGhlIG1hemUgY29tbWl0bWVudCBhIHNlbnNlIHN1Ym1pc3Npb24gbXVzdCBtYXRjaC4KClJldHVybnMgdGhlIGFkbWluLXB1Ymxpc2hlZCByb290IHdoZW4gc2V0LCBvdGhlcndpc2UgdGhlIHJvb3QgZGVyaXZlZApmcm9tIHRoZSBwbGF5ZXIncyBjdXJyZW50IHNlc3Npb24gc2VlZCAod2hpY2ggZG9lcyBub3QgY29tbWl0IHRvIHRoZQptYXplIGxheW91dDsgc2VlIGBzZXRfbWF6ZV9yb290YCkuIGBOb25lYCB3aXRob3V0IGEgc2Vzc2lvbi4AAAAADWdldF9tYXplX3Jvb3QAAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACHNlbnNlX2lkAAAABAAAAAEAAAPoAAAD7gAAACA=",
+        "AAAAAAAAAG1HZXQgYSBwbGF5ZXIncyBlbWJvZGltZW50IGNyZWRlbnRpYWwgZm9yIG9uZSBjaGFyYWN0ZXIuClBhcnQgb2YgdGhlIHN0YWJsZSBgRW1ib2RpbWVudENyZWRlbnRpYWxzYCBpbnRlcmZhY2UuAAAAAAAADmdldF9jcmVkZW50aWFsAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAABAAAD6AAAB9AAAAAURW1ib2RpbWVudENyZWRlbnRpYWw=",
+        "AAAAAAAAAGdXaGV0aGVyIHRoZSBwbGF5ZXIgaGFzIGV2ZXIgZW1ib2RpZWQgYGNoYXJhY3RlcmAuClBhcnQgb2YgdGhlIHN0YWJsZSBgRW1ib2RpbWVudENyZWRlbnRpYWxzYCBpbnRlcmZhY2UuAAAAAA5oYXNfY3JlZGVudGlhbAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAljaGFyYWN0ZXIAAAAAAAAEAAAAAQAAAAE=",
+        "AAAAAAAAAEJHZXQgdGhlIHBsYXllcidzIHVubG9ja2VkIGFjaGlldmVtZW50cyBhcyBhIGJpdHNldCAoYml0IGkgPSBpZCBpKS4AAAAAABBnZXRfYWNoaWV2ZW1lbnRzAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAo=",
+        "AAAAAAAAAEBHZXQgdGhlIHBsYXllcidzIGN1cnJlbnQgc3RyZWFrIG9mIGNvbnNlY3V0aXZlIHN1Y2Nlc3NmdWwgZXhpdHMuAAAACmdldF9zdHJlYWsAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAADxHZXQgdGhlIGFjaGlldmVtZW50IHRhYmxlOyBhbiBhY2hpZXZlbWVudCdzIGlkIGlzIGl0cyBpbmRleC4AAAAVZ2V0X2FjaGlldmVtZW50X3RhYmxlAAAAAAAAAAAAAAEAAAPqAAAH0AAAAA9BY2hpZXZlbWVudFJ1bGUA",
+        "AAAAAAAAAEBHZXQgdGhlIGdob3N0IGxlZnQgYnkgYSBzdWNjZXNzZnVsIHJ1biwga2V5ZWQgYnkgaXRzIHNlc3Npb24gaWQuAAAACWdldF9naG9zdAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAAIR2hvc3RSdW4=",
+        "AAAAAAAAAERHZXQgdGhlIGxhcmdlc3Qgc3Rha2UgYSBnaG9zdCdzIG93bmVyIGFncmVlZCB0byBtYXRjaCwgaWYgcHVibGlzaGVkLgAAABNnZXRfZ2hvc3Rfc3Rha2VfY2FwAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAAAs=",
+        "AAAAAAAAABlHZXQgYSByYWNlIGJ5IHNlc3Npb24gaWQuAAAAAAAACGdldF9yYWNlAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAARSYWNl",
+        "AAAAAAAAADlHZXQgdGhlIG1hemUgY29tbWl0bWVudCBzaGFyZWQgYnkgYm90aCBwbGF5ZXJzIG9mIGEgcmFjZS4AAAAAAAASZ2V0X3JhY2VfbWF6ZV9yb290AAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAhzZW5zZV9pZAAAAAQAAAABAAAD6AAAA+4AAAAg",
+        "AAAAAAAAADRHZXQgdGhlIGN1cnJlbnQgKG9yIGxhc3QpIHByYWN0aWNlIHJ1biBmb3IgYSBwbGF5ZXIuAAAAFGdldF9wcmFjdGljZV9zZXNzaW9uAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAfQAAAAEkNhbGlicmF0aW9uU2Vzc2lvbgAA",
+        "AAAAAAAAADBHZXQgYSBzdG9yZWQgcHJhY3RpY2Ugc2Vuc2UgcmVzdWx0IGZvciBhIHBsYXllci4AAAATZ2V0X3ByYWN0aWNlX3Jlc3VsdAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACHNlbnNlX2lkAAAABAAAAAEAAAPoAAAH0AAAAAtTZW5zZVJlc3VsdAA=",
+        "AAAAAAAAADlHZXQgdGhlIG1hemUgY29tbWl0bWVudCBhIHByYWN0aWNlIHN1Ym1pc3Npb24gbXVzdCBtYXRjaC4AAAAAAAAWZ2V0X3ByYWN0aWNlX21hemVfcm9vdAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhzZW5zZV9pZAAAAAQAAAABAAAD6AAAA+4AAAAg",
+        "AAAAAAAAAIRBZ2dyZWdhdGUgbWV0cmljcyBmb3IgZGlmZmljdWx0eSB0dW5pbmc6IHJ1bi1sZXZlbCBjb3VudGVycyBwbHVzIG9uZQplbnRyeSBwZXIgYChjaGFyYWN0ZXIsIHNlbnNlX2lkKWAgbWF6ZSAoY2hhcmFjdGVyLW1ham9yIG9yZGVyKS4AAAAJZ2V0X3N0YXRzAAAAAAAAAAAAAAEAAAfQAAAAEENhbGlicmF0aW9uU3RhdHM=",
+        "AAAAAAAAADZHZXQgdGhlIHJhdGUgbGltaXRzIGN1cnJlbnRseSBhcHBsaWVkIHRvIGBzdGFydF9nYW1lYC4AAAAAAA9nZXRfcmF0ZV9saW1pdHMAAAAAAAAAAAEAAAfQAAAAClJhdGVMaW1pdHMAAA==",
+        "AAAAAAAAAGhTaG93IHdoZW4gYHBsYXllcmAgY2FuIHN0YXJ0IHRoZWlyIG5leHQgc2Vzc2lvbiBhbmQgaG93IG11Y2ggb2YgdGhlCnJvbGxpbmctd2luZG93IHF1b3RhIHRoZXkgaGF2ZSB1c2VkLgAAABFnZXRfbGltaXRzX3N0YXR1cwAAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAfQAAAADExpbWl0c1N0YXR1cw==",
+        "AAAAAAAAAHpHZXQgbGVhZGVyYm9hcmQgc29ydGVkIGJ5IHRvdGFsX3Njb3JlIGFzY2VuZGluZyAobG93ZXIgPSBiZXR0ZXIpLgpPbmx5IHRoZSBiZXN0IGBNQVhfTEVBREVSQk9BUkRfTEVOYCAoMTAwKSBydW5zIGFyZSBrZXB0LgAAAAAAD2dldF9sZWFkZXJib2FyZAAAAAAAAAAAAQAAA+oAAAfQAAAAEExlYWRlcmJvYXJkRW50cnk=",
+        "AAAAAAAAAEQxLWJhc2VkIGxlYWRlcmJvYXJkIHJhbmsgb2YgdGhlIHBsYXllcidzIGJlc3QgcnVuLCBpZiB0aGV5IGhhdmUgb25lLgAAAAhnZXRfcmFuawAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPoAAAABA==",
+        "AAAAAAAAAEZHZXQgYGxpbWl0YCBlbnRyaWVzIHN0YXJ0aW5nIGF0IDAtYmFzZWQgYG9mZnNldGAgKGxpbWl0IGNhcHBlZCBhdCA1MCkuAAAAAAAUZ2V0X2xlYWRlcmJvYXJkX3BhZ2UAAAACAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAABBMZWFkZXJib2FyZEVudHJ5",
+        "AAAAAAAAAINHZXQgdGhlIHBsYXllcidzIGJlc3QgZW50cnkgd2l0aCB1cCB0byBgcmFkaXVzYCBlbnRyaWVzIGVpdGhlciBzaWRlCihyYWRpdXMgY2FwcGVkIHNvIHRoZSB3aW5kb3cgZml0cyBvbmUgcGFnZSkuIEVtcHR5IGlmIHVucmFua2VkLgAAAAAWZ2V0X2xlYWRlcmJvYXJkX2Fyb3VuZAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZyYWRpdXMAAAAAAAQAAAABAAAD6gAAB9AAAAAQTGVhZGVyYm9hcmRFbnRyeQ==",
+        "AAAAAAAAAIJHZXQgYSBwYWdlIG9mIHRoZSBsZWFkZXJib2FyZCByZXN0cmljdGVkIGJ5IGNoYXJhY3RlciBhbmQvb3IgdGltZXN0YW1wCnJhbmdlLiBgb2Zmc2V0YCBhbmQgYGxpbWl0YCBhcHBseSB0byB0aGUgZmlsdGVyZWQgb3JkZXJpbmcuAAAAAAAYZ2V0X2xlYWRlcmJvYXJkX2ZpbHRlcmVkAAAAAwAAAAAAAAAGZmlsdGVyAAAAAAfQAAAAEUxlYWRlcmJvYXJkRmlsdGVyAAAAAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAABBMZWFkZXJib2FyZEVudHJ5",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAEVHZXQgdGhlIHJ1bGVzIGN1cnJlbnRseSBhcHBsaWVkIHRvIGEgdGllciAoYE5vbmVgIGZvciB1bmtub3duIHRpZXJzKS4AAAAAAAAPZ2V0X3RpZXJfY29uZmlnAAAAAAEAAAAAAAAABHRpZXIAAAAEAAAAAQAAA+gAAAfQAAAAClRpZXJDb25maWcAAA==",
+        "AAAAAAAAAJ9PdmVycmlkZSBhIHRpZXIncyBjYXAsIG1pbmltdW0gc3Rha2UgYW5kIHBheW91dCBtdWx0aXBsaWVyLgpBcHBsaWVzIHRvIHNlc3Npb25zIHN0YXJ0ZWQgYWZ0ZXJ3YXJkczsgcnVubmluZyBzZXNzaW9ucyBrZWVwIHRoZSBjYXAKYW5kIHBheW91dCB0aGV5IHN0YXJ0ZWQgd2l0aC4AAAAAD3NldF90aWVyX2NvbmZpZwAAAAACAAAAAAAAAAR0aWVyAAAABAAAAAAAAAAGY29uZmlnAAAAAAfQAAAAClRpZXJDb25maWcAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAADxHZXQgdGhlIHByb2dyZXNzaW9uIHJlZ2lzdHJ5IGV4aXRzIHJlcG9ydCB0bywgaWYgY29uZmlndXJlZC4AAAAMZ2V0X3JlZ2lzdHJ5AAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAGhQb2ludCBleGl0cyBhdCBhIHByb2dyZXNzaW9uIHJlZ2lzdHJ5LiBUaGUgcmVnaXN0cnkgYWRtaW4gbXVzdCBhbHNvCmFsbG93bGlzdCB0aGlzIGNvbnRyYWN0IGFzIGEgd3JpdGVyLgAAAAxzZXRfcmVnaXN0cnkAAAABAAAAAAAAAAhyZWdpc3RyeQAAABMAAAAA",
+        "AAAAAAAAADFTdG9wIHJlcG9ydGluZyBleGl0cyB0byB0aGUgcHJvZ3Jlc3Npb24gcmVnaXN0cnkuAAAAAAAADmNsZWFyX3JlZ2lzdHJ5AAAAAAAAAAAAAA==",
+        "AAAAAAAAAHhBcHBlbmQgYW4gYWNoaWV2ZW1lbnQgdG8gdGhlIHRhYmxlIGFuZCByZXR1cm4gaXRzIGlkLgpFeGlzdGluZyB1bmxvY2tzIGFyZSB1bmFmZmVjdGVkOyB0aGUgbmV3IHJ1bGUgYXBwbGllcyBmcm9tIG5vdyBvbi4AAAAPYWRkX2FjaGlldmVtZW50AAAAAAEAAAAAAAAABHJ1bGUAAAfQAAAAD0FjaGlldmVtZW50UnVsZQAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAGFSZXBsYWNlIHRoZSBydWxlIGJlaGluZCBhbiBleGlzdGluZyBhY2hpZXZlbWVudCBpZAoodXNlIGBBY2hpZXZlbWVudFJ1bGU6OlJldGlyZWRgIHRvIHJldGlyZSBpdCkuAAAAAAAAD3NldF9hY2hpZXZlbWVudAAAAAACAAAAAAAAAA5hY2hpZXZlbWVudF9pZAAAAAAABAAAAAAAAAAEcnVsZQAAB9AAAAAPQWNoaWV2ZW1lbnRSdWxlAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAALJTZXQgdGhlIHNlbnNlcyAoYXMgYSBiaXRmaWVsZCkgdGhhdCBtdXN0IGJlIGNvbXBsZXRlZCBiZWZvcmUKYHNlbnNlX2lkYCBjYW4gYmUgc3VibWl0dGVkOyAwIHJlbW92ZXMgdGhlIHJlcXVpcmVtZW50LiBUaGUgZ3JhcGggbXVzdApzdGF5IGFjeWNsaWMgc28gZXZlcnkgc2Vuc2UgcmVtYWlucyByZWFjaGFibGUuAAAAAAAXc2V0X3NlbnNlX3ByZXJlcXVpc2l0ZXMAAAAAAgAAAAAAAAAIc2Vuc2VfaWQAAAAEAAAAAAAAAA1wcmVyZXF1aXNpdGVzAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAC1HZXQgdGhlIHJldHJ5IHBvbGljeSBhcHBsaWVkIGJ5IGBmYWlsX3NlbnNlYC4AAAAAAAAQZ2V0X3JldHJ5X3BvbGljeQAAAAAAAAABAAAH0AAAAAtSZXRyeVBvbGljeQA=",
+        "AAAAAAAAAF1Db25maWd1cmUgaG93IG1hbnkgdGltZXMgYSBmYWlsZWQgc2Vuc2UgbWF5IGJlIHJldHJpZWQgYW5kIHRoZQp0b3RhbF9zY29yZSBwZW5hbHR5IHBlciByZXRyeS4AAAAAAAAQc2V0X3JldHJ5X3BvbGljeQAAAAEAAAAAAAAABnBvbGljeQAAAAAH0AAAAAtSZXRyeVBvbGljeQAAAAAA",
+        "AAAAAAAAAEpDb25maWd1cmUgcGVyLXBsYXllciByYXRlIGxpbWl0cyBmb3IgYHN0YXJ0X2dhbWVgICh6ZXJvIGRpc2FibGVzIGEgbGltaXQpLgAAAAAAD3NldF9yYXRlX2xpbWl0cwAAAAABAAAAAAAAAAZsaW1pdHMAAAAAB9AAAAAKUmF0ZUxpbWl0cwAAAAAAAA==",
+        "AAAAAAAAAHdQdWJsaXNoIHRoZSBNZXJrbGUgcm9vdCBvZiBhIGAoY2hhcmFjdGVyLCBzZW5zZV9pZClgIG1hemUgZ3JpZC4KT3ZlcnJpZGVzIHRoZSBzZXNzaW9uLWRlcml2ZWQgY29tbWl0bWVudCBmb3IgdGhhdCBtYXplLgAAAAANc2V0X21hemVfcm9vdAAAAAAAAAMAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAAAAAAACHNlbnNlX2lkAAAABAAAAAAAAAAEcm9vdAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAEZSZW1vdmUgYSBwdWJsaXNoZWQgbWF6ZSByb290LCBmYWxsaW5nIGJhY2sgdG8gdGhlIHNlc3Npb24tZGVyaXZlZCBvbmUuAAAAAAAPY2xlYXJfbWF6ZV9yb290AAAAAAIAAAAAAAAACWNoYXJhY3RlcgAAAAAAAAQAAAAAAAAACHNlbnNlX2lkAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAYVCcmluZyBzdG9yYWdlIHdyaXR0ZW4gYnkgYW4gb2xkZXIgdmVyc2lvbiB1cCB0byBgU0NIRU1BX1ZFUlNJT05gLgpDYWxsIG9uY2UgYWZ0ZXIgYHVwZ3JhZGVgOyBhIG5vLW9wIHdoZW4gYWxyZWFkeSBjdXJyZW50LgoKUmV3cml0ZXMgdGhlIGxlYWRlcmJvYXJkIHNvcnRlZCBieSBzY29yZSwgd2l0aCB0aGUgbmV3ZXIgZW50cnkgZmllbGRzCmRlZmF1bHRlZCBhbmQgY2FwcGVkIGF0IGBNQVhfTEVBREVSQk9BUkRfTEVOYC4gU2Vzc2lvbnMgYW5kIHNlbnNlCnJlc3VsdHMgYXJlIHBlciBwbGF5ZXIsIHNvIHRoZXkgYXJlIGNvbnZlcnRlZCB3aGVuIG5leHQgcmVhZCBpbnN0ZWFkLgoKUmV0dXJucyB0aGUgc2NoZW1hIHZlcnNpb24gc3RvcmFnZSB3YXMgYXQgYmVmb3JlIHRoZSBjYWxsLgAAAAAAAAdtaWdyYXRlAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAFVTdG9yYWdlIGxheW91dCB2ZXJzaW9uOyBiZWxvdyB0aGUgY29udHJhY3QncyBjdXJyZW50IHZlcnNpb24gdW50aWwKYG1pZ3JhdGVgIGhhcyBydW4uAAAAAAAAEmdldF9zY2hlbWFfdmVyc2lvbgAAAAAAAAAAAAEAAAAE",
+        "AAAAAgAAAEhPdXRjb21lIHBhc3NlZCB0byBgZW5kX2dhbWVfdjJgIChtYXRjaGVzIG1vY2stZ2FtZS1odWIncyBgR2FtZU91dGNvbWVgKS4AAAAAAAAAC0dhbWVPdXRjb21lAAAAAAQAAAAAAAAAAAAAAAdQbGF5ZXIxAAAAAAAAAAAAAAAAB1BsYXllcjIAAAAAAAAAAAAAAAAERHJhdwAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAA==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<void>>,
+        start_game_with_tier: this.txFromJSON<Result<void>>,
+        start_game_reserved: this.txFromJSON<Result<u32>>,
+        publish_ghost: this.txFromJSON<Result<void>>,
+        start_challenge: this.txFromJSON<Result<void>>,
+        set_character: this.txFromJSON<Result<u32>>,
+        submit_sense_completion: this.txFromJSON<Result<void>>,
+        fail_sense: this.txFromJSON<Result<boolean>>,
+        register_session_key: this.txFromJSON<Result<void>>,
+        submit_sense_signed: this.txFromJSON<Result<void>>,
+        attempt_exit: this.txFromJSON<Result<readonly [boolean, u64]>>,
+        attempt_exit_with_proof: this.txFromJSON<Result<readonly [boolean, u64]>>,
+        start_practice: this.txFromJSON<Result<void>>,
+        submit_practice_sense: this.txFromJSON<Result<void>>,
+        exit_practice: this.txFromJSON<Result<PracticeOutcome>>,
+        start_race: this.txFromJSON<Result<void>>,
+        submit_race_sense: this.txFromJSON<Result<void>>,
+        settle_race: this.txFromJSON<Result<GameOutcome>>,
         get_game: this.txFromJSON<Option<CalibrationSession>>,
-        get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
         get_session: this.txFromJSON<Option<CalibrationSession>>,
-        attempt_exit: this.txFromJSON<Result<readonly [boolean, u64]>>,
-        set_character: this.txFromJSON<Result<u32>>,
-        get_leaderboard: this.txFromJSON<Array<LeaderboardEntry>>,
         get_sense_result: this.txFromJSON<Option<SenseResult>>,
-        submit_sense_completion: this.txFromJSON<Result<void>>
+        get_available_senses: this.txFromJSON<u32>,
+        get_sense_prerequisites: this.txFromJSON<u32>,
+        get_run_proof: this.txFromJSON<Option<RunProof>>,
+        get_maze_root: this.txFromJSON<Option<Buffer>>,
+        get_credential: this.txFromJSON<Option<EmbodimentCredential>>,
+        has_credential: this.txFromJSON<boolean>,
+        get_achievements: this.txFromJSON<u128>,
+        get_streak: this.txFromJSON<u32>,
+        get_achievement_table: this.txFromJSON<Array<AchievementRule>>,
+        get_ghost: this.txFromJSON<Option<GhostRun>>,
+        get_ghost_stake_cap: this.txFromJSON<Option<i128>>,
+        get_race: this.txFromJSON<Option<Race>>,
+        get_race_maze_root: this.txFromJSON<Option<Buffer>>,
+        get_practice_session: this.txFromJSON<Option<CalibrationSession>>,
+        get_practice_result: this.txFromJSON<Option<SenseResult>>,
+        get_practice_maze_root: this.txFromJSON<Option<Buffer>>,
+        get_stats: this.txFromJSON<CalibrationStats>,
+        get_rate_limits: this.txFromJSON<RateLimits>,
+        get_limits_status: this.txFromJSON<LimitsStatus>,
+        get_leaderboard: this.txFromJSON<Array<LeaderboardEntry>>,
+        get_rank: this.txFromJSON<Option<u32>>,
+        get_leaderboard_page: this.txFromJSON<Array<LeaderboardEntry>>,
+        get_leaderboard_around: this.txFromJSON<Array<LeaderboardEntry>>,
+        get_leaderboard_filtered: this.txFromJSON<Array<LeaderboardEntry>>,
+        get_admin: this.txFromJSON<string>,
+        set_admin: this.txFromJSON<null>,
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        get_tier_config: this.txFromJSON<Option<TierConfig>>,
+        set_tier_config: this.txFromJSON<Result<void>>,
+        get_registry: this.txFromJSON<Option<string>>,
+        set_registry: this.txFromJSON<null>,
+        clear_registry: this.txFromJSON<null>,
+        add_achievement: this.txFromJSON<Result<u32>>,
+        set_achievement: this.txFromJSON<Result<void>>,
+        set_sense_prerequisites: this.txFromJSON<Result<void>>,
+        get_retry_policy: this.txFromJSON<RetryPolicy>,
+        set_retry_policy: this.txFromJSON<null>,
+        set_rate_limits: this.txFromJSON<null>,
+        set_maze_root: this.txFromJSON<Result<void>>,
+        clear_maze_root: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>,
+        migrate: this.txFromJSON<u32>,
+        get_schema_version: this.txFromJSON<u32>
   }
 }
\ No newline at end of file
@@ -9,7 +9,7 @@ import { useSound } from '../../../utils/useSound';
 interface MazeProps {
   sense: Sense;
   character: Character;
-  onComplete: (score: number, points: number, time_ms: number) => void;
+  onComplete: (score: number, points: number, time_ms: number, hazards: number) => void;
   onBack: () => void;
 }
 
@@ -117,10 +117,10 @@ export function Maze({ sense, character, onComplete, onBack }: MazeProps) {
         const actualTime = Date.now() - startTime + timePenalty; // Add penalty time for Robert
         const time_ms = actualTime;
         const score = points * time_ms;
-        onComplete(score, points, time_ms);
+        onComplete(score, points, time_ms, hitHazards.size);
       }, 300);
     }
-  }, [playerPos, layout.goal, onComplete, explorationPoints, startTime, character, collectedItems, collectiblePositions.length, timePenalty]);
+  }, [playerPos, layout.goal, onComplete, explorationPoints, startTime, character, collectedItems, collectiblePositions.length, timePenalty, hitHazards]);
 
   const isValidMove = useCallback((x: number, y: number): boolean => {
     if (x < 0 || x >= layout.width || y < 0 || y >= layout.height) {
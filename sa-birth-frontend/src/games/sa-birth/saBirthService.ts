@@ -73,6 +73,15 @@ export class SaBirthService {
     }
   }
 
+  /**
+   * Get the maze commitment the contract expects for one sense of the
+   * player's current session. Returns null if there is no session.
+   */
+  async getMazeRoot(playerAddress: string, senseId: number): Promise<Buffer | null> {
+    const tx = await this.baseClient.get_maze_root({ player: playerAddress, sense_id: senseId });
+    return tx.result ?? null;
+  }
+
   /**
    * Start a new calibration session.
    *
@@ -162,7 +171,8 @@ export class SaBirthService {
   /**
    * Submit the result of one completed maze sense.
    *
-   * proof_hex only needs to be non-empty (contract checks length > 0).
+   * The maze_root is read from the contract (get_maze_root) so it always matches
+   * the commitment the contract checks for this session and sense.
    * The proof bytes are passed as a Buffer — the bindings type proof_hex as Bytes/Buffer.
    */
   async submitSenseCompletion(
@@ -171,12 +181,18 @@ export class SaBirthService {
     mazeId: number,
     points: bigint,
     timeMs: bigint,
+    hazards: number,
     score: bigint,
     proofHex: string,
     signer: Pick<ClientOptions, 'signTransaction' | 'signAuthEntry'>
   ) {
     const client = this.createSigningClient(playerAddress, signer);
 
+    const mazeRoot = await this.getMazeRoot(playerAddress, senseId);
+    if (!mazeRoot) {
+      throw new Error('submit_sense_completion failed — no active session to read the maze root from');
+    }
+
     // Convert hex string to Buffer for the Bytes contract type.
     // The contract only checks proof_hex.len() > 0, so any non-empty bytes are valid.
     const proofBuffer = Buffer.from(proofHex, 'hex');
@@ -185,8 +201,10 @@ export class SaBirthService {
       player:    playerAddress,
       sense_id:  senseId,
       maze_id:   mazeId,
+      maze_root: mazeRoot,
       points,
       time_ms:   timeMs,
+      hazards,
       score,
       proof_hex: proofBuffer,
     }, DEFAULT_METHOD_OPTIONS);
@@ -246,6 +246,9 @@ const adminSecret = adminKeypair.secret();
 
 const deployed: Record<string, string> = { ...existingContractIds };
 
+// Faucet points credited to each dev player when a fresh mock Game Hub is deployed.
+const MOCK_HUB_FAUCET_POINTS = "100000000000";
+
 // Ensure mock Game Hub exists so we can pass it into game constructors.
 let mockGameHubId = existingContractIds[mock.packageName] || "";
 if (shouldEnsureMock) {
@@ -277,7 +280,7 @@ if (shouldEnsureMock) {
     console.log(`Deploying ${mock.packageName}...`);
     try {
       const result =
-        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK}`.text();
+        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
       mockGameHubId = result.trim();
       deployed[mock.packageName] = mockGameHubId;
       console.log(`✅ ${mock.packageName} deployed: ${mockGameHubId}\n`);
@@ -285,6 +288,17 @@ if (shouldEnsureMock) {
       console.error(`❌ Failed to deploy ${mock.packageName}:`, error);
       process.exit(1);
     }
+
+    // The hub locks stakes from player balances, so fund the dev players with faucet points.
+    for (const identity of ['player1', 'player2']) {
+      try {
+        await $`stellar contract invoke --id ${mockGameHubId} --source-account ${adminSecret} --network ${NETWORK} -- credit --player ${walletAddresses[identity]} --amount ${MOCK_HUB_FAUCET_POINTS}`.quiet();
+        console.log(`✅ Credited ${identity} with ${MOCK_HUB_FAUCET_POINTS} points`);
+      } catch (error) {
+        console.warn(`⚠️  Warning: Failed to credit ${identity} on ${mock.packageName}:`, error);
+      }
+    }
+    console.log();
   }
 }
 
@@ -300,8 +314,12 @@ for (const contract of contracts) {
     console.log(`  WASM hash: ${wasmHash}`);
 
     console.log("  Deploying and initializing...");
+    // The progression registry is not a game: its constructor only takes the admin.
+    const constructorArgs = contract.packageName === "progression-registry"
+      ? ["--admin", adminAddress]
+      : ["--admin", adminAddress, "--game-hub", mockGameHubId];
     const deployResult =
-      await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
+      await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- ${constructorArgs}`.text();
     const contractId = deployResult.trim();
     deployed[contract.packageName] = contractId;
     console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
@@ -311,6 +329,33 @@ for (const contract of contracts) {
   }
 }
 
+// The hub only accepts sessions from approved games, so register every game deployed this run.
+for (const contract of contracts) {
+  if (contract.isMockHub || contract.packageName === "progression-registry") continue;
+  const gameId = deployed[contract.packageName];
+  try {
+    await $`stellar contract invoke --id ${mockGameHubId} --source-account ${adminSecret} --network ${NETWORK} -- add_game --game_address ${gameId}`.quiet();
+    console.log(`✅ Registered ${contract.packageName} with ${mock.packageName}`);
+  } catch (error) {
+    console.warn(`⚠️  Warning: Failed to register ${contract.packageName} with ${mock.packageName}:`, error);
+  }
+}
+
+// Wire sa-birth to the progression registry when either was (re)deployed.
+const registryId = deployed["progression-registry"];
+const saBirthId = deployed["sa-birth"];
+const deployedNow = new Set(contracts.map((c) => c.packageName));
+if (registryId && saBirthId && (deployedNow.has("progression-registry") || deployedNow.has("sa-birth"))) {
+  console.log("Linking sa-birth to progression-registry...");
+  try {
+    await $`stellar contract invoke --id ${registryId} --source-account ${adminSecret} --network ${NETWORK} -- allow_writer --game ${saBirthId}`.quiet();
+    await $`stellar contract invoke --id ${saBirthId} --source-account ${adminSecret} --network ${NETWORK} -- set_registry --registry ${registryId}`.quiet();
+    console.log("✅ sa-birth records milestones in progression-registry\n");
+  } catch (error) {
+    console.warn("⚠️  Warning: Failed to link sa-birth to progression-registry:", error);
+  }
+}
+
 console.log("🎉 Deployment complete!\n");
 console.log("Contract IDs:");
 const outputContracts = new Set<string>();
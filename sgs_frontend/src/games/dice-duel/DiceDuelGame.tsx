@@ -193,7 +193,7 @@ export function DiceDuelGame({
       setGameState(game);
 
       // Determine game phase based on state
-      if (game && game.winner !== null && game.winner !== undefined) {
+      if (game && game.player1_die1 !== null && game.player1_die1 !== undefined) {
         setGamePhase('complete');
       } else if (game && game.player1_rolled && game.player2_rolled) {
         setGamePhase('reveal');
@@ -216,11 +216,11 @@ export function DiceDuelGame({
 
   // Auto-refresh standings when game completes (for passive player who didn't call reveal_winner)
   useEffect(() => {
-    if (gamePhase === 'complete' && gameState?.winner) {
+    if (gamePhase === 'complete' && gameState?.player1_die1) {
       console.log('Game completed! Refreshing standings and dashboard data...');
       onStandingsRefresh(); // Refresh standings and available points; don't call onGameComplete() here or it will close the game!
     }
-  }, [gamePhase, gameState?.winner]);
+  }, [gamePhase, gameState?.player1_die1]);
 
   // Handle initial values from URL deep linking or props
   // Expected URL formats:
@@ -760,11 +760,12 @@ export function DiceDuelGame({
         setLoadSessionId('');
 
         // Determine game phase based on game state
-        if (game.winner !== null && game.winner !== undefined) {
+        if (game.player1_die1 !== null && game.player1_die1 !== undefined) {
           // Game is complete - show reveal phase with winner
           setGamePhase('complete');
           const isWinner = game.winner === userAddress;
-          setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
+          const isDraw = game.winner === null || game.winner === undefined;
+          setSuccess(isWinner ? '🎉 You won this game!' : isDraw ? 'Game complete. It was a draw.' : 'Game complete. Winner revealed.');
         } else if (game.player1_rolled && game.player2_rolled) {
           // Both players rolled, waiting for reveal
           setGamePhase('reveal');
@@ -864,7 +865,7 @@ export function DiceDuelGame({
   const waitForWinner = async () => {
     let updatedGame = await diceDuelService.getGame(sessionId);
     let attempts = 0;
-    while (attempts < 5 && (!updatedGame || updatedGame.winner === null || updatedGame.winner === undefined)) {
+    while (attempts < 5 && (!updatedGame || updatedGame.player1_die1 === null || updatedGame.player1_die1 === undefined)) {
       await new Promise((resolve) => setTimeout(resolve, 1000));
       updatedGame = await diceDuelService.getGame(sessionId);
       attempts += 1;
@@ -889,7 +890,8 @@ export function DiceDuelGame({
         setGamePhase('complete');
 
         const isWinner = updatedGame?.winner === userAddress;
-        setSuccess(isWinner ? '🎉 You won the duel!' : 'Game complete! Winner revealed.');
+        const isDraw = updatedGame?.winner === null || updatedGame?.winner === undefined;
+        setSuccess(isWinner ? '🎉 You won the duel!' : isDraw ? 'Game complete! It was a draw - both stakes are refunded.' : 'Game complete! Winner revealed.');
 
         // Refresh standings immediately (without navigating away)
         onStandingsRefresh();
@@ -990,7 +992,7 @@ export function DiceDuelGame({
                 Dice Duel 🎲
               </h2>
               <p className="text-sm text-gray-200 font-semibold mt-1">
-                Roll two dice each. Highest total wins. Ties favor Player 1.
+                Roll two dice each. Highest total wins. Ties are a draw.
               </p>
               <p className="text-xs text-gray-400 font-mono mt-1">
                 Session ID: {sessionId}
@@ -998,8 +1000,8 @@ export function DiceDuelGame({
             </div>
             <button
               onClick={() => {
-                // If game is complete (has winner), refresh stats before going back
-                if (gameState?.winner) {
+                // If game is complete (dice revealed), refresh stats before going back
+                if (gameState?.player1_die1) {
                   onGameComplete();
                 }
                 onBack();
@@ -1493,6 +1495,14 @@ export function DiceDuelGame({
                 )}
               </div>
             )}
+            {!gameState.winner && (
+              <div className="mt-6 p-5 bg-white border-2 border-emerald-200 rounded-xl shadow-lg">
+                <p className="text-xs font-bold uppercase tracking-wide text-gray-600 mb-2">Draw</p>
+                <p className="text-sm font-semibold text-gray-800">
+                  Both totals were equal. Both stakes are refunded.
+                </p>
+              </div>
+            )}
           </div>
           <button
             onClick={onBack}
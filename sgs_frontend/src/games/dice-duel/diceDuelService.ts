@@ -624,7 +624,7 @@ export class DiceDuelService {
     const client = this.createSigningClient(callerAddress, signer);
     const tx = await client.reveal_winner({ session_id: sessionId }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - footprint already includes all required storage keys
-    // (reveal_winner calls the Game Hub end_game() hook)
+    // (reveal_winner calls the Game Hub end_game_v2() hook)
 
     const validUntilLedgerSeq = authTtlMinutes
       ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
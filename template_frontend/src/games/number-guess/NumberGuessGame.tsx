@@ -100,7 +100,7 @@ export function NumberGuessGame({
   };
 
   const handleStartNewGame = () => {
-    if (gameState?.winner) {
+    if (gameState?.winning_number) {
       onGameComplete();
     }
 
@@ -150,7 +150,7 @@ export function NumberGuessGame({
       setGameState(game);
 
       // Determine game phase based on state
-      if (game && game.winner !== null && game.winner !== undefined) {
+      if (game && game.winning_number !== null && game.winning_number !== undefined) {
         setGamePhase('complete');
       } else if (game && game.player1_guess !== null && game.player1_guess !== undefined &&
                  game.player2_guess !== null && game.player2_guess !== undefined) {
@@ -174,11 +174,11 @@ export function NumberGuessGame({
 
   // Auto-refresh standings when game completes (for passive player who didn't call reveal_winner)
   useEffect(() => {
-    if (gamePhase === 'complete' && gameState?.winner) {
+    if (gamePhase === 'complete' && gameState?.winning_number) {
       console.log('Game completed! Refreshing standings and dashboard data...');
       onStandingsRefresh(); // Refresh standings and available points; don't call onGameComplete() here or it will close the game!
     }
-  }, [gamePhase, gameState?.winner]);
+  }, [gamePhase, gameState?.winning_number]);
 
   // Handle initial values from URL deep linking or props
   // Expected URL formats:
@@ -719,11 +719,12 @@ export function NumberGuessGame({
         setLoadSessionId('');
 
         // Determine game phase based on game state
-        if (game.winner !== null && game.winner !== undefined) {
+        if (game.winning_number !== null && game.winning_number !== undefined) {
           // Game is complete - show reveal phase with winner
           setGamePhase('reveal');
           const isWinner = game.winner === userAddress;
-          setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
+          const isDraw = game.winner === null || game.winner === undefined;
+          setSuccess(isWinner ? '🎉 You won this game!' : isDraw ? 'Game complete. It was a draw.' : 'Game complete. Winner revealed.');
         } else if (game.player1_guess !== null && game.player1_guess !== undefined &&
             game.player2_guess !== null && game.player2_guess !== undefined) {
           // Both players guessed, waiting for reveal
@@ -823,7 +824,7 @@ export function NumberGuessGame({
   const waitForWinner = async () => {
     let updatedGame = await numberGuessService.getGame(sessionId);
     let attempts = 0;
-    while (attempts < 5 && (!updatedGame || updatedGame.winner === null || updatedGame.winner === undefined)) {
+    while (attempts < 5 && (!updatedGame || updatedGame.winning_number === null || updatedGame.winning_number === undefined)) {
       await new Promise((resolve) => setTimeout(resolve, 1000));
       updatedGame = await numberGuessService.getGame(sessionId);
       attempts += 1;
@@ -847,7 +848,8 @@ export function NumberGuessGame({
         setGamePhase('complete');
 
         const isWinner = updatedGame?.winner === userAddress;
-        setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        const isDraw = updatedGame?.winner === null || updatedGame?.winner === undefined;
+        setSuccess(isWinner ? '🎉 You won!' : isDraw ? 'Game complete! It was a draw - both stakes are refunded.' : 'Game complete! Winner revealed.');
 
         // Refresh standings immediately (without navigating away)
         onStandingsRefresh();
@@ -1391,6 +1393,14 @@ export function NumberGuessGame({
                 )}
               </div>
             )}
+            {!gameState.winner && (
+              <div className="mt-6 p-5 bg-white border-2 border-green-200 rounded-xl shadow-lg">
+                <p className="text-xs font-bold uppercase tracking-wide text-gray-600 mb-2">Draw</p>
+                <p className="text-sm font-semibold text-gray-800">
+                  Both guesses were equally close. Both stakes are refunded.
+                </p>
+              </div>
+            )}
           </div>
           <button
             onClick={handleStartNewGame}